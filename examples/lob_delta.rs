@@ -10,10 +10,7 @@ use kalshi_fast::{
 async fn main() -> anyhow::Result<()> {
     let env = KalshiEnvironment::demo();
 
-    let auth = KalshiAuth::from_pem_file(
-        std::env::var("KALSHI_KEY_ID")?,
-        std::env::var("KALSHI_PRIVATE_KEY_PATH")?,
-    )?;
+    let auth = KalshiAuth::from_env()?;
 
     let mut ws =
         KalshiWsClient::connect_authenticated(env, auth, WsReconnectConfig::default()).await?;
@@ -28,9 +25,13 @@ async fn main() -> anyhow::Result<()> {
     loop {
         match ws.next_event().await? {
             WsEvent::Message(msg) => println!("{:?}", msg),
-            WsEvent::Raw(_) => {}
+            WsEvent::Raw(_)
+            | WsEvent::DuplicateFill { .. }
+            | WsEvent::SequenceGap { .. }
+            | WsEvent::TapDropped { .. }
+            | WsEvent::Reconnecting { .. } => {}
             WsEvent::Reconnected { attempt } => println!("Reconnected (attempt {})", attempt),
-            WsEvent::Disconnected { error } => {
+            WsEvent::Disconnected { error, .. } => {
                 println!("Disconnected: {:?}", error);
                 break;
             }
@@ -5,7 +5,7 @@
 /// 3. Subscribes to orderbook deltas for all markets in the event
 /// 4. Prints each delta update via debug logging
 ///
-/// Requires KALSHI_KEY_ID and KALSHI_PRIVATE_KEY_PATH env vars (or .env file)
+/// Requires KALSHI_KEY_ID and KALSHI_PRIVATE_KEY or KALSHI_PRIVATE_KEY_PATH env vars (or .env file)
 use kalshi_fast::{
     GetMarketsParams, KalshiAuth, KalshiEnvironment, KalshiRestClient, KalshiWsClient, Market,
     MarketStatusQuery, MveFilter, WsDataMessage, WsEvent, WsMessage, WsReconnectConfig,
@@ -108,10 +108,7 @@ async fn main() -> anyhow::Result<()> {
     );
 
     // Step 3: Connect authenticated WebSocket
-    let auth = KalshiAuth::from_pem_file(
-        std::env::var("KALSHI_KEY_ID")?,
-        std::env::var("KALSHI_PRIVATE_KEY_PATH")?,
-    )?;
+    let auth = KalshiAuth::from_env()?;
 
     let mut ws =
         KalshiWsClient::connect_authenticated(env, auth, WsReconnectConfig::default()).await?;
@@ -150,9 +147,13 @@ async fn main() -> anyhow::Result<()> {
                 WsMessage::Error { error, .. } => println!("[ERROR] {:?}", error),
                 other => println!("[OTHER] {:?}", other),
             },
-            WsEvent::Raw(_) => {}
+            WsEvent::Raw(_)
+            | WsEvent::DuplicateFill { .. }
+            | WsEvent::SequenceGap { .. }
+            | WsEvent::TapDropped { .. }
+            | WsEvent::Reconnecting { .. } => {}
             WsEvent::Reconnected { attempt } => println!("[RECONNECTED] attempt={}", attempt),
-            WsEvent::Disconnected { error } => {
+            WsEvent::Disconnected { error, .. } => {
                 println!("[DISCONNECTED] {:?}", error);
                 break;
             }
@@ -1,17 +1,14 @@
 /// Paginate through authenticated fill history.
 ///
 /// Demonstrates authenticated pager usage with statistics accumulation.
-/// Requires KALSHI_KEY_ID and KALSHI_PRIVATE_KEY_PATH environment variables.
+/// Requires KALSHI_KEY_ID and KALSHI_PRIVATE_KEY or KALSHI_PRIVATE_KEY_PATH environment variables.
 use kalshi_fast::{GetFillsParams, KalshiAuth, KalshiEnvironment, KalshiRestClient};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
-    let auth = KalshiAuth::from_pem_file(
-        std::env::var("KALSHI_KEY_ID")?,
-        std::env::var("KALSHI_PRIVATE_KEY_PATH")?,
-    )?;
+    let auth = KalshiAuth::from_env()?;
     let client = KalshiRestClient::new(KalshiEnvironment::production()).with_auth(auth);
 
     let mut pager = client.fills_pager(GetFillsParams::default());
@@ -11,10 +11,7 @@ async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
     let env = KalshiEnvironment::demo();
-    let auth = KalshiAuth::from_pem_file(
-        std::env::var("KALSHI_KEY_ID")?,
-        std::env::var("KALSHI_PRIVATE_KEY_PATH")?,
-    )?;
+    let auth = KalshiAuth::from_env()?;
     let client = KalshiRestClient::new(env).with_auth(auth);
 
     let balance = client.get_balance().await?;
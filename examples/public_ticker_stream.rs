@@ -8,10 +8,7 @@ async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
     let env = KalshiEnvironment::demo();
-    let auth = KalshiAuth::from_pem_file(
-        std::env::var("KALSHI_KEY_ID")?,
-        std::env::var("KALSHI_PRIVATE_KEY_PATH")?,
-    )?;
+    let auth = KalshiAuth::from_env()?;
 
     let mut ws =
         KalshiWsClient::connect_authenticated(env, auth, WsReconnectConfig::default()).await?;
@@ -37,11 +34,15 @@ async fn main() -> anyhow::Result<()> {
                     println!("type=other msg={:?}", other);
                 }
             },
-            WsEvent::Raw(_) => {}
+            WsEvent::Raw(_)
+            | WsEvent::DuplicateFill { .. }
+            | WsEvent::SequenceGap { .. }
+            | WsEvent::TapDropped { .. }
+            | WsEvent::Reconnecting { .. } => {}
             WsEvent::Reconnected { attempt } => {
                 println!("type=reconnected attempt={}", attempt);
             }
-            WsEvent::Disconnected { error } => {
+            WsEvent::Disconnected { error, .. } => {
                 println!("type=disconnected error={:?}", error);
                 break;
             }
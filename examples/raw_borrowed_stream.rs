@@ -19,6 +19,7 @@ async fn main() -> anyhow::Result<()> {
         .start_reader(WsReaderConfig {
             buffer_size: 1024,
             mode: WsReaderMode::Raw,
+            ..Default::default()
         })
         .await?;
 
@@ -34,11 +35,15 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
             WsEvent::Reconnected { attempt } => println!("Reconnected (attempt {})", attempt),
-            WsEvent::Disconnected { error } => {
+            WsEvent::Disconnected { error, .. } => {
                 println!("Disconnected: {:?}", error);
                 break;
             }
-            WsEvent::Message(_) => {}
+            WsEvent::Message(_)
+            | WsEvent::DuplicateFill { .. }
+            | WsEvent::SequenceGap { .. }
+            | WsEvent::TapDropped { .. }
+            | WsEvent::Reconnecting { .. } => {}
         }
     }
 
@@ -5,8 +5,6 @@ use kalshi_fast::{
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let key_id = std::env::var("KALSHI_KEY_ID")?;
-    let private_key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH")?;
     let market_ticker = match std::env::var("KALSHI_MARKET_TICKER") {
         Ok(value) => value,
         Err(_) => {
@@ -15,7 +13,7 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
-    let auth = KalshiAuth::from_pem_file(key_id, private_key_path)?;
+    let auth = KalshiAuth::from_env()?;
     let client = KalshiRestClient::new(KalshiEnvironment::demo()).with_auth(auth);
 
     let order_group = client
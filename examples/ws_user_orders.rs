@@ -5,9 +5,7 @@ use kalshi_fast::{
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let key_id = std::env::var("KALSHI_KEY_ID")?;
-    let private_key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH")?;
-    let auth = KalshiAuth::from_pem_file(key_id, private_key_path)?;
+    let auth = KalshiAuth::from_env()?;
 
     let mut ws = KalshiWsClient::connect_authenticated(
         KalshiEnvironment::demo(),
@@ -51,7 +49,7 @@ async fn main() -> anyhow::Result<()> {
                     msg.order_id, msg.ticker, msg.status
                 );
             }
-            WsEvent::Disconnected { error } => {
+            WsEvent::Disconnected { error, .. } => {
                 eprintln!("websocket disconnected: {error}");
                 break;
             }
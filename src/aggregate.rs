@@ -0,0 +1,256 @@
+use crate::rest::types::Trade;
+use crate::types::{FixedPoint, Quantity, TradeTakerSide, parse_rfc3339_unix_seconds};
+use crate::ws::types::WsTrade;
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+struct WindowTrade {
+    ts: i64,
+    side: TradeTakerSide,
+    /// YES price in micro-dollars.
+    yes_price: i64,
+    count: Quantity,
+}
+
+impl WindowTrade {
+    /// Micro-dollars times hundredths of a contract.
+    fn notional(&self) -> i128 {
+        i128::from(self.yes_price) * i128::from(self.count.hundredths())
+    }
+}
+
+/// Rolling-window trade flow split by taker side.
+///
+/// A YES taker is counted as buy volume and a NO taker as sell volume (in
+/// YES terms). Trades without a timestamp, YES price, count, or known taker
+/// side are skipped. The window is anchored on the newest trade seen, so
+/// REST pages (newest first) and WS trades can be mixed.
+///
+/// Volume and notional are kept as exact fixed-point sums, so adding and
+/// evicting trades never drifts; only the reported values are rounded.
+#[derive(Debug, Clone)]
+pub struct TradeAggregator {
+    window_secs: i64,
+    latest_ts: Option<i64>,
+    trades: VecDeque<WindowTrade>,
+    buy_volume: Quantity,
+    sell_volume: Quantity,
+    notional: i128,
+}
+
+impl TradeAggregator {
+    /// Aggregate over trades within `window` of the newest trade.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window_secs: i64::try_from(window.as_secs()).unwrap_or(i64::MAX),
+            latest_ts: None,
+            trades: VecDeque::new(),
+            buy_volume: Quantity::ZERO,
+            sell_volume: Quantity::ZERO,
+            notional: 0,
+        }
+    }
+
+    /// Add a REST trade. Returns `false` if it was skipped.
+    pub fn push_trade(&mut self, trade: &Trade) -> bool {
+        self.push_fields(
+            trade.created_time.as_deref(),
+            trade.taker_side,
+            trade.yes_price,
+            trade.yes_price_dollars.as_deref(),
+            trade.count,
+            trade.count_fp.as_deref(),
+        )
+    }
+
+    /// Add a WebSocket trade. Returns `false` if it was skipped.
+    pub fn push_ws_trade(&mut self, trade: &WsTrade) -> bool {
+        self.push_fields(
+            trade.created_time.as_deref(),
+            trade.taker_side,
+            trade.yes_price,
+            trade.yes_price_dollars.as_deref(),
+            trade.count,
+            trade.count_fp.as_deref(),
+        )
+    }
+
+    /// Add a trade from raw fields. Returns `false` if the taker side is
+    /// [`Unknown`](TradeTakerSide::Unknown) or it fell outside the window.
+    pub fn record(
+        &mut self,
+        ts: i64,
+        taker_side: TradeTakerSide,
+        yes_price: FixedPoint,
+        count: Quantity,
+    ) -> bool {
+        if matches!(taker_side, TradeTakerSide::Unknown) {
+            return false;
+        }
+        let latest = self.latest_ts.map_or(ts, |latest| latest.max(ts));
+        if ts <= latest.saturating_sub(self.window_secs) {
+            return false;
+        }
+
+        let trade = WindowTrade {
+            ts,
+            side: taker_side,
+            yes_price: yes_price.micro_units(),
+            count,
+        };
+        match taker_side {
+            TradeTakerSide::Yes => self.buy_volume += count,
+            _ => self.sell_volume += count,
+        }
+        self.notional += trade.notional();
+        self.trades.push_back(trade);
+
+        if self.latest_ts != Some(latest) {
+            self.latest_ts = Some(latest);
+            self.evict(latest.saturating_sub(self.window_secs));
+        }
+        true
+    }
+
+    /// Contracts bought by YES takers in the window.
+    pub fn buy_volume(&self) -> Quantity {
+        self.buy_volume
+    }
+
+    /// Contracts bought by NO takers in the window.
+    pub fn sell_volume(&self) -> Quantity {
+        self.sell_volume
+    }
+
+    /// Total contracts in the window.
+    pub fn volume(&self) -> Quantity {
+        self.buy_volume + self.sell_volume
+    }
+
+    /// Volume-weighted average YES price in cents, or `None` if empty.
+    pub fn vwap(&self) -> Option<f64> {
+        let volume = self.volume().hundredths();
+        // Micro-dollars per contract hundredth, divided down to cents.
+        (volume > 0).then(|| self.notional as f64 / volume as f64 / 10_000.0)
+    }
+
+    /// Number of trades in the window.
+    pub fn len(&self) -> usize {
+        self.trades.len()
+    }
+
+    /// Whether the window holds no trades.
+    pub fn is_empty(&self) -> bool {
+        self.trades.is_empty()
+    }
+
+    /// Drop every trade and reset the window.
+    pub fn clear(&mut self) {
+        self.latest_ts = None;
+        self.trades.clear();
+        self.buy_volume = Quantity::ZERO;
+        self.sell_volume = Quantity::ZERO;
+        self.notional = 0;
+    }
+
+    fn push_fields(
+        &mut self,
+        created_time: Option<&str>,
+        taker_side: Option<TradeTakerSide>,
+        yes_price: Option<i64>,
+        yes_price_dollars: Option<&str>,
+        count: Option<i64>,
+        count_fp: Option<&str>,
+    ) -> bool {
+        let ts = created_time.and_then(parse_rfc3339_unix_seconds);
+        let price = yes_price_dollars
+            .and_then(|d| d.parse::<FixedPoint>().ok())
+            .or(yes_price.and_then(FixedPoint::from_cents));
+        let count = count_fp
+            .and_then(Quantity::parse)
+            .or(count.map(Quantity::from_contracts));
+        match (ts, taker_side, price, count) {
+            (Some(ts), Some(side), Some(price), Some(count)) => self.record(ts, side, price, count),
+            _ => false,
+        }
+    }
+
+    fn evict(&mut self, cutoff: i64) {
+        let (mut buy, mut sell, mut notional) = (Quantity::ZERO, Quantity::ZERO, 0);
+        self.trades.retain(|t| {
+            if t.ts > cutoff {
+                return true;
+            }
+            match t.side {
+                TradeTakerSide::Yes => buy += t.count,
+                _ => sell += t.count,
+            }
+            notional += t.notional();
+            false
+        });
+        self.buy_volume -= buy;
+        self.sell_volume -= sell;
+        self.notional -= notional;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(created_time: &str, side: &str, yes_price: i64, count: i64) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "trade_id": "t",
+            "ticker": "MKT-1",
+            "yes_price": yes_price,
+            "count": count,
+            "taker_side": side,
+            "created_time": created_time,
+        }))
+        .expect("trade")
+    }
+
+    #[test]
+    fn aggregates_by_taker_side_over_rolling_window() {
+        let mut agg = TradeAggregator::new(Duration::from_secs(60));
+        assert!(agg.push_trade(&trade("2024-01-01T00:00:00Z", "yes", 40, 10)));
+        assert!(agg.push_trade(&trade("2024-01-01T00:00:30Z", "no", 50, 10)));
+        assert_eq!(agg.buy_volume(), Quantity::from_contracts(10));
+        assert_eq!(agg.sell_volume(), Quantity::from_contracts(10));
+        assert_eq!(agg.vwap(), Some(45.0));
+
+        let incomplete: Trade = serde_json::from_value(serde_json::json!({
+            "trade_id": "t",
+            "ticker": "MKT-1",
+            "taker_side": "yes",
+        }))
+        .expect("trade");
+        assert!(!agg.push_trade(&incomplete));
+
+        assert!(agg.push_trade(&trade("2024-01-01T00:01:10Z", "yes", 60, 10)));
+        assert_eq!(agg.len(), 2);
+        assert_eq!(agg.buy_volume(), Quantity::from_contracts(10));
+        assert_eq!(agg.vwap(), Some(55.0));
+
+        assert!(!agg.push_trade(&trade("2024-01-01T00:00:05Z", "yes", 60, 10)));
+    }
+
+    #[test]
+    fn fractional_trades_sum_exactly() {
+        let mut agg = TradeAggregator::new(Duration::from_secs(60));
+        let price: FixedPoint = "0.4350".parse().expect("price");
+        let tenth = Quantity::parse("0.10").expect("count");
+        for ts in 0..3 {
+            assert!(agg.record(ts, TradeTakerSide::Yes, price, tenth));
+        }
+        assert_eq!(agg.buy_volume(), Quantity::from_hundredths(30));
+        assert_eq!(agg.vwap(), Some(43.5));
+
+        assert!(agg.record(60, TradeTakerSide::No, price, tenth));
+        assert_eq!(agg.buy_volume(), Quantity::from_hundredths(20));
+        assert_eq!(agg.volume(), Quantity::from_hundredths(30));
+        assert_eq!(agg.vwap(), Some(43.5));
+    }
+}
@@ -6,12 +6,37 @@ use rsa::pss::SigningKey;
 use rsa::signature::{RandomizedSigner, SignatureEncoding};
 use rsa::{RsaPrivateKey, pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey};
 use sha2::Sha256;
+use std::fmt;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Clone)]
 pub struct KalshiAuth {
     pub key_id: String,
     private_key: RsaPrivateKey,
+    clock: Arc<dyn Clock>,
+}
+
+/// Source of the `KALSHI-ACCESS-TIMESTAMP` value.
+///
+/// Install one with [`KalshiAuth::with_clock`] to pin timestamps in tests
+/// or to correct for a measured offset from server time.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Milliseconds since UNIX epoch.
+    fn now_millis(&self) -> u64;
+}
+
+/// The default [`Clock`], backed by [`SystemTime::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock before unix epoch")
+            .as_millis() as u64
+    }
 }
 
 /// Convenience container for the three auth headers.
@@ -43,16 +68,45 @@ impl KalshiAuth {
         Ok(Self {
             key_id,
             private_key,
+            clock: Arc::new(SystemClock),
         })
     }
 
+    /// Stamp requests with `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Load from `KALSHI_KEY_ID` plus either `KALSHI_PRIVATE_KEY` (PEM
+    /// content; escaped `\n` sequences are unescaped, as is common in CI
+    /// secrets) or `KALSHI_PRIVATE_KEY_PATH` (path to a PEM file).
+    ///
+    /// Returns [`KalshiError::AuthRequired`] naming the missing variable.
+    pub fn from_env() -> Result<Self, KalshiError> {
+        Self::from_env_lookup(|name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn from_env_lookup(
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, KalshiError> {
+        let key_id =
+            lookup("KALSHI_KEY_ID").ok_or(KalshiError::AuthRequired("KALSHI_KEY_ID is not set"))?;
+
+        if let Some(pem) = lookup("KALSHI_PRIVATE_KEY") {
+            Self::from_pem_str(key_id, &pem.replace("\\n", "\n"))
+        } else if let Some(path) = lookup("KALSHI_PRIVATE_KEY_PATH") {
+            Self::from_pem_file(key_id, path)
+        } else {
+            Err(KalshiError::AuthRequired(
+                "neither KALSHI_PRIVATE_KEY nor KALSHI_PRIVATE_KEY_PATH is set",
+            ))
+        }
+    }
+
     /// Milliseconds since UNIX epoch, as required by Kalshi auth headers.
     pub fn now_timestamp_ms() -> String {
-        let ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system clock before unix epoch")
-            .as_millis();
-        ms.to_string()
+        SystemClock.now_millis().to_string()
     }
 
     /// Create signature for a request:
@@ -84,13 +138,14 @@ impl KalshiAuth {
         format!("{timestamp_ms}{method}{path_without_query}")
     }
 
-    /// Build the three headers required by Kalshi authenticated endpoints.
+    /// Build the three headers required by Kalshi authenticated endpoints,
+    /// timestamped by this key's [`Clock`].
     pub fn build_headers(
         &self,
         method: &str,
         path: &str,
     ) -> Result<KalshiAuthHeaders, KalshiError> {
-        let timestamp_ms = Self::now_timestamp_ms();
+        let timestamp_ms = self.clock.now_millis().to_string();
         let signature = self.sign(&timestamp_ms, method, path)?;
 
         Ok(KalshiAuthHeaders {
@@ -103,36 +158,41 @@ impl KalshiAuth {
 
 #[cfg(test)]
 pub mod tests {
-    use super::KalshiAuth;
+    use super::{Clock, KalshiAuth, SystemClock};
+    use crate::error::KalshiError;
     use base64::{Engine as _, engine::general_purpose::STANDARD};
     use rand::rngs::OsRng;
     use rsa::RsaPrivateKey;
     use rsa::pss::{Signature, VerifyingKey};
     use rsa::signature::Verifier;
     use sha2::Sha256;
+    use std::sync::Arc;
 
-    /// Load auth for tests. Optionally loads .env.test, supports both
-    /// KALSHI_PRIVATE_KEY (content) and KALSHI_PRIVATE_KEY_PATH (file path).
+    /// Load auth for tests. Optionally loads .env.test and reads the same
+    /// variables as [`KalshiAuth::from_env`], defaulting the key id and
+    /// generating a throwaway key when none is configured. A configured key
+    /// id is kept either way.
     pub fn load_test_auth() -> KalshiAuth {
         dotenvy::from_filename(".env.test").ok();
 
         let key_id = std::env::var("KALSHI_KEY_ID").unwrap_or_else(|_| "test-key-id".to_string());
-
-        if let Ok(pem_content) = std::env::var("KALSHI_PRIVATE_KEY") {
-            let pem_content = pem_content.replace("\\n", "\n");
-            KalshiAuth::from_pem_str(key_id, &pem_content)
-                .expect("load auth from KALSHI_PRIVATE_KEY")
-        } else if let Ok(pem_path) = std::env::var("KALSHI_PRIVATE_KEY_PATH") {
-            KalshiAuth::from_pem_file(key_id, pem_path)
-                .expect("load auth from KALSHI_PRIVATE_KEY_PATH")
-        } else {
-            let mut rng = OsRng;
-            let private_key =
-                RsaPrivateKey::new(&mut rng, 2048).expect("generate local test private key");
-            KalshiAuth {
-                key_id,
-                private_key,
+        let lookup = |name: &str| match name {
+            "KALSHI_KEY_ID" => Some(key_id.clone()),
+            _ => std::env::var(name).ok(),
+        };
+        match KalshiAuth::from_env_lookup(lookup) {
+            Ok(auth) => auth,
+            Err(KalshiError::AuthRequired(_)) => {
+                let mut rng = OsRng;
+                let private_key =
+                    RsaPrivateKey::new(&mut rng, 2048).expect("generate local test private key");
+                KalshiAuth {
+                    key_id,
+                    private_key,
+                    clock: Arc::new(SystemClock),
+                }
             }
+            Err(err) => panic!("load test auth from env: {err}"),
         }
     }
 
@@ -163,4 +223,61 @@ pub mod tests {
             .verify(message.as_bytes(), &sig)
             .expect("signature verifies");
     }
+
+    #[test]
+    fn build_headers_uses_injected_clock() {
+        #[derive(Debug)]
+        struct FixedClock(u64);
+        impl Clock for FixedClock {
+            fn now_millis(&self) -> u64 {
+                self.0
+            }
+        }
+
+        let auth = load_test_auth().with_clock(FixedClock(1_700_000_000_123));
+        let headers = auth
+            .build_headers("POST", "/trade-api/v2/portfolio/orders")
+            .expect("build headers");
+        assert_eq!(headers.timestamp_ms, "1700000000123");
+        let message = KalshiAuth::signing_message(
+            &headers.timestamp_ms,
+            "POST",
+            "/trade-api/v2/portfolio/orders",
+        );
+        let sig_bytes = STANDARD
+            .decode(headers.signature.as_bytes())
+            .expect("decode signature");
+        let sig = Signature::try_from(sig_bytes.as_slice()).expect("signature");
+        VerifyingKey::<Sha256>::new(auth.private_key.to_public_key())
+            .verify(message.as_bytes(), &sig)
+            .expect("signature verifies");
+    }
+
+    #[test]
+    fn from_env_lookup_unescapes_key_and_names_missing_vars() {
+        use rsa::pkcs8::{EncodePrivateKey, LineEnding};
+
+        let private_key = RsaPrivateKey::new(&mut OsRng, 2048).expect("generate key");
+        let escaped = private_key
+            .to_pkcs8_pem(LineEnding::LF)
+            .expect("pem")
+            .replace('\n', "\\n");
+        let auth = KalshiAuth::from_env_lookup(|name| match name {
+            "KALSHI_KEY_ID" => Some("key-1".to_string()),
+            "KALSHI_PRIVATE_KEY" => Some(escaped.clone()),
+            _ => None,
+        })
+        .expect("auth from escaped key");
+        assert_eq!(auth.key_id, "key-1");
+        assert_eq!(auth.private_key, private_key);
+
+        let err = KalshiAuth::from_env_lookup(|_| None).expect_err("missing key id");
+        assert!(err.to_string().contains("KALSHI_KEY_ID"));
+
+        let err = KalshiAuth::from_env_lookup(|name| {
+            (name == "KALSHI_KEY_ID").then(|| "key-1".to_string())
+        })
+        .expect_err("missing private key");
+        assert!(err.to_string().contains("KALSHI_PRIVATE_KEY_PATH"));
+    }
 }
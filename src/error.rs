@@ -1,4 +1,6 @@
-use crate::types::ErrorResponse;
+use crate::types::{ErrorResponse, KalshiErrorCode};
+use reqwest::StatusCode;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,6 +17,8 @@ pub enum KalshiError {
         api_error: Option<ErrorResponse>,
         raw_body: String,
         request_id: Option<String>,
+        /// Parsed `Retry-After` header (delay seconds or HTTP date), if sent.
+        retry_after: Option<Duration>,
     },
 
     #[error(transparent)]
@@ -46,9 +50,128 @@ pub enum KalshiError {
 
     #[error("websocket error: {0}")]
     Ws(String),
+
+    /// The server closed the WebSocket. `code` and `reason` come from the
+    /// close frame, if one was sent.
+    #[error("websocket closed (code {code:?}): {reason}")]
+    WsClosed { code: Option<u16>, reason: String },
+}
+
+/// Coarse error category from [`KalshiError::kind`], for metrics and alerting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Missing or rejected credentials (including HTTP 401/403).
+    Auth,
+    /// HTTP 429.
+    RateLimit,
+    /// Any other non-success HTTP status.
+    Http,
+    /// Connection, TLS, or I/O failure.
+    Transport,
+    /// Response body could not be decoded.
+    Parse,
+    /// Request rejected locally before sending.
+    InvalidParams,
+    /// WebSocket failure or close.
+    Ws,
+    /// Request or connect timeout.
+    Timeout,
+}
+
+impl ErrorKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::Auth => "auth",
+            ErrorKind::RateLimit => "rate_limit",
+            ErrorKind::Http => "http",
+            ErrorKind::Transport => "transport",
+            ErrorKind::Parse => "parse",
+            ErrorKind::InvalidParams => "invalid_params",
+            ErrorKind::Ws => "ws",
+            ErrorKind::Timeout => "timeout",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl KalshiError {
+    /// Lossy projection onto an [`ErrorKind`] for aggregation.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::AuthRequired(_) | Self::Crypto(_) => ErrorKind::Auth,
+            Self::InvalidParams(_) | Self::Url(_) | Self::Header(_) => ErrorKind::InvalidParams,
+            Self::Http { status, .. } => match status.as_u16() {
+                401 | 403 => ErrorKind::Auth,
+                429 => ErrorKind::RateLimit,
+                _ => ErrorKind::Http,
+            },
+            Self::Reqwest(err) if err.is_timeout() => ErrorKind::Timeout,
+            Self::Reqwest(err) if err.is_decode() => ErrorKind::Parse,
+            Self::Reqwest(_) => ErrorKind::Transport,
+            Self::Io(err) if err.kind() == std::io::ErrorKind::TimedOut => ErrorKind::Timeout,
+            Self::Io(_) => ErrorKind::Transport,
+            Self::Json(_) | Self::Parse { .. } => ErrorKind::Parse,
+            Self::Ws(_) | Self::WsClosed { .. } => ErrorKind::Ws,
+        }
+    }
+
+    /// Whether retrying the same request may succeed: HTTP 408, 425, 429,
+    /// 500, 502, 503, and 504 (the statuses [`RetryConfig`](crate::RetryConfig)
+    /// retries), connect/timeout failures, dropped connections, WebSocket
+    /// errors, and WebSocket closes with no frame or code 1000, 1001, 1006, or
+    /// 1011-1014. Policy (1008) and other protocol or application closes, such
+    /// as an expired-auth close, are not; nor are other HTTP statuses, invalid
+    /// parameters, auth, and parse failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http { status, .. } => retryable_status(*status),
+            Self::Reqwest(err) => retryable_reqwest_error(err),
+            Self::Io(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            ),
+            Self::Ws(_) => true,
+            Self::WsClosed { code, .. } => retryable_close_code(*code),
+            Self::AuthRequired(_)
+            | Self::InvalidParams(_)
+            | Self::Url(_)
+            | Self::Json(_)
+            | Self::Parse { .. }
+            | Self::Crypto(_)
+            | Self::Header(_) => false,
+        }
+    }
+
+    /// The typed error code from an HTTP error body, if the server sent one.
+    pub fn error_code(&self) -> Option<KalshiErrorCode> {
+        match self {
+            Self::Http {
+                api_error: Some(api_error),
+                ..
+            } => api_error.error_code(),
+            _ => None,
+        }
+    }
+
+    /// Server-requested delay from the `Retry-After` header of an HTTP error.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Http { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     pub(crate) fn parse_json(
         context: impl Into<String>,
         raw: impl AsRef<[u8]>,
@@ -90,6 +213,14 @@ impl KalshiError {
         }
     }
 
+    /// Close code from a [`KalshiError::WsClosed`] frame, if any.
+    pub fn ws_close_code(&self) -> Option<u16> {
+        match self {
+            Self::WsClosed { code, .. } => *code,
+            _ => None,
+        }
+    }
+
     pub fn parse_raw_bytes(&self) -> Option<&[u8]> {
         match self {
             Self::Parse { raw, .. } => Some(raw),
@@ -97,3 +228,110 @@ impl KalshiError {
         }
     }
 }
+
+pub(crate) fn retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_EARLY
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Close codes after which reconnecting may succeed; `None` means the
+/// connection dropped without a close frame.
+pub(crate) fn retryable_close_code(code: Option<u16>) -> bool {
+    matches!(code, None | Some(1000 | 1001 | 1006 | 1011..=1014))
+}
+
+pub(crate) fn retryable_reqwest_error(err: &reqwest::Error) -> bool {
+    err.is_timeout() || err.is_connect() || err.is_request()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http(status: u16) -> KalshiError {
+        KalshiError::Http {
+            status: reqwest::StatusCode::from_u16(status).expect("status"),
+            api_error: None,
+            raw_body: String::new(),
+            request_id: None,
+            retry_after: None,
+        }
+    }
+
+    #[test]
+    fn kind_buckets_variants() {
+        assert_eq!(http(401).kind(), ErrorKind::Auth);
+        assert_eq!(http(429).kind(), ErrorKind::RateLimit);
+        assert_eq!(http(500).kind(), ErrorKind::Http);
+        assert_eq!(KalshiError::AuthRequired("test").kind(), ErrorKind::Auth);
+        assert_eq!(
+            KalshiError::InvalidParams("bad".to_string()).kind(),
+            ErrorKind::InvalidParams
+        );
+        assert_eq!(
+            KalshiError::Io(std::io::ErrorKind::TimedOut.into()).kind(),
+            ErrorKind::Timeout
+        );
+        assert_eq!(
+            KalshiError::Io(std::io::ErrorKind::ConnectionReset.into()).kind(),
+            ErrorKind::Transport
+        );
+        assert_eq!(
+            KalshiError::parse_reason("ctx", b"", "bad").kind(),
+            ErrorKind::Parse
+        );
+        assert_eq!(
+            KalshiError::WsClosed {
+                code: None,
+                reason: String::new()
+            }
+            .kind(),
+            ErrorKind::Ws
+        );
+        assert_eq!(ErrorKind::RateLimit.to_string(), "rate_limit");
+    }
+
+    #[test]
+    fn retryable_errors_are_transient_failures() {
+        for status in [429, 500, 502, 503, 504] {
+            assert!(http(status).is_retryable(), "{status}");
+        }
+        for status in [400, 401, 404, 409] {
+            assert!(!http(status).is_retryable(), "{status}");
+        }
+        assert!(KalshiError::Ws("reset".to_string()).is_retryable());
+        assert!(KalshiError::Io(std::io::ErrorKind::ConnectionReset.into()).is_retryable());
+        assert!(!KalshiError::Io(std::io::ErrorKind::NotFound.into()).is_retryable());
+        assert!(!KalshiError::InvalidParams("bad".to_string()).is_retryable());
+        assert!(!KalshiError::AuthRequired("test").is_retryable());
+
+        let closed = |code| KalshiError::WsClosed {
+            code,
+            reason: String::new(),
+        };
+        for code in [None, Some(1000), Some(1001), Some(1006), Some(1012)] {
+            assert!(closed(code).is_retryable(), "{code:?}");
+        }
+        for code in [Some(1002), Some(1008), Some(4001)] {
+            assert!(!closed(code).is_retryable(), "{code:?}");
+        }
+
+        assert_eq!(http(429).retry_after(), None);
+        let err = KalshiError::Http {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            api_error: None,
+            raw_body: String::new(),
+            request_id: None,
+            retry_after: Some(Duration::from_secs(3)),
+        };
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(3)));
+    }
+}
@@ -0,0 +1,201 @@
+//! Streaming writers for historical pulls.
+//!
+//! Feed any `stream_*` pager (e.g. [`KalshiRestClient::stream_trades`]) into
+//! [`to_csv_writer`] or [`to_ndjson_writer`] to write rows as pages arrive
+//! instead of collecting the whole history first. Parquet is not supported;
+//! convert the CSV or NDJSON output downstream.
+//!
+//! [`KalshiRestClient::stream_trades`]: crate::rest::KalshiRestClient::stream_trades
+
+use crate::error::KalshiError;
+
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::io::Write;
+
+/// Options for [`to_csv_writer`] and [`to_ndjson_writer`].
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+    /// Flush the writer and report progress after this many rows (at least one).
+    pub flush_every: usize,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self { flush_every: 1000 }
+    }
+}
+
+/// Write each item as one JSON object per line.
+///
+/// `on_progress` receives the total rows written after every flush,
+/// including the final one. Returns the total row count, or the first
+/// stream, serialization, or I/O error; rows written before the error are
+/// flushed.
+pub async fn to_ndjson_writer<S, T, W>(
+    stream: S,
+    writer: W,
+    config: ExportConfig,
+    on_progress: impl FnMut(u64),
+) -> Result<u64, KalshiError>
+where
+    S: Stream<Item = Result<T, KalshiError>>,
+    T: Serialize,
+    W: Write,
+{
+    drive(stream, &config, on_progress, NdjsonSink(writer)).await
+}
+
+/// Write each item as one CSV record, with a header row taken from the
+/// first item's field names.
+///
+/// Rows must be flat: nested structs, maps, and sequences fail with
+/// [`KalshiError::Io`]. `Trade` and `Fill` export cleanly. Progress and
+/// error handling match [`to_ndjson_writer`].
+pub async fn to_csv_writer<S, T, W>(
+    stream: S,
+    writer: W,
+    config: ExportConfig,
+    on_progress: impl FnMut(u64),
+) -> Result<u64, KalshiError>
+where
+    S: Stream<Item = Result<T, KalshiError>>,
+    T: Serialize,
+    W: Write,
+{
+    drive(
+        stream,
+        &config,
+        on_progress,
+        CsvSink(csv::Writer::from_writer(writer)),
+    )
+    .await
+}
+
+trait RowSink {
+    fn write_row<T: Serialize>(&mut self, row: &T) -> Result<(), KalshiError>;
+    fn flush(&mut self) -> std::io::Result<()>;
+}
+
+struct NdjsonSink<W>(W);
+
+impl<W: Write> RowSink for NdjsonSink<W> {
+    fn write_row<T: Serialize>(&mut self, row: &T) -> Result<(), KalshiError> {
+        serde_json::to_writer(&mut self.0, row)?;
+        self.0.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+struct CsvSink<W: Write>(csv::Writer<W>);
+
+impl<W: Write> RowSink for CsvSink<W> {
+    fn write_row<T: Serialize>(&mut self, row: &T) -> Result<(), KalshiError> {
+        self.0
+            .serialize(row)
+            .map_err(|err| KalshiError::Io(err.into()))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+async fn drive<S, T>(
+    stream: S,
+    config: &ExportConfig,
+    mut on_progress: impl FnMut(u64),
+    mut sink: impl RowSink,
+) -> Result<u64, KalshiError>
+where
+    S: Stream<Item = Result<T, KalshiError>>,
+    T: Serialize,
+{
+    let flush_every = config.flush_every.max(1) as u64;
+    let mut stream = std::pin::pin!(stream);
+    let mut rows = 0u64;
+
+    while let Some(item) = stream.next().await {
+        let written = match item {
+            Ok(row) => sink.write_row(&row),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = written {
+            sink.flush()?;
+            return Err(err);
+        }
+        rows += 1;
+        if rows.is_multiple_of(flush_every) {
+            sink.flush()?;
+            on_progress(rows);
+        }
+    }
+
+    if !rows.is_multiple_of(flush_every) {
+        sink.flush()?;
+        on_progress(rows);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::types::Trade;
+    use futures::stream;
+
+    fn trade(id: &str, yes_price: i64) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "trade_id": id,
+            "ticker": "MKT-1",
+            "yes_price": yes_price,
+            "count": 1,
+            "taker_side": "yes",
+        }))
+        .expect("trade")
+    }
+
+    #[tokio::test]
+    async fn writers_emit_rows_and_report_progress() {
+        let trades = || stream::iter((0..5).map(|i| Ok(trade(&format!("t{i}"), 40 + i))));
+        let config = ExportConfig { flush_every: 2 };
+
+        let mut progress = Vec::new();
+        let mut out = Vec::new();
+        let rows = to_ndjson_writer(trades(), &mut out, config.clone(), |n| progress.push(n))
+            .await
+            .expect("ndjson");
+        assert_eq!(rows, 5);
+        assert_eq!(progress, [2, 4, 5]);
+        let lines: Vec<_> = std::str::from_utf8(&out).expect("utf8").lines().collect();
+        assert_eq!(lines.len(), 5);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).expect("json");
+        assert_eq!(first["trade_id"], "t0");
+
+        let mut out = Vec::new();
+        let rows = to_csv_writer(trades(), &mut out, config, |_| {})
+            .await
+            .expect("csv");
+        assert_eq!(rows, 5);
+        let text = String::from_utf8(out).expect("utf8");
+        let mut lines = text.lines();
+        assert!(lines.next().expect("header").starts_with("trade_id,"));
+        assert!(lines.next().expect("row").starts_with("t0,"));
+        assert_eq!(lines.count(), 4);
+
+        let failing = stream::iter(vec![
+            Ok(trade("t0", 40)),
+            Err(KalshiError::Ws("boom".to_string())),
+        ]);
+        let mut out = Vec::new();
+        let err = to_ndjson_writer(failing, &mut out, ExportConfig::default(), |_| {})
+            .await
+            .expect_err("stream error");
+        assert!(matches!(err, KalshiError::Ws(_)));
+        assert_eq!(out.iter().filter(|b| **b == b'\n').count(), 1);
+    }
+}
@@ -0,0 +1,354 @@
+//! Sample WebSocket payloads for testing code built on this crate.
+//!
+//! Each constructor returns a fully populated payload for one
+//! [`WsDataMessage`] variant, all on [`MARKET_TICKER`] in [`EVENT_TICKER`].
+//! Override what a test cares about with struct update syntax and wrap it in
+//! the variant, or feed recorded frames through [`parse_ws_message`]:
+//!
+//! ```
+//! use kalshi_fast::fixtures;
+//! use kalshi_fast::{WsDataMessage, WsMessage, WsTicker};
+//!
+//! let msg = WsMessage::Data(WsDataMessage::Ticker {
+//!     sid: Some(1),
+//!     seq: Some(1),
+//!     msg: WsTicker {
+//!         yes_bid: 40,
+//!         ..fixtures::ticker()
+//!     },
+//! });
+//! let frame = msg.to_wire_json().unwrap();
+//! assert!(matches!(
+//!     fixtures::parse_ws_message(&frame).unwrap(),
+//!     WsMessage::Data(WsDataMessage::Ticker { msg, .. }) if msg.yes_bid == 40
+//! ));
+//! ```
+//!
+//! Requires the `test-util` feature.
+
+use crate::error::KalshiError;
+use crate::rest::types::MarketPosition;
+use crate::types::{BuySell, OrderStatus, TradeTakerSide, YesNo};
+use crate::ws::types::{
+    WsCommunications, WsEventLifecycle, WsFill, WsMarketLifecycleEventType, WsMarketLifecycleV2,
+    WsMarketPositions, WsMessage, WsMultivariate, WsMultivariateSelectedMarket,
+    WsOrderGroupEventType, WsOrderGroupUpdate, WsOrderbookDelta, WsOrderbookSnapshot, WsRfqCreated,
+    WsTicker, WsTrade, WsUserOrder,
+};
+
+/// Market ticker used by every fixture.
+pub const MARKET_TICKER: &str = "KXTEST-26JAN01-T50";
+/// Event ticker used by every fixture.
+pub const EVENT_TICKER: &str = "KXTEST-26JAN01";
+/// Unix time (seconds) used by every fixture; string timestamps are the same instant.
+pub const TS: i64 = 1_767_225_600;
+const TIME: &str = "2026-01-01T00:00:00Z";
+
+/// Parse one WebSocket frame, e.g. a line from a
+/// [`WsFrameTap`](crate::WsFrameTap) recording.
+pub fn parse_ws_message(bytes: impl AsRef<[u8]>) -> Result<WsMessage, KalshiError> {
+    WsMessage::from_bytes(bytes.as_ref())
+}
+
+/// Ticker at 45¢ last, 44¢ bid, 46¢ ask.
+pub fn ticker() -> WsTicker {
+    WsTicker {
+        market_ticker: MARKET_TICKER.to_string(),
+        market_id: "fixture-market-id".to_string(),
+        price: 45,
+        yes_bid: 44,
+        yes_ask: 46,
+        price_dollars: "0.4500".to_string(),
+        yes_bid_dollars: "0.4400".to_string(),
+        yes_ask_dollars: "0.4600".to_string(),
+        volume: 1_000,
+        volume_fp: "1000.00".to_string(),
+        open_interest: 500,
+        open_interest_fp: "500.00".to_string(),
+        dollar_volume: 450,
+        dollar_open_interest: 225,
+        ts: TS,
+    }
+}
+
+/// Ten contracts traded at 45¢ YES, taken on the YES side.
+pub fn trade() -> WsTrade {
+    WsTrade {
+        trade_id: "fixture-trade-id".to_string(),
+        ticker: MARKET_TICKER.to_string(),
+        price: Some(45),
+        count: Some(10),
+        count_fp: Some("10.00".to_string()),
+        yes_price: Some(45),
+        no_price: Some(55),
+        yes_price_dollars: Some("0.4500".to_string()),
+        no_price_dollars: Some("0.5500".to_string()),
+        taker_side: Some(TradeTakerSide::Yes),
+        created_time: Some(TIME.to_string()),
+    }
+}
+
+/// Two levels a side: YES bids at 44¢ and 43¢, NO bids at 54¢ and 53¢.
+pub fn orderbook_snapshot() -> WsOrderbookSnapshot {
+    WsOrderbookSnapshot {
+        market_ticker: MARKET_TICKER.to_string(),
+        market_id: "fixture-market-id".to_string(),
+        yes: vec![(43, 20), (44, 10)],
+        no: vec![(53, 20), (54, 10)],
+        yes_dollars: vec![("0.4300".to_string(), 20), ("0.4400".to_string(), 10)],
+        no_dollars: vec![("0.5300".to_string(), 20), ("0.5400".to_string(), 10)],
+        yes_dollars_fp: vec![
+            ("0.4300".to_string(), "20.00".to_string()),
+            ("0.4400".to_string(), "10.00".to_string()),
+        ],
+        no_dollars_fp: vec![
+            ("0.5300".to_string(), "20.00".to_string()),
+            ("0.5400".to_string(), "10.00".to_string()),
+        ],
+    }
+}
+
+/// Five contracts added to the 44¢ YES level.
+pub fn orderbook_delta() -> WsOrderbookDelta {
+    WsOrderbookDelta {
+        market_ticker: MARKET_TICKER.to_string(),
+        market_id: "fixture-market-id".to_string(),
+        price: 44,
+        price_dollars: "0.4400".to_string(),
+        delta: 5,
+        delta_fp: "5.00".to_string(),
+        side: YesNo::Yes,
+        client_order_id: None,
+        subaccount: None,
+        ts: Some(TIME.to_string()),
+    }
+}
+
+/// A taker buy of ten YES contracts at 45¢.
+pub fn fill() -> WsFill {
+    WsFill {
+        fill_id: "fixture-fill-id".to_string(),
+        trade_id: "fixture-trade-id".to_string(),
+        order_id: "fixture-order-id".to_string(),
+        client_order_id: Some("fixture-client-order-id".to_string()),
+        ticker: MARKET_TICKER.to_string(),
+        market_ticker: MARKET_TICKER.to_string(),
+        side: YesNo::Yes,
+        action: BuySell::Buy,
+        count: 10,
+        count_fp: "10.00".to_string(),
+        yes_price: 45,
+        no_price: 55,
+        yes_price_fixed: "0.4500".to_string(),
+        no_price_fixed: "0.5500".to_string(),
+        is_taker: true,
+        fee_cost: "0.0700".to_string(),
+        created_time: Some(TIME.to_string()),
+        subaccount_number: None,
+        ts: Some(TS),
+    }
+}
+
+/// Ten YES contracts held in [`MARKET_TICKER`].
+pub fn market_positions() -> WsMarketPositions {
+    WsMarketPositions {
+        market_positions: vec![MarketPosition {
+            ticker: MARKET_TICKER.to_string(),
+            position: Some(10),
+            position_fp: Some("10.00".to_string()),
+            fees_paid: Some(7),
+            fees_paid_fp: Some("0.0700".to_string()),
+            resting_orders: Some(0),
+            resting_orders_fp: Some("0.00".to_string()),
+            total_traded: Some(450),
+            total_traded_fp: Some("10.00".to_string()),
+            subaccount: None,
+        }],
+        event_positions: Vec::new(),
+    }
+}
+
+/// The market was activated for trading.
+pub fn market_lifecycle() -> WsMarketLifecycleV2 {
+    WsMarketLifecycleV2 {
+        market_ticker: MARKET_TICKER.to_string(),
+        event_type: Some(WsMarketLifecycleEventType::Activated),
+        open_ts: Some(TS),
+        close_ts: Some(TS + 86_400),
+        additional_metadata: None,
+    }
+}
+
+/// The event was created.
+pub fn event_lifecycle() -> WsEventLifecycle {
+    WsEventLifecycle {
+        event_ticker: EVENT_TICKER.to_string(),
+        title: Some("Fixture event".to_string()),
+        subtitle: None,
+        collateral_return_type: None,
+        series_ticker: Some("KXTEST".to_string()),
+        additional_metadata: None,
+    }
+}
+
+/// A one-leg combo resolving to [`MARKET_TICKER`] YES.
+pub fn multivariate() -> WsMultivariate {
+    WsMultivariate {
+        collection_ticker: "KXTEST-COLLECTION".to_string(),
+        event_ticker: EVENT_TICKER.to_string(),
+        market_ticker: MARKET_TICKER.to_string(),
+        selected_markets: vec![WsMultivariateSelectedMarket {
+            event_ticker: EVENT_TICKER.to_string(),
+            market_ticker: MARKET_TICKER.to_string(),
+            side: YesNo::Yes,
+        }],
+    }
+}
+
+/// An RFQ for ten contracts.
+pub fn communications() -> WsCommunications {
+    WsCommunications::RfqCreated(rfq_created())
+}
+
+/// An RFQ for ten contracts, as carried by [`communications`].
+pub fn rfq_created() -> WsRfqCreated {
+    WsRfqCreated {
+        id: "fixture-rfq-id".to_string(),
+        creator_id: "fixture-creator-id".to_string(),
+        market_ticker: MARKET_TICKER.to_string(),
+        event_ticker: Some(EVENT_TICKER.to_string()),
+        contracts: Some(10),
+        contracts_fp: Some("10.00".to_string()),
+        target_cost: None,
+        target_cost_dollars: None,
+        created_ts: TIME.to_string(),
+        mve_collection_ticker: None,
+        mve_selected_legs: None,
+    }
+}
+
+/// An order group was created with a 100-contract limit.
+pub fn order_group_update() -> WsOrderGroupUpdate {
+    WsOrderGroupUpdate {
+        event_type: WsOrderGroupEventType::Created,
+        order_group_id: "fixture-order-group-id".to_string(),
+        contracts_limit_fp: Some("100.00".to_string()),
+    }
+}
+
+/// A resting YES buy of ten contracts at 44¢, nothing filled.
+pub fn user_order() -> WsUserOrder {
+    WsUserOrder {
+        order_id: "fixture-order-id".to_string(),
+        user_id: "fixture-user-id".to_string(),
+        ticker: MARKET_TICKER.to_string(),
+        status: Some(OrderStatus::Resting),
+        side: Some(YesNo::Yes),
+        yes_price_dollars: Some("0.4400".to_string()),
+        fill_count_fp: Some("0.00".to_string()),
+        remaining_count_fp: Some("10.00".to_string()),
+        initial_count_fp: Some("10.00".to_string()),
+        taker_fill_cost_dollars: Some("0.0000".to_string()),
+        maker_fill_cost_dollars: Some("0.0000".to_string()),
+        taker_fees_dollars: Some("0.0000".to_string()),
+        maker_fees_dollars: Some("0.0000".to_string()),
+        client_order_id: Some("fixture-client-order-id".to_string()),
+        order_group_id: None,
+        self_trade_prevention_type: None,
+        created_time: Some(TIME.to_string()),
+        last_update_time: Some(TIME.to_string()),
+        expiration_time: None,
+        subaccount_number: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::types::WsDataMessage;
+
+    #[test]
+    fn fixtures_round_trip_through_the_wire_format() {
+        let (sid, seq) = (Some(1), Some(1));
+        let messages = [
+            WsDataMessage::Ticker {
+                sid,
+                seq,
+                msg: ticker(),
+            },
+            WsDataMessage::Trade {
+                sid,
+                seq,
+                msg: trade(),
+            },
+            WsDataMessage::OrderbookSnapshot {
+                sid,
+                seq,
+                msg: orderbook_snapshot(),
+            },
+            WsDataMessage::OrderbookDelta {
+                sid,
+                seq,
+                msg: orderbook_delta(),
+            },
+            WsDataMessage::Fill {
+                sid,
+                seq,
+                msg: fill(),
+            },
+            WsDataMessage::MarketPositions {
+                sid,
+                seq,
+                msg: market_positions(),
+            },
+            WsDataMessage::MarketLifecycleV2 {
+                sid,
+                seq,
+                msg: market_lifecycle(),
+            },
+            WsDataMessage::EventLifecycle {
+                sid,
+                seq,
+                msg: event_lifecycle(),
+            },
+            WsDataMessage::Multivariate {
+                sid,
+                seq,
+                msg: multivariate(),
+            },
+            WsDataMessage::Communications {
+                sid,
+                seq,
+                msg: communications(),
+            },
+            WsDataMessage::OrderGroupUpdates {
+                sid,
+                seq,
+                msg: order_group_update(),
+            },
+            WsDataMessage::UserOrder {
+                sid,
+                seq,
+                msg: user_order(),
+            },
+        ];
+        for data in messages {
+            let frame = data.to_wire_json().expect("encode");
+            let parsed = parse_ws_message(&frame).expect(&frame);
+            let WsMessage::Data(parsed) = parsed else {
+                panic!("not a data message: {frame}");
+            };
+            assert_eq!(
+                std::mem::discriminant(&parsed),
+                std::mem::discriminant(&data),
+                "{frame}"
+            );
+            assert_eq!(
+                Some(parsed.msg_type()),
+                crate::ws::types::peek_msg_type(frame.as_bytes()),
+                "{frame}"
+            );
+            assert_eq!(parsed.to_wire_json().expect("re-encode"), frame);
+        }
+    }
+}
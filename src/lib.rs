@@ -28,7 +28,11 @@
 //!
 //! # async fn run() -> Result<(), kalshi_fast::KalshiError> {
 //! let client = KalshiRestClient::builder(KalshiEnvironment::demo())
-//!     .with_rate_limit_config(RateLimitConfig { read_rps: 30, write_rps: 15 })
+//!     .with_rate_limit_config(RateLimitConfig {
+//!         read_rps: 30,
+//!         write_rps: 15,
+//!         ..Default::default()
+//!     })
 //!     .with_retry_config(RetryConfig {
 //!         max_retries: 4,
 //!         base_delay: Duration::from_millis(200),
@@ -53,10 +57,7 @@
 //! };
 //!
 //! # async fn run() -> Result<(), kalshi_fast::KalshiError> {
-//! let auth = KalshiAuth::from_pem_file(
-//!     std::env::var("KALSHI_KEY_ID").unwrap(),
-//!     std::env::var("KALSHI_PRIVATE_KEY_PATH").unwrap(),
-//! )?;
+//! let auth = KalshiAuth::from_env()?;
 //!
 //! let mut ws = KalshiWsClient::connect_authenticated(
 //!     KalshiEnvironment::demo(),
@@ -101,9 +102,11 @@
 //! # }
 //! ```
 //!
-//! Environment variables used by the examples:
+//! Environment variables read by [`KalshiAuth::from_env`] and the examples:
 //! - `KALSHI_KEY_ID` — your API key ID
-//! - `KALSHI_PRIVATE_KEY_PATH` — path to your RSA private key (PEM format)
+//! - `KALSHI_PRIVATE_KEY` — PEM content, with `\n` escapes allowed
+//! - `KALSHI_PRIVATE_KEY_PATH` — path to your RSA private key (PEM format),
+//!   used when `KALSHI_PRIVATE_KEY` is unset
 //!
 //! ## Pagination
 //!
@@ -155,6 +158,7 @@
 //! Connection lifecycle events are exposed through [`WsEvent`]:
 //!
 //! - [`WsEvent::Message`] — incoming data
+//! - [`WsEvent::Reconnecting`] — a reconnect attempt is about to start
 //! - [`WsEvent::Reconnected`] — connection restored after a drop
 //! - [`WsEvent::Disconnected`] — connection lost after max retries
 //!
@@ -168,24 +172,37 @@
 //! - **Zero-copy message parsing** — binary WebSocket frames parsed with `from_slice`
 //! - **Split read/write streams** — no lock contention on WebSocket operations
 
+mod aggregate;
 pub mod auth;
 pub mod env;
 pub mod error;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(any(test, feature = "test-util"))]
+pub mod fixtures;
 pub mod rest;
 pub mod types;
 pub mod ws;
 
 // Primary clients
-pub use auth::{KalshiAuth, KalshiAuthHeaders};
+pub use aggregate::TradeAggregator;
+pub use auth::{Clock, KalshiAuth, KalshiAuthHeaders, SystemClock};
 pub use env::{KalshiEnvironment, REST_PREFIX, WS_PATH};
-pub use error::KalshiError;
+pub use error::{ErrorKind, KalshiError};
+#[cfg(feature = "blocking")]
+pub use rest::KalshiRestClientSync;
+#[cfg(any(test, feature = "test-util"))]
+pub use rest::MockTransport;
 pub use rest::{
-    CursorPager, KalshiRestClient, KalshiRestClientBuilder, RateLimitConfig, RateLimitTier,
-    RetryConfig,
+    ConcurrencyConfig, CursorPager, KalshiRestClient, KalshiRestClientBuilder, Lenient,
+    MarketCatalog, ParseWarning, RateLimitConfig, RateLimitObservation, RateLimitState,
+    RateLimitTier, ReqwestTransport, RetryConfig, Transport, TransportRequest, TransportResponse,
 };
 pub use ws::{
-    KalshiWsClient, KalshiWsLowLevelClient, WsEvent, WsEventReceiver, WsReaderConfig, WsReaderMode,
-    WsReconnectConfig,
+    BackoffStrategy, BookManager, DeltaOutcome, DisconnectAction, ExponentialBackoff,
+    FeeAccumulator, FillDeduper, FillUpdate, KalshiWsClient, KalshiWsLowLevelClient, OrderBook,
+    SnapshotCache, TopOfBook, WsEvent, WsEventReceiver, WsFrameTap, WsMetrics, WsProxy,
+    WsReaderConfig, WsReaderMode, WsReconnectConfig, WsSubscriptionDrift,
 };
 
 // Backwards-compatible type re-exports
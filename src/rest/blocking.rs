@@ -0,0 +1,203 @@
+//! Blocking facade over [`KalshiRestClient`], enabled with the `blocking` feature.
+//!
+//! [`KalshiRestClientSync`] drives the async client on an internal
+//! current-thread Tokio runtime, the same way `reqwest::blocking` does. Every
+//! request method keeps its async name and arguments and returns
+//! `Result<T, KalshiError>` directly.
+//!
+//! This is meant for scripts, notebooks, and tooling. Each call blocks the
+//! calling thread for the full request (including rate-limit waits and
+//! retries), so prefer [`KalshiRestClient`] for high-throughput use.
+//!
+//! Calling these methods from inside an async runtime panics, as with any
+//! nested `block_on`.
+//!
+//! ```no_run
+//! use kalshi_fast::{GetMarketsParams, KalshiEnvironment, KalshiRestClient, KalshiRestClientSync};
+//!
+//! # fn main() -> Result<(), kalshi_fast::KalshiError> {
+//! let client = KalshiRestClientSync::new(KalshiRestClient::new(KalshiEnvironment::demo()))?;
+//! let markets = client.get_markets(GetMarketsParams::default())?;
+//! println!("{} markets", markets.markets.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::KalshiError;
+use crate::rest::client::KalshiRestClient;
+use crate::rest::types::*;
+use crate::ws::OrderBook;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+/// Synchronous wrapper around [`KalshiRestClient`]. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct KalshiRestClientSync {
+    inner: KalshiRestClient,
+    runtime: Arc<Runtime>,
+}
+
+impl KalshiRestClientSync {
+    /// Wrap an async client, starting the internal runtime.
+    pub fn new(client: KalshiRestClient) -> Result<Self, KalshiError> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The wrapped async client.
+    pub fn inner(&self) -> &KalshiRestClient {
+        &self.inner
+    }
+
+    /// Run any future built from the async client to completion.
+    ///
+    /// Escape hatch for methods without a blocking wrapper (pagers, streams).
+    pub fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        impl KalshiRestClientSync {
+            $(
+                #[doc = concat!("Blocking [`KalshiRestClient::", stringify!($name), "`].")]
+                pub fn $name(&self $(, $arg: $ty)*) -> Result<$ret, KalshiError> {
+                    self.runtime.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    fn get_series_list(&self, params: GetSeriesListParams) -> GetSeriesListResponse;
+    fn get_series(&self, series_ticker: &str) -> GetSeriesResponse;
+    fn get_events(&self, params: GetEventsParams) -> GetEventsResponse;
+    fn get_series_events(&self, series_ticker: &str, params: GetEventsParams) -> GetEventsResponse;
+    fn get_event(&self, event_ticker: &str, with_nested_markets: Option<bool>) -> GetEventResponse;
+    fn get_markets(&self, params: GetMarketsParams) -> GetMarketsResponse;
+    fn get_market(&self, market_ticker: &str) -> GetMarketResponse;
+    fn get_historical_market(&self, market_ticker: &str) -> GetMarketResponse;
+    fn get_market_orderbook(&self, market_ticker: &str, depth: Option<u32>) -> GetMarketOrderbookResponse;
+    fn get_order_book(&self, market_ticker: &str, depth: Option<u32>) -> OrderBook;
+    fn get_trades(&self, params: GetTradesParams) -> GetTradesResponse;
+    fn get_historical_fills(&self, params: GetHistoricalFillsParams) -> GetFillsResponse;
+    fn get_historical_orders(&self, params: GetHistoricalOrdersParams) -> GetOrdersResponse;
+    fn get_historical_markets(&self, params: GetHistoricalMarketsParams) -> GetMarketsResponse;
+    fn get_historical_cutoff(&self) -> GetHistoricalCutoffResponse;
+    fn get_exchange_status(&self) -> GetExchangeStatusResponse;
+    fn wait_for_trading_active(&self, poll_interval: Duration, timeout: Duration) -> GetExchangeStatusResponse;
+    fn get_exchange_announcements(&self) -> GetExchangeAnnouncementsResponse;
+    fn get_exchange_schedule(&self) -> GetExchangeScheduleResponse;
+    fn get_user_data_timestamp(&self) -> GetUserDataTimestampResponse;
+    fn get_series_fee_changes(&self, params: GetSeriesFeeChangesParams) -> GetSeriesFeeChangesResponse;
+    fn get_balance(&self) -> GetBalanceResponse;
+    fn get_positions(&self, params: GetPositionsParams) -> GetPositionsResponse;
+    fn get_unsettled_positions(&self, params: GetPositionsParams) -> PositionsPage;
+    fn get_orders(&self, params: GetOrdersParams) -> GetOrdersResponse;
+    fn create_order(&self, body: CreateOrderRequest) -> CreateOrderResponse;
+    fn cancel_order(&self, order_id: &str, params: CancelOrderParams) -> CancelOrderResponse;
+    fn get_fills(&self, params: GetFillsParams) -> GetFillsResponse;
+    fn get_settlements(&self, params: GetSettlementsParams) -> GetSettlementsResponse;
+    fn get_account_api_limits(&self) -> GetAccountApiLimitsResponse;
+    fn create_subaccount(&self) -> CreateSubaccountResponse;
+    fn get_subaccount_balances(&self) -> GetSubaccountBalancesResponse;
+    fn transfer_subaccount(&self, body: ApplySubaccountTransferRequest) -> ApplySubaccountTransferResponse;
+    fn get_subaccount_transfers(&self, params: GetSubaccountTransfersParams) -> GetSubaccountTransfersResponse;
+    fn get_subaccount_netting(&self) -> GetSubaccountNettingResponse;
+    fn update_subaccount_netting(&self, body: UpdateSubaccountNettingRequest) -> EmptyResponse;
+    fn get_api_keys(&self) -> GetApiKeysResponse;
+    fn create_api_key(&self, body: CreateApiKeyRequest) -> CreateApiKeyResponse;
+    fn generate_api_key(&self, body: GenerateApiKeyRequest) -> GenerateApiKeyResponse;
+    fn delete_api_key(&self, api_key: &str) -> EmptyResponse;
+    fn get_communications_id(&self) -> GetCommunicationsIdResponse;
+    fn get_rfqs(&self, params: GetRFQsParams) -> GetRFQsResponse;
+    fn create_rfq(&self, body: CreateRFQRequest) -> CreateRFQResponse;
+    fn get_rfq(&self, rfq_id: &str) -> GetRFQResponse;
+    fn delete_rfq(&self, rfq_id: &str) -> EmptyResponse;
+    fn get_quotes(&self, params: GetQuotesParams) -> GetQuotesResponse;
+    fn create_quote(&self, body: CreateQuoteRequest) -> CreateQuoteResponse;
+    fn get_quote(&self, quote_id: &str) -> GetQuoteResponse;
+    fn delete_quote(&self, quote_id: &str) -> EmptyResponse;
+    fn accept_quote(&self, quote_id: &str, body: AcceptQuoteRequest) -> EmptyResponse;
+    fn confirm_quote(&self, quote_id: &str) -> EmptyResponse;
+    fn get_multivariate_events(&self, params: GetMultivariateEventsParams) -> GetMultivariateEventsResponse;
+    fn get_event_metadata(&self, event_ticker: &str) -> GetEventMetadataResponse;
+    fn get_incentive_programs(&self, params: GetIncentiveProgramsParams) -> GetIncentiveProgramsResponse;
+    fn get_live_data_batch(&self, params: GetLiveDatasParams) -> GetLiveDatasResponse;
+    fn get_live_data(&self, live_data_type: &str, milestone_id: &str) -> GetLiveDataResponse;
+    fn batch_get_market_candlesticks(&self, params: BatchGetMarketCandlesticksParams) -> BatchGetMarketCandlesticksResponse;
+    fn get_milestones(&self, params: GetMilestonesParams) -> GetMilestonesResponse;
+    fn get_milestone(&self, milestone_id: &str) -> GetMilestoneResponse;
+    fn get_multivariate_event_collections(&self, params: GetMultivariateEventCollectionsParams) -> GetMultivariateEventCollectionsResponse;
+    fn get_multivariate_event_collection(&self, collection_ticker: &str) -> GetMultivariateEventCollectionResponse;
+    fn create_market_in_multivariate_event_collection(&self, collection_ticker: &str, body: CreateMarketInMultivariateEventCollectionRequest) -> CreateMarketInMultivariateEventCollectionResponse;
+    fn get_multivariate_event_collection_lookup_history(&self, collection_ticker: &str, params: GetMultivariateEventCollectionLookupHistoryParams) -> GetMultivariateEventCollectionLookupHistoryResponse;
+    fn lookup_tickers_for_market_in_multivariate_event_collection(&self, collection_ticker: &str, body: LookupTickersForMarketInMultivariateEventCollectionRequest) -> LookupTickersForMarketInMultivariateEventCollectionResponse;
+    fn get_historical_market_candlesticks(&self, ticker: &str, params: GetMarketCandlesticksHistoricalParams) -> GetMarketCandlesticksHistoricalResponse;
+    fn get_market_candlesticks(&self, series_ticker: &str, ticker: &str, params: GetMarketCandlesticksParams) -> GetMarketCandlesticksResponse;
+    fn get_event_market_candlesticks(&self, series_ticker: &str, ticker: &str, params: GetEventCandlesticksParams) -> GetEventCandlesticksResponse;
+    fn get_event_forecast_percentile_history(&self, series_ticker: &str, ticker: &str, params: GetEventForecastPercentileHistoryParams) -> GetEventForecastPercentilesHistoryResponse;
+    fn get_structured_targets(&self, params: GetStructuredTargetsParams) -> GetStructuredTargetsResponse;
+    fn get_structured_target(&self, structured_target_id: &str) -> GetStructuredTargetResponse;
+    fn get_fcm_orders(&self, params: GetFcmOrdersParams) -> GetFcmOrdersResponse;
+    fn get_fcm_positions(&self, params: GetFcmPositionsParams) -> GetFcmPositionsResponse;
+    fn get_order_groups(&self, params: SubaccountQueryParams) -> GetOrderGroupsResponse;
+    fn create_order_group(&self, body: CreateOrderGroupRequest) -> CreateOrderGroupResponse;
+    fn get_order_group(&self, order_group_id: &str, params: SubaccountQueryParams) -> GetOrderGroupResponse;
+    fn delete_order_group(&self, order_group_id: &str, params: SubaccountQueryParams) -> EmptyResponse;
+    fn update_order_group_limit(&self, order_group_id: &str, body: UpdateOrderGroupLimitRequest) -> EmptyResponse;
+    fn reset_order_group(&self, order_group_id: &str, params: SubaccountQueryParams) -> EmptyResponse;
+    fn trigger_order_group(&self, order_group_id: &str, params: SubaccountQueryParams) -> EmptyResponse;
+    fn batch_create_orders(&self, body: BatchCreateOrdersRequest) -> BatchCreateOrdersResponse;
+    fn batch_cancel_orders(&self, body: BatchCancelOrdersRequest) -> BatchCancelOrdersResponse;
+    fn cancel_all_orders(&self) -> Vec<BatchCancelOrdersIndividualResponse>;
+    fn get_order(&self, order_id: &str) -> GetOrderResponse;
+    fn amend_order(&self, order_id: &str, body: AmendOrderRequest) -> AmendOrderResponse;
+    fn decrease_order(&self, order_id: &str, body: DecreaseOrderRequest) -> DecreaseOrderResponse;
+    fn get_order_queue_positions(&self, params: GetOrderQueuePositionsParams) -> GetOrderQueuePositionsResponse;
+    fn get_order_queue_position(&self, order_id: &str) -> GetOrderQueuePositionResponse;
+    fn get_portfolio_total_resting_order_value(&self) -> GetPortfolioRestingOrderTotalValueResponse;
+    fn get_tags_by_categories(&self) -> GetTagsForSeriesCategoriesResponse;
+    fn get_filters_by_sport(&self) -> GetFiltersBySportsResponse;
+    fn get_markets_all(&self, params: GetMarketsParams) -> Vec<Market>;
+    fn get_markets_sorted(&self, params: GetMarketsParams, key: MarketSortKey) -> Vec<Market>;
+    fn get_events_all(&self, params: GetEventsParams) -> Vec<EventData>;
+    fn get_events_by_category(&self, category: &str, params: GetEventsParams) -> Vec<EventData>;
+    fn get_trades_all(&self, params: GetTradesParams) -> Vec<Trade>;
+    fn get_subaccount_transfers_all(&self, params: GetSubaccountTransfersParams) -> Vec<SubaccountTransfer>;
+    fn get_milestones_all(&self, params: GetMilestonesParams) -> Vec<Milestone>;
+    fn get_multivariate_events_all(&self, params: GetMultivariateEventsParams) -> Vec<EventData>;
+    fn get_multivariate_event_collections_all(&self, params: GetMultivariateEventCollectionsParams) -> Vec<MultivariateEventCollection>;
+    fn get_rfqs_all(&self, params: GetRFQsParams) -> Vec<RFQ>;
+    fn get_quotes_all(&self, params: GetQuotesParams) -> Vec<Quote>;
+    fn get_structured_targets_all(&self, params: GetStructuredTargetsParams) -> Vec<StructuredTarget>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KalshiEnvironment;
+
+    #[test]
+    fn blocking_client_surfaces_validation_errors() {
+        let client =
+            KalshiRestClientSync::new(KalshiRestClient::new(KalshiEnvironment::demo())).unwrap();
+        let err = client
+            .get_series_events(
+                "KXFED",
+                GetEventsParams {
+                    series_ticker: Some("KXCPI".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, KalshiError::InvalidParams(_)));
+    }
+}
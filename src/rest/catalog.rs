@@ -0,0 +1,256 @@
+use crate::error::KalshiError;
+use crate::rest::KalshiRestClient;
+use crate::rest::types::{Market, Series};
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::time::{Duration, Instant};
+
+/// Cached `Market` and `Series` metadata shared across tasks.
+///
+/// Lookups fetch with [`KalshiRestClient::get_market`] or
+/// [`KalshiRestClient::get_series`] and keep the result for `ttl`.
+/// Concurrent lookups of the same ticker wait on a single request; if it
+/// fails, the error goes to the caller that made it and the next waiter
+/// retries. Clones share the cache. Each cache holds at most
+/// [`max_entries`](Self::with_max_entries) tickers: when a new ticker would
+/// exceed that, expired entries are dropped first, then the oldest ones.
+/// [`invalidate_market`](Self::invalidate_market),
+/// [`invalidate_series`](Self::invalidate_series), and [`clear`](Self::clear)
+/// drop entries on demand.
+#[derive(Debug, Clone)]
+pub struct MarketCatalog {
+    client: KalshiRestClient,
+    ttl: Duration,
+    max_entries: usize,
+    markets: Arc<Cache<Market>>,
+    series: Arc<Cache<Series>>,
+}
+
+/// Default cap on cached markets, and separately on cached series.
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+type Slot<T> = Arc<Mutex<Option<(T, Instant)>>>;
+
+#[derive(Debug)]
+struct Cache<T> {
+    slots: std::sync::Mutex<HashMap<String, Slot<T>>>,
+}
+
+impl<T: Clone> Cache<T> {
+    fn new() -> Self {
+        Self {
+            slots: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_or_fetch<F, Fut>(
+        &self,
+        key: &str,
+        ttl: Duration,
+        max_entries: usize,
+        fetch: F,
+    ) -> Result<T, KalshiError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, KalshiError>>,
+    {
+        let slot = {
+            let mut slots = self.slots.lock().expect("catalog cache lock poisoned");
+            match slots.get(key) {
+                Some(slot) => slot.clone(),
+                None => {
+                    if slots.len() >= max_entries {
+                        evict(&mut slots, ttl, max_entries);
+                    }
+                    slots.entry(key.to_string()).or_default().clone()
+                }
+            }
+        };
+        let mut entry = slot.lock().await;
+        if let Some((value, fetched)) = entry.as_ref()
+            && fetched.elapsed() < ttl
+        {
+            return Ok(value.clone());
+        }
+        let value = fetch().await?;
+        *entry = Some((value.clone(), Instant::now()));
+        Ok(value)
+    }
+
+    fn remove(&self, key: &str) {
+        self.slots
+            .lock()
+            .expect("catalog cache lock poisoned")
+            .remove(key);
+    }
+
+    fn clear(&self) {
+        self.slots
+            .lock()
+            .expect("catalog cache lock poisoned")
+            .clear();
+    }
+}
+
+/// Make room for one more slot: drop expired or empty slots, then the oldest
+/// ones. Slots with a fetch in flight are kept.
+fn evict<T>(slots: &mut HashMap<String, Slot<T>>, ttl: Duration, max_entries: usize) {
+    slots.retain(|_, slot| match slot.try_lock() {
+        Ok(entry) => entry
+            .as_ref()
+            .is_some_and(|(_, fetched)| fetched.elapsed() < ttl),
+        Err(_) => true,
+    });
+    let excess = (slots.len() + 1).saturating_sub(max_entries);
+    if excess == 0 {
+        return;
+    }
+    let mut idle: Vec<(Instant, String)> = slots
+        .iter()
+        .filter_map(|(key, slot)| {
+            let entry = slot.try_lock().ok()?;
+            entry.as_ref().map(|(_, fetched)| (*fetched, key.clone()))
+        })
+        .collect();
+    idle.sort_unstable();
+    for (_, key) in idle.into_iter().take(excess) {
+        slots.remove(&key);
+    }
+}
+
+impl MarketCatalog {
+    /// Cache lookups made through `client` for `ttl`.
+    pub fn new(client: KalshiRestClient, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            markets: Arc::new(Cache::new()),
+            series: Arc::new(Cache::new()),
+        }
+    }
+
+    /// Cap each cache at `max` tickers (default 10,000, at least 1).
+    pub fn with_max_entries(mut self, max: usize) -> Self {
+        self.max_entries = max.max(1);
+        self
+    }
+
+    /// The market for `ticker`, fetched if missing or older than the TTL.
+    pub async fn market(&self, ticker: &str) -> Result<Market, KalshiError> {
+        self.markets
+            .get_or_fetch(ticker, self.ttl, self.max_entries, || async {
+                Ok(self.client.get_market(ticker).await?.market)
+            })
+            .await
+    }
+
+    /// The series for `series_ticker`, fetched if missing or older than the TTL.
+    pub async fn series(&self, series_ticker: &str) -> Result<Series, KalshiError> {
+        self.series
+            .get_or_fetch(series_ticker, self.ttl, self.max_entries, || async {
+                Ok(self.client.get_series(series_ticker).await?.series)
+            })
+            .await
+    }
+
+    /// Drop the cached market so the next lookup refetches it.
+    pub fn invalidate_market(&self, ticker: &str) {
+        self.markets.remove(ticker);
+    }
+
+    /// Drop the cached series so the next lookup refetches it.
+    pub fn invalidate_series(&self, series_ticker: &str) {
+        self.series.remove(series_ticker);
+    }
+
+    /// Drop every cached market and series.
+    pub fn clear(&self) {
+        self.markets.clear();
+        self.series.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KalshiEnvironment;
+    use crate::rest::MockTransport;
+    use reqwest::StatusCode;
+    use url::Url;
+
+    fn mock_client(mock: &MockTransport) -> KalshiRestClient {
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://mock/").expect("url"),
+            ws_url: "ws://127.0.0.1/".to_string(),
+        };
+        KalshiRestClient::builder(env)
+            .with_transport(mock.clone())
+            .build()
+            .expect("client")
+    }
+
+    fn market_body(ticker: &str) -> String {
+        format!(r#"{{"market":{{"ticker":"{ticker}","event_ticker":"EVT-1","status":"active"}}}}"#)
+    }
+
+    #[tokio::test]
+    async fn coalesces_lookups_and_refetches_after_invalidate() {
+        let mock = MockTransport::new();
+        mock.push_response(StatusCode::OK, market_body("MKT-1"))
+            .push_response(StatusCode::OK, r#"{"series":{"ticker":"SER"}}"#)
+            .push_response(StatusCode::OK, market_body("MKT-1"));
+
+        let catalog = MarketCatalog::new(mock_client(&mock), Duration::from_secs(60));
+        let lookups = (0..8).map(|_| catalog.market("MKT-1"));
+        for market in futures::future::join_all(lookups).await {
+            assert_eq!(market.expect("market").ticker, "MKT-1");
+        }
+        assert_eq!(mock.requests().len(), 1);
+
+        assert_eq!(catalog.series("SER").await.expect("series").ticker, "SER");
+        assert_eq!(catalog.series("SER").await.expect("series").ticker, "SER");
+        catalog.invalidate_market("MKT-1");
+        catalog.market("MKT-1").await.expect("refetched");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].url.path(), "/trade-api/v2/series/SER");
+    }
+
+    #[tokio::test]
+    async fn evicts_the_oldest_ticker_past_max_entries() {
+        let mock = MockTransport::new();
+        for ticker in ["A", "B", "C", "A"] {
+            mock.push_response(StatusCode::OK, market_body(ticker));
+        }
+
+        let catalog =
+            MarketCatalog::new(mock_client(&mock), Duration::from_secs(60)).with_max_entries(2);
+        catalog.market("A").await.expect("A");
+        catalog.market("B").await.expect("B");
+        catalog.market("C").await.expect("C");
+        assert_eq!(catalog.markets.slots.lock().expect("lock").len(), 2);
+
+        // B is still cached; A was the oldest and is fetched again.
+        catalog.market("B").await.expect("B cached");
+        catalog.market("A").await.expect("A refetched");
+        let paths: Vec<_> = mock
+            .requests()
+            .iter()
+            .map(|r| r.url.path().to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/trade-api/v2/markets/A",
+                "/trade-api/v2/markets/B",
+                "/trade-api/v2/markets/C",
+                "/trade-api/v2/markets/A",
+            ]
+        );
+    }
+}
@@ -1,17 +1,24 @@
+use crate::error::{retryable_reqwest_error, retryable_status};
+use crate::rest::lenient::{
+    Lenient, collect_warnings, collecting_warnings, decode_dropping_invalid, record_warnings,
+};
+use crate::rest::transport::{ReqwestTransport, Transport, TransportRequest, request_id};
 use crate::rest::types::*;
-use crate::types::ErrorResponse;
+use crate::types::{ErrorResponse, OrderStatus, parse_rfc3339_unix_seconds};
+use crate::ws::OrderBook;
 use crate::{KalshiAuth, KalshiEnvironment, KalshiError, REST_PREFIX};
 
 use futures::future::BoxFuture;
-use futures::stream::{self, Stream};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 use rand::random;
-use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, RETRY_AFTER, USER_AGENT};
 use reqwest::{Client, Method, Proxy, StatusCode};
 use serde::{Serialize, de::DeserializeOwned};
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
-use std::time::SystemTime;
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
 use tokio::time::{Duration, Instant, sleep};
 use url::Url;
 
@@ -20,15 +27,33 @@ use url::Url;
 /// The Kalshi API enforces separate rate limits for reads and writes.
 /// Set either value to `0` to disable throttling for that category.
 ///
+/// Each category is a token bucket that holds up to `*_burst` requests and
+/// refills at `*_rps`: after a quiet period that many requests go out at
+/// once, and the long-run rate still averages `*_rps`. A burst of `0` or `1`
+/// spaces every request by `1 / rps`.
+///
+/// When responses carry `x-ratelimit-remaining` and `x-ratelimit-reset`
+/// headers and fewer than `adaptive_threshold` requests remain, the client
+/// spreads the remaining budget evenly until the reset; see
+/// [`KalshiRestClient::rate_limit_state`].
+///
 /// # Default
 ///
-/// The default matches the **Basic** tier: 20 read RPS, 10 write RPS.
+/// The default matches the **Basic** tier: 20 read RPS, 10 write RPS, no
+/// burst, adaptive slowdown below 2 remaining requests.
 #[derive(Debug, Clone, Copy)]
 pub struct RateLimitConfig {
     /// Maximum GET requests per second (0 = unlimited).
     pub read_rps: u32,
     /// Maximum POST/DELETE requests per second (0 = unlimited).
     pub write_rps: u32,
+    /// GET requests that may go out back to back when the bucket is full.
+    pub read_burst: u32,
+    /// POST/DELETE requests that may go out back to back when the bucket is full.
+    pub write_burst: u32,
+    /// Slow down when the server reports fewer remaining requests than this
+    /// (0 = ignore rate-limit headers).
+    pub adaptive_threshold: u32,
 }
 
 impl Default for RateLimitConfig {
@@ -37,23 +62,73 @@ impl Default for RateLimitConfig {
         Self {
             read_rps: 20,
             write_rps: 10,
+            read_burst: 1,
+            write_burst: 1,
+            adaptive_threshold: 2,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Limits reported by `GET /account/limits`, clamped to at least 1 RPS.
+    pub fn from_api_limits(limits: &GetAccountApiLimitsResponse) -> Self {
+        let rps = |limit: i64| u32::try_from(limit.max(1)).unwrap_or(u32::MAX);
+        Self {
+            read_rps: rps(limits.read_limit),
+            write_rps: rps(limits.write_limit),
+            ..Self::default()
         }
     }
 }
 
+/// Caps on in-flight REST requests, complementing [`RateLimitConfig`].
+///
+/// The rate limiter spaces requests in time; this bounds how many run at
+/// once, so a burst of spawned tasks cannot open an unbounded number of
+/// sockets. Set either value to `0` to leave that category unbounded.
+///
+/// # Default
+///
+/// Unbounded for both reads and writes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConcurrencyConfig {
+    /// Maximum in-flight GET requests (0 = unlimited).
+    pub max_concurrent_reads: usize,
+    /// Maximum in-flight POST/PUT/DELETE requests (0 = unlimited).
+    pub max_concurrent_writes: usize,
+}
+
 /// Named rate-limit tier matching Kalshi's published API tiers.
 ///
 /// Pass to [`KalshiRestClient::with_rate_limit_tier`] for quick configuration.
-#[derive(Debug, Clone, Copy)]
+/// If your account's limits differ, use
+/// [`RateLimitConfig::from_api_limits`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RateLimitTier {
     /// 20 read RPS, 10 write RPS.
     Basic,
+    /// 30 read RPS, 30 write RPS.
+    Advanced,
+    /// 100 read RPS, 100 write RPS.
+    Premier,
 }
 
 impl RateLimitTier {
-    fn config(self) -> RateLimitConfig {
+    /// The read/write limits for this tier.
+    pub fn config(self) -> RateLimitConfig {
         match self {
             RateLimitTier::Basic => RateLimitConfig::default(),
+            RateLimitTier::Advanced => RateLimitConfig {
+                read_rps: 30,
+                write_rps: 30,
+                ..RateLimitConfig::default()
+            },
+            RateLimitTier::Premier => RateLimitConfig {
+                read_rps: 100,
+                write_rps: 100,
+                ..RateLimitConfig::default()
+            },
         }
     }
 }
@@ -133,6 +208,7 @@ fn build_http_error(
     status: reqwest::StatusCode,
     bytes: &[u8],
     request_id: Option<String>,
+    retry_after: Option<Duration>,
 ) -> KalshiError {
     #[derive(serde::Deserialize)]
     struct WrappedErrorBody {
@@ -164,26 +240,10 @@ fn build_http_error(
         api_error,
         raw_body,
         request_id,
+        retry_after,
     }
 }
 
-fn retryable_status(status: StatusCode) -> bool {
-    matches!(
-        status,
-        StatusCode::REQUEST_TIMEOUT
-            | StatusCode::TOO_EARLY
-            | StatusCode::TOO_MANY_REQUESTS
-            | StatusCode::INTERNAL_SERVER_ERROR
-            | StatusCode::BAD_GATEWAY
-            | StatusCode::SERVICE_UNAVAILABLE
-            | StatusCode::GATEWAY_TIMEOUT
-    )
-}
-
-fn retryable_reqwest_error(err: &reqwest::Error) -> bool {
-    err.is_timeout() || err.is_connect() || err.is_request()
-}
-
 fn retry_after_delay(headers: &HeaderMap) -> Option<Duration> {
     let value = headers.get(RETRY_AFTER)?;
     let text = value.to_str().ok()?.trim();
@@ -198,60 +258,211 @@ fn retry_after_delay(headers: &HeaderMap) -> Option<Duration> {
     Some(delta)
 }
 
-#[derive(Debug)]
-struct RateLimiter {
-    read: Mutex<Instant>,
-    write: Mutex<Instant>,
-    read_interval: Duration,
-    write_interval: Duration,
+/// The server's latest rate-limit headers for one kind of request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitObservation {
+    /// Requests left in the current window (`x-ratelimit-remaining`).
+    pub remaining: u64,
+    /// Window size, if sent (`x-ratelimit-limit`).
+    pub limit: Option<u64>,
+    /// When the window resets, if sent (`x-ratelimit-reset`).
+    pub reset_at: Option<Instant>,
+    /// When the response carrying these headers arrived.
+    pub observed_at: Instant,
 }
 
-impl RateLimiter {
-    fn new(config: RateLimitConfig) -> Self {
-        let read_interval = if config.read_rps == 0 {
-            Duration::from_secs(0)
+/// Latest server-reported headroom, returned by
+/// [`KalshiRestClient::rate_limit_state`]. `None` until a response of that
+/// kind carried rate-limit headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitState {
+    pub read: Option<RateLimitObservation>,
+    pub write: Option<RateLimitObservation>,
+}
+
+/// Rate-limit header names, prefixed form first.
+const RATE_LIMIT_REMAINING: [&str; 2] = ["x-ratelimit-remaining", "ratelimit-remaining"];
+const RATE_LIMIT_RESET: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+const RATE_LIMIT_LIMIT: [&str; 2] = ["x-ratelimit-limit", "ratelimit-limit"];
+
+/// Reads `x-ratelimit-*` (or unprefixed `ratelimit-*`) headers. The reset is
+/// seconds from now, or a Unix timestamp in seconds if it is that large.
+fn parse_rate_limit_headers(headers: &HeaderMap, now: Instant) -> Option<RateLimitObservation> {
+    let number = |names: [&'static str; 2]| {
+        names
+            .into_iter()
+            .find_map(|name| headers.get(name))
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|v| v.is_finite() && *v >= 0.0)
+    };
+    let remaining = number(RATE_LIMIT_REMAINING)? as u64;
+    let reset_at = number(RATE_LIMIT_RESET).map(|reset| {
+        let secs = if reset >= 1_000_000_000.0 {
+            let epoch = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64();
+            (reset - epoch).max(0.0)
         } else {
-            Duration::from_secs_f64(1.0 / config.read_rps as f64)
+            reset
         };
-        let write_interval = if config.write_rps == 0 {
+        now + Duration::from_secs_f64(secs)
+    });
+    Some(RateLimitObservation {
+        remaining,
+        limit: number(RATE_LIMIT_LIMIT).map(|v| v as u64),
+        reset_at,
+        observed_at: now,
+    })
+}
+
+/// Token bucket per kind. Uses Tokio's clock, so tests can drive it with
+/// `tokio::time::pause` / `advance`.
+#[derive(Debug)]
+struct RateLimiter {
+    read: Bucket,
+    write: Bucket,
+    adaptive_threshold: u32,
+}
+
+/// A token bucket tracked as the time it would next be empty (GCRA), so a
+/// caller reserves its slot under the lock and sleeps outside it.
+#[derive(Debug)]
+struct Bucket {
+    /// When the bucket runs dry if no more tokens are taken.
+    empty_at: Mutex<Instant>,
+    /// Time to refill one token; zero disables the static limit.
+    interval: Duration,
+    /// How far `empty_at` may run ahead of now: `(burst - 1) * interval`.
+    tolerance: Duration,
+    /// Set while `observe` holds an unlimited bucket back, so `wait` knows
+    /// to take the lock.
+    held_back: AtomicBool,
+    observed: std::sync::Mutex<Option<RateLimitObservation>>,
+}
+
+impl Bucket {
+    fn new(rps: u32, burst: u32) -> Self {
+        let interval = if rps == 0 {
             Duration::from_secs(0)
         } else {
-            Duration::from_secs_f64(1.0 / config.write_rps as f64)
+            Duration::from_secs_f64(1.0 / rps as f64)
         };
+        Self {
+            empty_at: Mutex::new(Instant::now()),
+            interval,
+            tolerance: interval * burst.saturating_sub(1),
+            held_back: AtomicBool::new(false),
+            observed: std::sync::Mutex::new(None),
+        }
+    }
 
+    async fn wait(&self) {
+        if self.interval.is_zero() && !self.held_back.load(Ordering::Acquire) {
+            return;
+        }
+        let mut empty_at = self.empty_at.lock().await;
         let now = Instant::now();
+        if *empty_at <= now {
+            self.held_back.store(false, Ordering::Release);
+        }
+        let start = (*empty_at).max(now);
+        let ready = start.checked_sub(self.tolerance).unwrap_or(now).max(now);
+        *empty_at = start + self.interval;
+        drop(empty_at);
+
+        if ready > now {
+            tokio::time::sleep(ready - now).await;
+        }
+    }
+
+    /// Record the headers and, below `threshold`, hold the next request
+    /// back so the remaining budget lasts until the reset.
+    async fn observe(&self, observation: RateLimitObservation, threshold: u32) {
+        *self.observed.lock().expect("rate limit state lock") = Some(observation);
+        let Some(reset_at) = observation.reset_at else {
+            return;
+        };
+        if observation.remaining >= u64::from(threshold) {
+            return;
+        }
+        let now = observation.observed_at;
+        let spacing =
+            (reset_at.saturating_duration_since(now)).div_f64(observation.remaining as f64 + 1.0);
+        let not_before = now + spacing + self.tolerance;
+        let mut empty_at = self.empty_at.lock().await;
+        *empty_at = (*empty_at).max(not_before);
+        self.held_back.store(true, Ordering::Release);
+    }
+
+    fn observed(&self) -> Option<RateLimitObservation> {
+        *self.observed.lock().expect("rate limit state lock")
+    }
+}
+
+impl RateLimiter {
+    fn new(config: RateLimitConfig) -> Self {
         Self {
-            read: Mutex::new(now - read_interval),
-            write: Mutex::new(now - write_interval),
-            read_interval,
-            write_interval,
+            read: Bucket::new(config.read_rps, config.read_burst),
+            write: Bucket::new(config.write_rps, config.write_burst),
+            adaptive_threshold: config.adaptive_threshold,
+        }
+    }
+
+    fn bucket(&self, kind: RateLimitKind) -> &Bucket {
+        match kind {
+            RateLimitKind::Read => &self.read,
+            RateLimitKind::Write => &self.write,
         }
     }
 
     async fn wait(&self, kind: RateLimitKind) {
-        let (lock, interval) = match kind {
-            RateLimitKind::Read => (&self.read, self.read_interval),
-            RateLimitKind::Write => (&self.write, self.write_interval),
-        };
+        self.bucket(kind).wait().await;
+    }
 
-        if interval.is_zero() {
+    async fn observe(&self, kind: RateLimitKind, headers: &HeaderMap) {
+        if self.adaptive_threshold == 0 {
             return;
         }
+        if let Some(observation) = parse_rate_limit_headers(headers, Instant::now()) {
+            self.bucket(kind)
+                .observe(observation, self.adaptive_threshold)
+                .await;
+        }
+    }
 
-        let mut last = lock.lock().await;
-        let now = Instant::now();
-        let scheduled = if *last + interval > now {
-            *last + interval
-        } else {
-            now
-        };
-        *last = scheduled;
-        drop(last);
+    fn state(&self) -> RateLimitState {
+        RateLimitState {
+            read: self.read.observed(),
+            write: self.write.observed(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ConcurrencyLimiter {
+    read: Option<Semaphore>,
+    write: Option<Semaphore>,
+}
 
-        if scheduled > now {
-            tokio::time::sleep(scheduled - now).await;
+impl ConcurrencyLimiter {
+    fn new(config: ConcurrencyConfig) -> Self {
+        let semaphore = |max: usize| (max > 0).then(|| Semaphore::new(max));
+        Self {
+            read: semaphore(config.max_concurrent_reads),
+            write: semaphore(config.max_concurrent_writes),
         }
     }
+
+    async fn acquire(&self, kind: RateLimitKind) -> Option<SemaphorePermit<'_>> {
+        let semaphore = match kind {
+            RateLimitKind::Read => self.read.as_ref(),
+            RateLimitKind::Write => self.write.as_ref(),
+        }?;
+        // The semaphore is never closed, so acquire cannot fail.
+        semaphore.acquire().await.ok()
+    }
 }
 
 /// Manual page-by-page cursor pagination.
@@ -263,6 +474,10 @@ impl RateLimiter {
 ///
 /// For item-by-item iteration, see the `stream_*` methods on [`KalshiRestClient`].
 ///
+/// Pagination only goes forward. No Kalshi list endpoint returns a previous
+/// cursor, so to go back, keep the cursors seen so far and start a new pager
+/// from one of them.
+///
 /// # Example
 /// ```no_run
 /// # use kalshi_fast::{KalshiEnvironment, KalshiRestClient, GetMarketsParams};
@@ -279,6 +494,9 @@ impl RateLimiter {
 pub struct CursorPager<T> {
     cursor: Option<String>,
     done: bool,
+    last_page_len: Option<usize>,
+    total_fetched: usize,
+    pages_fetched: usize,
     fetch: Box<
         dyn FnMut(
                 Option<String>,
@@ -299,6 +517,9 @@ impl<T> CursorPager<T> {
         Self {
             cursor: cursor.filter(|c| !c.is_empty()),
             done: false,
+            last_page_len: None,
+            total_fetched: 0,
+            pages_fetched: 0,
             fetch: Box::new(fetch),
         }
     }
@@ -317,6 +538,9 @@ impl<T> CursorPager<T> {
         if self.cursor.is_none() {
             self.done = true;
         }
+        self.last_page_len = Some(items.len());
+        self.total_fetched += items.len();
+        self.pages_fetched += 1;
 
         Ok(Some(items))
     }
@@ -332,6 +556,22 @@ impl<T> CursorPager<T> {
     pub fn is_done(&self) -> bool {
         self.done
     }
+
+    /// Number of items in the most recently fetched page, or `None` before
+    /// the first fetch.
+    pub fn last_page_len(&self) -> Option<usize> {
+        self.last_page_len
+    }
+
+    /// Total items fetched by this pager so far.
+    pub fn total_fetched(&self) -> usize {
+        self.total_fetched
+    }
+
+    /// Pages returned by this pager so far, including empty ones.
+    pub fn pages_fetched(&self) -> usize {
+        self.pages_fetched
+    }
 }
 
 struct StreamState<T> {
@@ -341,6 +581,30 @@ struct StreamState<T> {
     done: bool,
 }
 
+struct WatchOrderState {
+    client: KalshiRestClient,
+    order_id: String,
+    poll_interval: Duration,
+    last: Option<OrderUpdate>,
+    done: bool,
+}
+
+fn scope_to_series(
+    series_ticker: &str,
+    mut params: GetEventsParams,
+) -> Result<GetEventsParams, KalshiError> {
+    match params.series_ticker.as_deref() {
+        Some(existing) if existing != series_ticker => {
+            return Err(KalshiError::InvalidParams(format!(
+                "get_series_events: series_ticker {existing:?} conflicts with {series_ticker:?}"
+            )));
+        }
+        _ => {}
+    }
+    params.series_ticker = Some(series_ticker.to_string());
+    Ok(params)
+}
+
 /// Stream items one-by-one from paginated endpoints.
 ///
 /// Streams provide lazy, item-level iteration built on [`CursorPager`].
@@ -405,12 +669,258 @@ where
     })
 }
 
+/// Like [`stream_items`], but a background task walks the cursor chain and
+/// keeps up to `prefetch` pages (at least one) queued ahead of the consumer.
+///
+/// The task stops once `max_items` have been fetched, at the end of the
+/// chain, or after the first error. Dropping the stream aborts the task and
+/// discards queued pages, so stopping early costs up to `prefetch` extra
+/// requests.
+fn stream_items_buffered<T>(
+    mut pager: CursorPager<T>,
+    max_items: Option<usize>,
+    prefetch: usize,
+) -> impl Stream<Item = Result<T, KalshiError>> + Send
+where
+    T: Send + 'static,
+{
+    // Spawn on first poll so the stream can be built outside a runtime.
+    let pages = stream::once(async move {
+        let (tx, rx) = tokio::sync::mpsc::channel(prefetch.max(1));
+        let task = tokio::spawn(async move {
+            while max_items.is_none_or(|max| pager.total_fetched() < max) {
+                let page = match pager.next_page().await {
+                    Ok(Some(items)) => Ok(items),
+                    Ok(None) => break,
+                    Err(err) => Err(err),
+                };
+                let failed = page.is_err();
+                if tx.send(page).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+        let task = AbortOnDrop(task);
+        stream::unfold((rx, task), |(mut rx, task)| async move {
+            rx.recv().await.map(|page| (page, (rx, task)))
+        })
+    })
+    .flatten();
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .take(max_items.unwrap_or(usize::MAX))
+}
+
+/// Aborts the wrapped task when dropped.
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Pages fetched from one cursor chain before the trade window is split.
+const TRADES_WINDOW_PAGES_PER_CHAIN: usize = 100;
+
+struct TradesWindowState {
+    client: KalshiRestClient,
+    ticker: String,
+    start_ts: i64,
+    /// Upper bound of the current sub-window (inclusive).
+    max_ts: i64,
+    cursor: Option<String>,
+    pages_in_chain: usize,
+    pages_per_chain: usize,
+    /// Oldest trade timestamp seen so far, with the ids seen at that second.
+    oldest_ts: Option<i64>,
+    boundary_ids: HashSet<String>,
+    buffer: VecDeque<Trade>,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+impl TradesWindowState {
+    async fn fetch_page(&mut self) -> Result<(), KalshiError> {
+        let resp = self
+            .client
+            .get_trades(GetTradesParams {
+                ticker: Some(self.ticker.clone()),
+                min_ts: Some(self.start_ts),
+                max_ts: Some(self.max_ts),
+                limit: Some(1000),
+                cursor: self.cursor.clone(),
+                ..Default::default()
+            })
+            .await?;
+
+        for trade in resp.trades {
+            let ts = trade
+                .created_time
+                .as_deref()
+                .and_then(parse_rfc3339_unix_seconds);
+            if let Some(ts) = ts {
+                if ts < self.start_ts {
+                    self.done = true;
+                    break;
+                }
+                if ts > self.max_ts {
+                    continue;
+                }
+                match self.oldest_ts {
+                    Some(oldest) if ts == oldest => {
+                        if !self.boundary_ids.insert(trade.trade_id.clone()) {
+                            continue;
+                        }
+                    }
+                    Some(oldest) if ts > oldest => {
+                        // Overlap from a split window: already yielded.
+                        continue;
+                    }
+                    _ => {
+                        self.oldest_ts = Some(ts);
+                        self.boundary_ids.clear();
+                        self.boundary_ids.insert(trade.trade_id.clone());
+                    }
+                }
+            }
+            self.buffer.push_back(trade);
+        }
+
+        self.cursor = resp.cursor.filter(|c| !c.is_empty());
+        if self.cursor.is_none() {
+            self.done = true;
+            return Ok(());
+        }
+
+        self.pages_in_chain += 1;
+        if self.pages_in_chain >= self.pages_per_chain
+            && let Some(oldest) = self.oldest_ts
+            && oldest < self.max_ts
+        {
+            // Restart with a fresh cursor chain ending at the oldest trade seen.
+            self.max_ts = oldest;
+            self.cursor = None;
+            self.pages_in_chain = 0;
+        }
+        Ok(())
+    }
+}
+
+fn stream_trades_window_inner(
+    client: KalshiRestClient,
+    ticker: String,
+    start_ts: i64,
+    end_ts: i64,
+    max_items: Option<usize>,
+    pages_per_chain: usize,
+) -> impl Stream<Item = Result<Trade, KalshiError>> + Send {
+    let state = TradesWindowState {
+        client,
+        ticker,
+        start_ts,
+        max_ts: end_ts,
+        cursor: None,
+        pages_in_chain: 0,
+        pages_per_chain: pages_per_chain.max(1),
+        oldest_ts: None,
+        boundary_ids: HashSet::new(),
+        buffer: VecDeque::new(),
+        remaining: max_items,
+        done: start_ts > end_ts,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if let Some(remaining) = state.remaining
+            && remaining == 0
+        {
+            return None;
+        }
+
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                if let Some(remaining) = state.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+            if let Err(err) = state.fetch_page().await {
+                state.done = true;
+                state.buffer.clear();
+                return Some((Err(err), state));
+            }
+        }
+    })
+}
+
+/// Request types whose `subaccount` is filled from the client default.
+trait SubaccountScoped {
+    fn apply_default_subaccount(&mut self, subaccount: u32);
+}
+
+macro_rules! subaccount_scoped {
+    ($($ty:ty),* $(,)?) => {
+        $(impl SubaccountScoped for $ty {
+            fn apply_default_subaccount(&mut self, subaccount: u32) {
+                self.subaccount.get_or_insert(subaccount);
+            }
+        })*
+    };
+}
+
+subaccount_scoped!(
+    GetPositionsParams,
+    GetOrdersParams,
+    CreateOrderRequest,
+    CancelOrderParams,
+    GetFillsParams,
+    GetSettlementsParams,
+    CreateQuoteRequest,
+    GetRFQsParams,
+    CreateRFQRequest,
+    SubaccountQueryParams,
+    CreateOrderGroupRequest,
+    BatchCancelOrdersRequestOrder,
+    AmendOrderRequest,
+    DecreaseOrderRequest,
+    GetOrderQueuePositionsParams,
+);
+
+impl SubaccountScoped for BatchCreateOrdersRequest {
+    fn apply_default_subaccount(&mut self, subaccount: u32) {
+        for order in &mut self.orders {
+            order.apply_default_subaccount(subaccount);
+        }
+    }
+}
+
+impl SubaccountScoped for BatchCancelOrdersRequest {
+    fn apply_default_subaccount(&mut self, subaccount: u32) {
+        for order in self.orders.iter_mut().flatten() {
+            order.apply_default_subaccount(subaccount);
+        }
+    }
+}
+
+/// `User-Agent` sent when none is configured.
+const DEFAULT_USER_AGENT: &str = concat!("kalshi-fast/", env!("CARGO_PKG_VERSION"));
+
+/// Tickers per `GET /markets` lookup in
+/// [`KalshiRestClient::get_unsettled_positions`].
+const POSITION_MARKETS_BATCH_SIZE: usize = 100;
+
 /// Builder for [`KalshiRestClient`] with transport and retry customization.
 #[derive(Debug, Clone)]
 pub struct KalshiRestClientBuilder {
     env: KalshiEnvironment,
     auth: Option<KalshiAuth>,
     rate_limit_config: RateLimitConfig,
+    concurrency_config: ConcurrencyConfig,
     retry_config: RetryConfig,
     timeout: Option<Duration>,
     connect_timeout: Option<Duration>,
@@ -419,6 +929,12 @@ pub struct KalshiRestClientBuilder {
     proxy: Option<Proxy>,
     proxy_error: Option<String>,
     http_client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    default_subaccount: Option<u32>,
+    decompression: bool,
+    max_response_bytes: Option<usize>,
+    lenient_parsing: bool,
+    require_auth: bool,
 }
 
 impl KalshiRestClientBuilder {
@@ -427,6 +943,7 @@ impl KalshiRestClientBuilder {
             env,
             auth: None,
             rate_limit_config: RateLimitConfig::default(),
+            concurrency_config: ConcurrencyConfig::default(),
             retry_config: RetryConfig::default(),
             timeout: None,
             connect_timeout: None,
@@ -435,6 +952,12 @@ impl KalshiRestClientBuilder {
             proxy: None,
             proxy_error: None,
             http_client: None,
+            transport: None,
+            default_subaccount: None,
+            decompression: true,
+            max_response_bytes: None,
+            lenient_parsing: false,
+            require_auth: false,
         }
     }
 
@@ -443,11 +966,31 @@ impl KalshiRestClientBuilder {
         self
     }
 
+    /// Make [`build`](Self::build) fail with [`KalshiError::AuthRequired`]
+    /// unless [`with_auth`](Self::with_auth) was called, for apps that only
+    /// use authenticated endpoints.
+    pub fn require_auth(mut self) -> Self {
+        self.require_auth = true;
+        self
+    }
+
+    /// Use the limits of a known tier. See [`RateLimitTier`].
+    pub fn with_rate_limit_tier(mut self, tier: RateLimitTier) -> Self {
+        self.rate_limit_config = tier.config();
+        self
+    }
+
     pub fn with_rate_limit_config(mut self, config: RateLimitConfig) -> Self {
         self.rate_limit_config = config;
         self
     }
 
+    /// Bound in-flight requests per category. See [`ConcurrencyConfig`].
+    pub fn with_concurrency_config(mut self, config: ConcurrencyConfig) -> Self {
+        self.concurrency_config = config;
+        self
+    }
+
     pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
         self.retry_config = config;
         self
@@ -463,16 +1006,26 @@ impl KalshiRestClientBuilder {
         self
     }
 
+    /// Override the `User-Agent` (default `kalshi-fast/<version>`).
     pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
         self.user_agent = Some(user_agent.into());
         self
     }
 
+    /// Headers sent with every request. Auth headers take precedence.
     pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
         self.default_headers = Some(headers);
         self
     }
 
+    /// Add a single header sent with every request. Auth headers take precedence.
+    pub fn with_default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers
+            .get_or_insert_with(HeaderMap::new)
+            .insert(name, value);
+        self
+    }
+
     /// Configure an HTTP proxy for the internally-built `reqwest::Client`.
     ///
     /// Accepts either a concrete [`reqwest::Proxy`] or a `Result<Proxy, reqwest::Error>`
@@ -491,14 +1044,97 @@ impl KalshiRestClientBuilder {
         self
     }
 
+    /// Send requests with `client`, e.g. one shared across your app or with
+    /// its own pool and proxy settings.
+    ///
+    /// The builder's timeout, proxy, and decompression options apply only to
+    /// the client it builds itself, so set a `timeout` on `client`.
     pub fn with_http_client(mut self, client: Client) -> Self {
         self.http_client = Some(client);
         self
     }
 
+    /// Send requests through `transport` instead of `reqwest`.
+    ///
+    /// Rate limiting, retries, signing, and decoding still run in the client.
+    /// Takes precedence over [`with_http_client`](Self::with_http_client) and
+    /// the reqwest-specific options (timeouts, proxy, decompression). See
+    /// [`MockTransport`](crate::MockTransport) (feature `test-util`) for
+    /// asserting on requests in tests.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Subaccount used by portfolio and order requests that leave
+    /// `subaccount` unset. Must be `0..=32`; checked by [`build`](Self::build).
+    pub fn with_default_subaccount(mut self, subaccount: u32) -> Self {
+        self.default_subaccount = Some(subaccount);
+        self
+    }
+
+    /// Advertise and transparently decode gzip and brotli responses (default on).
+    ///
+    /// Ignored when a client is supplied via [`with_http_client`](Self::with_http_client).
+    pub fn with_decompression(mut self, enabled: bool) -> Self {
+        self.decompression = enabled;
+        self
+    }
+
+    /// Reject response bodies larger than `max_bytes` (after decompression)
+    /// with a [`KalshiError::Http`] whose `raw_body` starts with
+    /// `"response too large"`. Unbounded by default.
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Keep going when a successful response doesn't match its typed schema
+    /// (default off).
+    ///
+    /// Each rejected value is dropped and the response decoded again, so an
+    /// unexpected type leaves that field at its default and a list element
+    /// missing a required field is skipped. Make a call through
+    /// [`KalshiRestClient::lenient`] to get what it dropped; otherwise the
+    /// warnings are discarded. If the failure can't be isolated (e.g. a
+    /// required top-level field), the call still fails with
+    /// [`KalshiError::Parse`].
+    pub fn with_lenient_parsing(mut self, enabled: bool) -> Self {
+        self.lenient_parsing = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<KalshiRestClient, KalshiError> {
-        let http = if let Some(client) = self.http_client {
-            client
+        if self.require_auth && self.auth.is_none() {
+            return Err(KalshiError::AuthRequired(
+                "KalshiRestClientBuilder::require_auth",
+            ));
+        }
+        if let Some(sub) = self.default_subaccount
+            && sub > 32
+        {
+            return Err(KalshiError::InvalidParams(
+                "default subaccount must be 0..=32".to_string(),
+            ));
+        }
+
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+        if let Some(headers) = self.default_headers {
+            default_headers.extend(headers);
+        }
+        if let Some(user_agent) = self.user_agent {
+            default_headers.insert(
+                USER_AGENT,
+                HeaderValue::from_str(&user_agent)
+                    .map_err(|e| KalshiError::Header(e.to_string()))?,
+            );
+        }
+
+        let transport: Arc<dyn Transport> = if let Some(transport) = self.transport {
+            transport
+        } else if let Some(client) = self.http_client {
+            Arc::new(ReqwestTransport::new(client))
         } else {
             if let Some(proxy_error) = self.proxy_error {
                 return Err(KalshiError::InvalidParams(format!(
@@ -506,31 +1142,32 @@ impl KalshiRestClientBuilder {
                 )));
             }
 
-            let mut builder = Client::builder();
+            let mut builder = Client::builder()
+                .gzip(self.decompression)
+                .brotli(self.decompression);
             if let Some(timeout) = self.timeout {
                 builder = builder.timeout(timeout);
             }
             if let Some(timeout) = self.connect_timeout {
                 builder = builder.connect_timeout(timeout);
             }
-            if let Some(user_agent) = self.user_agent {
-                builder = builder.user_agent(user_agent);
-            }
-            if let Some(headers) = self.default_headers {
-                builder = builder.default_headers(headers);
-            }
             if let Some(proxy) = self.proxy {
                 builder = builder.proxy(proxy);
             }
-            builder.build()?
+            Arc::new(ReqwestTransport::new(builder.build()?))
         };
 
         Ok(KalshiRestClient {
-            http,
+            transport,
             rest_origin: self.env.rest_origin,
             auth: self.auth,
+            default_headers,
             rate_limiter: Arc::new(RateLimiter::new(self.rate_limit_config)),
+            concurrency: Arc::new(ConcurrencyLimiter::new(self.concurrency_config)),
             retry_config: self.retry_config,
+            default_subaccount: self.default_subaccount,
+            max_response_bytes: self.max_response_bytes,
+            lenient_parsing: self.lenient_parsing,
         })
     }
 }
@@ -551,6 +1188,7 @@ impl KalshiRestClientBuilder {
 ///     .with_rate_limit_config(kalshi_fast::RateLimitConfig {
 ///         read_rps: 10,
 ///         write_rps: 5,
+///         ..Default::default()
 ///     });
 /// # Ok(())
 /// # }
@@ -569,11 +1207,16 @@ impl KalshiRestClientBuilder {
 /// returns [`KalshiError::AuthRequired`](crate::KalshiError::AuthRequired).
 #[derive(Debug, Clone)]
 pub struct KalshiRestClient {
-    http: Client,
+    transport: Arc<dyn Transport>,
     rest_origin: Url,
     auth: Option<KalshiAuth>,
+    default_headers: HeaderMap,
     rate_limiter: Arc<RateLimiter>,
+    concurrency: Arc<ConcurrencyLimiter>,
     retry_config: RetryConfig,
+    default_subaccount: Option<u32>,
+    max_response_bytes: Option<usize>,
+    lenient_parsing: bool,
 }
 
 impl KalshiRestClient {
@@ -593,12 +1236,59 @@ impl KalshiRestClient {
             .expect("default rest client builder should not fail")
     }
 
+    /// Create an authenticated client whose rate limits match the account's
+    /// tier, as reported by [`get_account_api_limits`](Self::get_account_api_limits).
+    ///
+    /// The lookup itself runs under the Basic tier defaults.
+    pub async fn new_with_detected_limits(
+        env: KalshiEnvironment,
+        auth: KalshiAuth,
+    ) -> Result<Self, KalshiError> {
+        let client = Self::builder(env).with_auth(auth).build()?;
+        let limits = client.get_account_api_limits().await?;
+        Ok(client.with_rate_limit_config(RateLimitConfig::from_api_limits(&limits)))
+    }
+
+    /// Run `call` with lenient parsing and return its data together with the
+    /// [`ParseWarning`](crate::ParseWarning)s for every value its responses dropped, e.g.
+    /// `client.lenient(client.get_markets(params)).await`.
+    ///
+    /// Decoding works as described on
+    /// [`KalshiRestClientBuilder::with_lenient_parsing`], whether or not the
+    /// client was built with it. Warnings are collected per call, so
+    /// concurrent calls each get their own; requests `call` moves to other
+    /// tasks (e.g. prefetching `stream_*` pages) are not covered.
+    pub async fn lenient<T>(
+        &self,
+        call: impl Future<Output = Result<T, KalshiError>>,
+    ) -> Result<Lenient<T>, KalshiError> {
+        collect_warnings(call).await
+    }
+
+    /// The latest `x-ratelimit-*` headers seen on read and write responses.
+    /// Shared by clones; always empty when `adaptive_threshold` is 0.
+    pub fn rate_limit_state(&self) -> RateLimitState {
+        self.rate_limiter.state()
+    }
+
     /// Attach auth so you can call authenticated endpoints.
     pub fn with_auth(mut self, auth: KalshiAuth) -> Self {
         self.auth = Some(auth);
         self
     }
 
+    /// Set the `User-Agent` sent with every request (default `kalshi-fast/<version>`).
+    pub fn with_user_agent(mut self, user_agent: HeaderValue) -> Self {
+        self.default_headers.insert(USER_AGENT, user_agent);
+        self
+    }
+
+    /// Add a header sent with every request. Auth headers take precedence.
+    pub fn with_default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
     /// Override rate limits with a known tier.
     pub fn with_rate_limit_tier(mut self, tier: RateLimitTier) -> Self {
         self.rate_limiter = Arc::new(RateLimiter::new(tier.config()));
@@ -611,12 +1301,34 @@ impl KalshiRestClient {
         self
     }
 
+    /// Override in-flight request limits. See [`ConcurrencyConfig`].
+    pub fn with_concurrency_config(mut self, config: ConcurrencyConfig) -> Self {
+        self.concurrency = Arc::new(ConcurrencyLimiter::new(config));
+        self
+    }
+
     /// Override retry policy.
     pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
         self.retry_config = config;
         self
     }
 
+    /// Replace the HTTP client, e.g. with one that has a `timeout` or is
+    /// shared across your app. Replaces any custom
+    /// [`Transport`](crate::Transport) as well.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.transport = Arc::new(ReqwestTransport::new(client));
+        self
+    }
+
+    /// Fill `subaccount` from the builder default when the caller left it unset.
+    fn scoped<T: SubaccountScoped>(&self, mut params: T) -> T {
+        if let Some(sub) = self.default_subaccount {
+            params.apply_default_subaccount(sub);
+        }
+        params
+    }
+
     fn full_path(endpoint_path: &str) -> String {
         // endpoint_path must begin with "/", e.g. "/markets"
         format!("{REST_PREFIX}{endpoint_path}")
@@ -651,6 +1363,28 @@ impl KalshiRestClient {
         Ok(())
     }
 
+    fn decode<T: DeserializeOwned>(
+        &self,
+        method: &Method,
+        full_path: &str,
+        request_id: Option<&str>,
+        bytes: &[u8],
+    ) -> Result<T, KalshiError> {
+        let source = match serde_json::from_slice::<T>(bytes) {
+            Ok(parsed) => return Ok(parsed),
+            Err(source) => source,
+        };
+        let context = format!("REST {} {}", method, full_path);
+        if (self.lenient_parsing || collecting_warnings())
+            && let Some((parsed, dropped)) =
+                decode_dropping_invalid::<T>(&context, request_id, bytes)
+        {
+            record_warnings(dropped);
+            return Ok(parsed);
+        }
+        Err(KalshiError::parse_json(context, bytes, source))
+    }
+
     async fn send<Q, B, T>(
         &self,
         method: Method,
@@ -664,7 +1398,17 @@ impl KalshiRestClient {
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let url = self.build_url(full_path)?;
+        let mut url = self.build_url(full_path)?;
+        if let Some(q) = query {
+            // Same encoding as `reqwest::RequestBuilder::query`.
+            q.serialize(serde_urlencoded::Serializer::new(
+                &mut url.query_pairs_mut(),
+            ))
+            .map_err(|e| KalshiError::InvalidParams(format!("{full_path}: query: {e}")))?;
+            if url.query() == Some("") {
+                url.set_query(None);
+            }
+        }
         let auth = if require_auth {
             Some(
                 self.auth
@@ -675,51 +1419,63 @@ impl KalshiRestClient {
             None
         };
         let body_bytes = match body {
-            Some(value) => Some(serde_json::to_vec(value)?),
+            Some(value) => Some(bytes::Bytes::from(serde_json::to_vec(value)?)),
             None => None,
         };
 
         let mut retry_number: u32 = 0;
+        let kind = rate_limit_kind(&method);
+        let method_retries = self.retry_config.allows_method(&method);
 
-        loop {
-            let mut headers = HeaderMap::new();
+        let mut headers = self.default_headers.clone();
+        if body_bytes.is_some() {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        }
+        // Retries copy this; the last attempt takes it.
+        let mut prepared = Some(TransportRequest {
+            method: method.clone(),
+            url,
+            headers,
+            body: body_bytes,
+            max_response_bytes: self.max_response_bytes,
+        });
+
+        loop {
+            let last_attempt = !method_retries || retry_number >= self.retry_config.max_retries;
+            let mut request = if last_attempt {
+                prepared.take().expect("no attempt follows the last one")
+            } else {
+                prepared.clone().expect("no attempt follows the last one")
+            };
             if let Some(auth) = auth {
                 // IMPORTANT: sign the path without query parameters.
-                Self::insert_auth_headers(&mut headers, auth, &method, full_path)?;
+                Self::insert_auth_headers(&mut request.headers, auth, &method, full_path)?;
             }
 
-            self.rate_limiter.wait(rate_limit_kind(&method)).await;
-
-            let mut req = self
-                .http
-                .request(method.clone(), url.clone())
-                .headers(headers);
-
-            if let Some(q) = query {
-                req = req.query(q);
-            }
-            if let Some(body) = &body_bytes {
-                req = req
-                    .header(CONTENT_TYPE, "application/json")
-                    .body(body.clone());
-            }
+            // Held until the response body is read; released before any backoff.
+            let permit = self.concurrency.acquire(kind).await;
+            self.rate_limiter.wait(kind).await;
 
-            match req.send().await {
+            match self.transport.send(request).await {
                 Ok(resp) => {
-                    let status = resp.status();
-                    let headers = resp.headers().clone();
-                    let request_id = headers
-                        .get("x-request-id")
-                        .or_else(|| headers.get("request-id"))
-                        .and_then(|v| v.to_str().ok())
-                        .map(|s| s.to_string());
-
-                    let retry_after = if status == StatusCode::TOO_MANY_REQUESTS {
-                        retry_after_delay(&headers)
-                    } else {
-                        None
-                    };
-                    let bytes = resp.bytes().await?;
+                    drop(permit);
+                    self.rate_limiter.observe(kind, &resp.headers).await;
+                    let status = resp.status;
+                    let request_id = request_id(&resp.headers);
+                    let bytes = resp.body;
+                    if let Some(limit) = self.max_response_bytes
+                        && bytes.len() > limit
+                    {
+                        return Err(KalshiError::Http {
+                            status,
+                            api_error: None,
+                            raw_body: format!("response too large: exceeded {limit} bytes"),
+                            request_id,
+                            retry_after: None,
+                        });
+                    }
+
+                    let retry_after = retry_after_delay(&resp.headers);
 
                     if status.is_success() {
                         let body_bytes = if bytes.is_empty() {
@@ -727,22 +1483,15 @@ impl KalshiRestClient {
                         } else {
                             bytes.as_ref()
                         };
-                        return serde_json::from_slice::<T>(body_bytes).map_err(|source| {
-                            KalshiError::parse_json(
-                                format!("REST {} {}", method, full_path),
-                                body_bytes,
-                                source,
-                            )
-                        });
+                        return self.decode(&method, full_path, request_id.as_deref(), body_bytes);
                     }
 
-                    let should_retry = retry_number < self.retry_config.max_retries
-                        && self.retry_config.allows_method(&method)
-                        && retryable_status(status);
+                    let should_retry = !last_attempt && retryable_status(status);
 
                     if should_retry {
                         retry_number = retry_number.saturating_add(1);
                         let delay = retry_after
+                            .filter(|_| status == StatusCode::TOO_MANY_REQUESTS)
                             .unwrap_or_else(|| self.retry_config.backoff_delay(retry_number));
                         if !delay.is_zero() {
                             sleep(delay).await;
@@ -750,12 +1499,12 @@ impl KalshiRestClient {
                         continue;
                     }
 
-                    return Err(build_http_error(status, &bytes, request_id));
+                    return Err(build_http_error(status, &bytes, request_id, retry_after));
                 }
                 Err(err) => {
-                    let should_retry = retry_number < self.retry_config.max_retries
-                        && self.retry_config.allows_method(&method)
-                        && retryable_reqwest_error(&err);
+                    drop(permit);
+                    let should_retry = !last_attempt
+                        && matches!(&err, KalshiError::Reqwest(err) if retryable_reqwest_error(err));
                     if should_retry {
                         retry_number = retry_number.saturating_add(1);
                         let delay = self.retry_config.backoff_delay(retry_number);
@@ -764,7 +1513,7 @@ impl KalshiRestClient {
                         }
                         continue;
                     }
-                    return Err(err.into());
+                    return Err(err);
                 }
             }
         }
@@ -824,6 +1573,19 @@ impl KalshiRestClient {
         .await
     }
 
+    /// List the events in a series. Sets `params.series_ticker` for you.
+    ///
+    /// Returns [`KalshiError::InvalidParams`] if `params` already names a
+    /// different series.
+    pub async fn get_series_events(
+        &self,
+        series_ticker: &str,
+        params: GetEventsParams,
+    ) -> Result<GetEventsResponse, KalshiError> {
+        let params = scope_to_series(series_ticker, params)?;
+        self.get_events(params).await
+    }
+
     /// Get a single event by ticker, optionally including its nested markets.
     pub async fn get_event(
         &self,
@@ -912,6 +1674,19 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Get the order book for a market as an [`OrderBook`], the same type the
+    /// WebSocket [`BookManager`](crate::BookManager) maintains.
+    ///
+    /// Use it to seed a book before applying `orderbook_delta` updates.
+    pub async fn get_order_book(
+        &self,
+        market_ticker: &str,
+        depth: Option<u32>,
+    ) -> Result<OrderBook, KalshiError> {
+        let resp = self.get_market_orderbook(market_ticker, depth).await?;
+        Ok(OrderBook::from_rest_response(market_ticker, &resp))
+    }
+
     // -----------------------------------------------
     // Trades
     // -----------------------------------------------
@@ -921,6 +1696,7 @@ impl KalshiRestClient {
         &self,
         params: GetTradesParams,
     ) -> Result<GetTradesResponse, KalshiError> {
+        params.validate()?;
         let path = Self::full_path("/markets/trades");
         self.send(
             Method::GET,
@@ -998,6 +1774,38 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Poll [`get_exchange_status`](Self::get_exchange_status) until
+    /// `trading_active` is true or `timeout` elapses, returning the last status.
+    ///
+    /// Polls every `poll_interval`. When the exchange reports an
+    /// `exchange_estimated_resume_time` further out than that, sleeps until
+    /// then instead. Each poll goes through the normal rate limiter.
+    pub async fn wait_for_trading_active(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<GetExchangeStatusResponse, KalshiError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let status = self.get_exchange_status().await?;
+            let now = Instant::now();
+            if status.trading_active || now >= deadline {
+                return Ok(status);
+            }
+            let until_resume = status
+                .exchange_estimated_resume_time
+                .as_deref()
+                .and_then(parse_rfc3339_unix_seconds)
+                .and_then(|resume| {
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+                    let resume = Duration::from_secs(u64::try_from(resume).ok()?);
+                    resume.checked_sub(now)
+                })
+                .unwrap_or_default();
+            sleep(poll_interval.max(until_resume).min(deadline - now)).await;
+        }
+    }
+
     /// Get exchange announcements.
     pub async fn get_exchange_announcements(
         &self,
@@ -1083,12 +1891,65 @@ impl KalshiRestClient {
         &self,
         params: GetPositionsParams,
     ) -> Result<GetPositionsResponse, KalshiError> {
+        let params = self.scoped(params);
         params.validate()?;
         let path = Self::full_path("/portfolio/positions");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
     }
 
+    /// Fetch every position page, then drop positions in settled markets.
+    ///
+    /// `GET /portfolio/positions` has no settlement filter, so this looks up
+    /// the held markets via `GET /markets?tickers=` (100 per request) and
+    /// applies [`PositionsPage::filter_unsettled`].
+    ///
+    /// **Requires auth.**
+    pub async fn get_unsettled_positions(
+        &self,
+        params: GetPositionsParams,
+    ) -> Result<PositionsPage, KalshiError> {
+        let pages = self
+            .paginate_cursor(params.cursor.clone(), |cursor| {
+                let mut page_params = params.clone();
+                page_params.cursor = cursor;
+                async move {
+                    let resp = self.get_positions(page_params).await?;
+                    let cursor = resp.cursor.clone();
+                    Ok((vec![PositionsPage::from(resp)], cursor))
+                }
+            })
+            .await?;
+        let mut positions = PositionsPage {
+            market_positions: Vec::new(),
+            event_positions: Vec::new(),
+        };
+        for page in pages {
+            positions.market_positions.extend(page.market_positions);
+            positions.event_positions.extend(page.event_positions);
+        }
+
+        let mut tickers: Vec<String> = positions
+            .market_positions
+            .iter()
+            .map(|position| position.ticker.clone())
+            .collect();
+        tickers.sort_unstable();
+        tickers.dedup();
+        let mut markets = Vec::with_capacity(tickers.len());
+        for chunk in tickers.chunks(POSITION_MARKETS_BATCH_SIZE) {
+            markets.extend(
+                self.get_markets_all(GetMarketsParams {
+                    tickers: Some(chunk.to_vec()),
+                    limit: Some(POSITION_MARKETS_BATCH_SIZE as u32),
+                    ..Default::default()
+                })
+                .await?,
+            );
+        }
+        Ok(positions.filter_unsettled(&markets))
+    }
+
     /// List orders with optional filters. Supports cursor pagination.
     ///
     /// **Requires auth.**
@@ -1096,6 +1957,7 @@ impl KalshiRestClient {
         &self,
         params: GetOrdersParams,
     ) -> Result<GetOrdersResponse, KalshiError> {
+        let params = self.scoped(params);
         params.validate()?;
         let path = Self::full_path("/portfolio/orders");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
@@ -1113,6 +1975,7 @@ impl KalshiRestClient {
         &self,
         body: CreateOrderRequest,
     ) -> Result<CreateOrderResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/portfolio/orders");
         body.validate()?;
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
@@ -1127,6 +1990,7 @@ impl KalshiRestClient {
         order_id: &str,
         params: CancelOrderParams,
     ) -> Result<CancelOrderResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path(&format!("/portfolio/orders/{order_id}"));
         self.send(
             Method::DELETE,
@@ -1142,6 +2006,7 @@ impl KalshiRestClient {
     ///
     /// **Requires auth.**
     pub async fn get_fills(&self, params: GetFillsParams) -> Result<GetFillsResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path("/portfolio/fills");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
@@ -1154,6 +2019,7 @@ impl KalshiRestClient {
         &self,
         params: GetSettlementsParams,
     ) -> Result<GetSettlementsResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path("/portfolio/settlements");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
@@ -1330,15 +2196,20 @@ impl KalshiRestClient {
     }
 
     pub async fn get_rfqs(&self, params: GetRFQsParams) -> Result<GetRFQsResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path("/communications/rfqs");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
     }
 
+    /// Create an RFQ. The response carries only its id; [`get_rfq`](Self::get_rfq)
+    /// returns the full [`RFQ`], the REST counterpart of the WebSocket
+    /// `rfq_created` message.
     pub async fn create_rfq(
         &self,
         body: CreateRFQRequest,
     ) -> Result<CreateRFQResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/communications/rfqs");
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
@@ -1377,10 +2248,14 @@ impl KalshiRestClient {
             .await
     }
 
+    /// Quote on an RFQ. The response carries only its id; [`get_quote`](Self::get_quote)
+    /// returns the full [`Quote`], the REST counterpart of the WebSocket
+    /// `quote_created` message.
     pub async fn create_quote(
         &self,
         body: CreateQuoteRequest,
     ) -> Result<CreateQuoteResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/communications/quotes");
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
@@ -1410,6 +2285,8 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Accept a quote on one of your RFQs, taking `accepted_side`. The
+    /// quoter must then [`confirm_quote`](Self::confirm_quote) it before it executes.
     pub async fn accept_quote(
         &self,
         quote_id: &str,
@@ -1554,6 +2431,8 @@ impl KalshiRestClient {
         .await
     }
 
+    /// List multivariate event collections, optionally filtered by status,
+    /// associated event, or series.
     pub async fn get_multivariate_event_collections(
         &self,
         params: GetMultivariateEventCollectionsParams,
@@ -1569,6 +2448,9 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Get one multivariate event collection, including its associated
+    /// events. Use it to resolve the `collection_ticker` on a WebSocket
+    /// multivariate lookup.
     pub async fn get_multivariate_event_collection(
         &self,
         collection_ticker: &str,
@@ -1644,6 +2526,8 @@ impl KalshiRestClient {
         .await
     }
 
+    /// OHLC candles for one market over `start_ts..=end_ts`, validated with
+    /// [`GetMarketCandlesticksParams::validate`].
     pub async fn get_market_candlesticks(
         &self,
         series_ticker: &str,
@@ -1653,6 +2537,7 @@ impl KalshiRestClient {
         let path = Self::full_path(&format!(
             "/series/{series_ticker}/markets/{ticker}/candlesticks"
         ));
+        params.validate()?;
         self.send(
             Method::GET,
             &path,
@@ -1747,39 +2632,57 @@ impl KalshiRestClient {
             .await
     }
 
+    /// List order groups.
+    ///
+    /// **Requires auth.**
     pub async fn get_order_groups(
         &self,
         params: SubaccountQueryParams,
     ) -> Result<GetOrderGroupsResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path("/portfolio/order_groups");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
     }
 
+    /// Create an order group. Orders placed with its `order_group_id` are
+    /// cancelled once `contracts_limit` (or `contracts_limit_fp`) contracts fill.
+    ///
+    /// **Requires auth.**
     pub async fn create_order_group(
         &self,
         body: CreateOrderGroupRequest,
     ) -> Result<CreateOrderGroupResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/portfolio/order_groups/create");
+        body.validate()?;
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
     }
 
+    /// Get an order group's limit and member orders.
+    ///
+    /// **Requires auth.**
     pub async fn get_order_group(
         &self,
         order_group_id: &str,
         params: SubaccountQueryParams,
     ) -> Result<GetOrderGroupResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path(&format!("/portfolio/order_groups/{order_group_id}"));
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
     }
 
+    /// Delete an order group, cancelling its resting orders.
+    ///
+    /// **Requires auth.**
     pub async fn delete_order_group(
         &self,
         order_group_id: &str,
         params: SubaccountQueryParams,
     ) -> Result<EmptyResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path(&format!("/portfolio/order_groups/{order_group_id}"));
         self.send(
             Method::DELETE,
@@ -1791,12 +2694,16 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Change an order group's rolling 15-second contract limit.
+    ///
+    /// **Requires auth.**
     pub async fn update_order_group_limit(
         &self,
         order_group_id: &str,
         body: UpdateOrderGroupLimitRequest,
     ) -> Result<EmptyResponse, KalshiError> {
         let path = Self::full_path(&format!("/portfolio/order_groups/{order_group_id}/limit"));
+        body.validate()?;
         self.send(Method::PUT, &path, Option::<&()>::None, Some(&body), true)
             .await
     }
@@ -1806,6 +2713,7 @@ impl KalshiRestClient {
         order_group_id: &str,
         params: SubaccountQueryParams,
     ) -> Result<EmptyResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path(&format!("/portfolio/order_groups/{order_group_id}/reset"));
         let body = EmptyResponse::default();
         self.send(Method::PUT, &path, Some(&params), Some(&body), true)
@@ -1817,26 +2725,41 @@ impl KalshiRestClient {
         order_group_id: &str,
         params: SubaccountQueryParams,
     ) -> Result<EmptyResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path(&format!("/portfolio/order_groups/{order_group_id}/trigger"));
         let body = EmptyResponse::default();
         self.send(Method::PUT, &path, Some(&params), Some(&body), true)
             .await
     }
 
+    /// Place up to [`BatchCreateOrdersRequest::MAX_ORDERS`] orders in one
+    /// request. Each order is validated first; per-order failures come back
+    /// as entries with `error` set.
+    ///
+    /// **Requires auth.**
     pub async fn batch_create_orders(
         &self,
         body: BatchCreateOrdersRequest,
     ) -> Result<BatchCreateOrdersResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/portfolio/orders/batched");
+        body.validate()?;
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
     }
 
+    /// Cancel up to [`BatchCancelOrdersRequest::MAX_ORDERS`] orders in one
+    /// request, counted as a single write by the rate limiter. Each entry in
+    /// the response reports that order's `reduced_by_fp` or `error`.
+    ///
+    /// **Requires auth.**
     pub async fn batch_cancel_orders(
         &self,
         body: BatchCancelOrdersRequest,
     ) -> Result<BatchCancelOrdersResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path("/portfolio/orders/batched");
+        body.validate()?;
         self.send(
             Method::DELETE,
             &path,
@@ -1847,6 +2770,45 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Cancel every resting order, 20 per batch request.
+    ///
+    /// Pages `GET /portfolio/orders?status=resting` first, so orders placed
+    /// while this runs may survive. Per-order failures are reported in the
+    /// returned entries; the first request error aborts the remaining batches.
+    ///
+    /// **Requires auth.**
+    pub async fn cancel_all_orders(
+        &self,
+    ) -> Result<Vec<BatchCancelOrdersIndividualResponse>, KalshiError> {
+        let params = GetOrdersParams {
+            status: Some(OrderStatus::Resting),
+            ..Default::default()
+        };
+        let orders = self
+            .paginate_cursor(None, |cursor| {
+                let mut page_params = params.clone();
+                page_params.cursor = cursor;
+                async move {
+                    let resp = self.get_orders(page_params).await?;
+                    Ok((resp.orders, resp.cursor))
+                }
+            })
+            .await?;
+
+        let ids: Vec<String> = orders.into_iter().map(|order| order.order_id).collect();
+        let mut results = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(BatchCancelOrdersRequest::MAX_ORDERS) {
+            let resp = self
+                .batch_cancel_orders(BatchCancelOrdersRequest::from_ids(chunk.iter().cloned()))
+                .await?;
+            results.extend(resp.orders);
+        }
+        Ok(results)
+    }
+
+    /// Fetch one order by ID, e.g. to check its fill status after placing it.
+    ///
+    /// **Requires auth.**
     pub async fn get_order(&self, order_id: &str) -> Result<GetOrderResponse, KalshiError> {
         let path = Self::full_path(&format!("/portfolio/orders/{order_id}"));
         self.send(
@@ -1859,22 +2821,82 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Poll an order and stream its status/fill-progress transitions.
+    ///
+    /// Calls [`get_order`](Self::get_order) immediately and then every
+    /// `poll_interval`, yielding an [`OrderUpdate`] only when status, filled
+    /// count, or remaining count changed. The stream ends after a terminal
+    /// update (executed or canceled) or the first error.
+    ///
+    /// **Requires auth.**
+    pub fn watch_order(
+        &self,
+        order_id: impl Into<String>,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<OrderUpdate, KalshiError>> + Send {
+        let state = WatchOrderState {
+            client: self.clone(),
+            order_id: order_id.into(),
+            poll_interval,
+            last: None,
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            if state.done {
+                return None;
+            }
+            loop {
+                if state.last.is_some() {
+                    sleep(state.poll_interval).await;
+                }
+                let update = match state.client.get_order(&state.order_id).await {
+                    Ok(resp) => OrderUpdate::from_order(resp.order),
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
+                    }
+                };
+                if state
+                    .last
+                    .as_ref()
+                    .is_some_and(|last| last.same_progress(&update))
+                {
+                    continue;
+                }
+                state.done = update.is_terminal();
+                state.last = Some(update.clone());
+                return Some((Ok(update), state));
+            }
+        })
+    }
+
+    /// Amend a resting order's price and count in place.
+    ///
+    /// **Requires auth.**
     pub async fn amend_order(
         &self,
         order_id: &str,
         body: AmendOrderRequest,
     ) -> Result<AmendOrderResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path(&format!("/portfolio/orders/{order_id}/amend"));
+        body.validate()?;
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
     }
 
+    /// Shrink a resting order without cancelling it.
+    ///
+    /// **Requires auth.**
     pub async fn decrease_order(
         &self,
         order_id: &str,
         body: DecreaseOrderRequest,
     ) -> Result<DecreaseOrderResponse, KalshiError> {
+        let body = self.scoped(body);
         let path = Self::full_path(&format!("/portfolio/orders/{order_id}/decrease"));
+        body.validate()?;
         self.send(Method::POST, &path, Option::<&()>::None, Some(&body), true)
             .await
     }
@@ -1883,6 +2905,7 @@ impl KalshiRestClient {
         &self,
         params: GetOrderQueuePositionsParams,
     ) -> Result<GetOrderQueuePositionsResponse, KalshiError> {
+        let params = self.scoped(params);
         let path = Self::full_path("/portfolio/orders/queue_positions");
         self.send(Method::GET, &path, Some(&params), Option::<&()>::None, true)
             .await
@@ -2007,6 +3030,19 @@ impl KalshiRestClient {
         })
     }
 
+    /// Create a pager over the events in a series. See [`CursorPager`].
+    ///
+    /// Returns [`KalshiError::InvalidParams`] if `params` already names a
+    /// different series.
+    pub fn series_events_pager(
+        &self,
+        series_ticker: &str,
+        params: GetEventsParams,
+    ) -> Result<CursorPager<EventData>, KalshiError> {
+        let params = scope_to_series(series_ticker, params)?;
+        Ok(self.events_pager(params))
+    }
+
     /// Create a pager for iterating over markets page by page. See [`CursorPager`].
     pub fn markets_pager(&self, params: GetMarketsParams) -> CursorPager<Market> {
         let client = self.clone();
@@ -2264,6 +3300,23 @@ impl KalshiRestClient {
         stream_items(self.markets_pager(params), max_items)
     }
 
+    /// Stream markets one by one, fetching up to `prefetch` pages ahead so
+    /// the consumer does not wait at page boundaries.
+    ///
+    /// Cursors are sequential, so pages still arrive one request at a time;
+    /// prefetching overlaps those requests with the consumer's work. Fetching
+    /// stops once `max_items` markets are in hand, but pages already queued
+    /// when the stream is dropped are wasted requests. Must be polled inside
+    /// a Tokio runtime.
+    pub fn stream_markets_buffered(
+        &self,
+        params: GetMarketsParams,
+        max_items: Option<usize>,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<Market, KalshiError>> + Send {
+        stream_items_buffered(self.markets_pager(params), max_items, prefetch)
+    }
+
     /// Stream trades one by one.
     pub fn stream_trades(
         &self,
@@ -2273,6 +3326,29 @@ impl KalshiRestClient {
         stream_items(self.trades_pager(params), max_items)
     }
 
+    /// Stream every trade for `ticker` between `start_ts` and `end_ts` (unix seconds),
+    /// newest to oldest.
+    ///
+    /// Stops once trades fall before `start_ts`. Long windows are split into
+    /// fresh cursor chains ending at the oldest trade seen, so arbitrarily
+    /// large histories can be walked without relying on one cursor.
+    pub fn stream_trades_window(
+        &self,
+        ticker: impl Into<String>,
+        start_ts: i64,
+        end_ts: i64,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<Trade, KalshiError>> + Send {
+        stream_trades_window_inner(
+            self.clone(),
+            ticker.into(),
+            start_ts,
+            end_ts,
+            max_items,
+            TRADES_WINDOW_PAGES_PER_CHAIN,
+        )
+    }
+
     /// Stream positions one by one.
     ///
     /// **Requires auth.**
@@ -2402,6 +3478,21 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Fetch all pages for markets, then sort them by `key`.
+    ///
+    /// The API has no server-side ordering, so this pulls every matching
+    /// market first; narrow `params` (e.g. by `status` or `series_ticker`)
+    /// to keep the pull small. See [`MarketSortKey`] for the comparator.
+    pub async fn get_markets_sorted(
+        &self,
+        params: GetMarketsParams,
+        key: MarketSortKey,
+    ) -> Result<Vec<Market>, KalshiError> {
+        let mut markets = self.get_markets_all(params).await?;
+        key.sort(&mut markets);
+        Ok(markets)
+    }
+
     /// Fetch all pages for events using cursor pagination.
     pub async fn get_events_all(
         &self,
@@ -2418,6 +3509,40 @@ impl KalshiRestClient {
         .await
     }
 
+    /// Fetch every event in a series category.
+    ///
+    /// `GET /events` has no category filter, so this lists the series in
+    /// `category` via [`get_series_list`](Self::get_series_list), then fetches
+    /// all events for each series in turn (one paginated call per series).
+    /// Other `params` filters (e.g. `status`) apply to every series; setting
+    /// `params.series_ticker` is rejected.
+    pub async fn get_events_by_category(
+        &self,
+        category: &str,
+        params: GetEventsParams,
+    ) -> Result<Vec<EventData>, KalshiError> {
+        if params.series_ticker.is_some() {
+            return Err(KalshiError::InvalidParams(
+                "get_events_by_category: series_ticker cannot be combined with category"
+                    .to_string(),
+            ));
+        }
+        let series = self
+            .get_series_list(GetSeriesListParams {
+                category: Some(category.to_string()),
+                ..Default::default()
+            })
+            .await?
+            .series;
+
+        let mut events = Vec::new();
+        for series in series {
+            let params = scope_to_series(&series.ticker, params.clone())?;
+            events.extend(self.get_events_all(params).await?);
+        }
+        Ok(events)
+    }
+
     /// Fetch all pages for trades using cursor pagination.
     pub async fn get_trades_all(&self, params: GetTradesParams) -> Result<Vec<Trade>, KalshiError> {
         self.paginate_cursor(params.cursor.clone(), |cursor| {
@@ -2541,12 +3666,12 @@ impl KalshiRestClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use futures::stream::TryStreamExt;
+    use futures::stream::{StreamExt, TryStreamExt};
     use reqwest::Method;
     use reqwest::StatusCode;
     use serde_json::json;
     use std::sync::Arc;
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
     use tokio::net::TcpListener;
     use tokio::time::{Duration, Instant, timeout};
@@ -2578,7 +3703,8 @@ mod tests {
         buf.windows(4).position(|w| w == b"\r\n\r\n").map(|i| i + 4)
     }
 
-    async fn read_http_request(stream: &mut tokio::net::TcpStream) -> std::io::Result<()> {
+    /// Read one request and return its head (request line + headers).
+    async fn read_http_request(stream: &mut tokio::net::TcpStream) -> std::io::Result<String> {
         let mut buffer = Vec::new();
         let mut chunk = [0u8; 2048];
         let mut required_body_len: Option<usize> = None;
@@ -2587,7 +3713,8 @@ mod tests {
         loop {
             let n = stream.read(&mut chunk).await?;
             if n == 0 {
-                return Ok(());
+                let end = header_len.unwrap_or(buffer.len());
+                return Ok(String::from_utf8_lossy(&buffer[..end]).to_string());
             }
             buffer.extend_from_slice(&chunk[..n]);
 
@@ -2607,7 +3734,7 @@ mod tests {
             if let (Some(header_len), Some(required_body_len)) = (header_len, required_body_len) {
                 let body_len = buffer.len().saturating_sub(header_len);
                 if body_len >= required_body_len {
-                    return Ok(());
+                    return Ok(String::from_utf8_lossy(&buffer[..header_len]).to_string());
                 }
             }
         }
@@ -2619,16 +3746,32 @@ mod tests {
         Url,
         Arc<AtomicUsize>,
         tokio::task::JoinHandle<std::io::Result<()>>,
+    ) {
+        let (url, hits, _requests, task) = spawn_http_recording_server(responses).await;
+        (url, hits, task)
+    }
+
+    /// Like [`spawn_http_sequence_server`], also recording each request head.
+    async fn spawn_http_recording_server(
+        responses: Vec<TestHttpResponse>,
+    ) -> (
+        Url,
+        Arc<AtomicUsize>,
+        Arc<std::sync::Mutex<Vec<String>>>,
+        tokio::task::JoinHandle<std::io::Result<()>>,
     ) {
         let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
         let addr = listener.local_addr().expect("local addr");
         let hits = Arc::new(AtomicUsize::new(0));
         let hits_ref = Arc::clone(&hits);
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let requests_ref = Arc::clone(&requests);
 
         let task = tokio::spawn(async move {
             for response in responses {
                 let (mut stream, _) = listener.accept().await?;
-                read_http_request(&mut stream).await?;
+                let head = read_http_request(&mut stream).await?;
+                requests_ref.lock().expect("requests lock").push(head);
                 hits_ref.fetch_add(1, Ordering::Relaxed);
 
                 let reason = response.status.canonical_reason().unwrap_or("Unknown");
@@ -2653,6 +3796,7 @@ mod tests {
         (
             Url::parse(&format!("http://{addr}")).expect("url"),
             hits,
+            requests,
             task,
         )
     }
@@ -2664,6 +3808,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_url_joins_prefix_for_each_environment() {
+        let cases = [
+            (KalshiEnvironment::demo(), "https://demo-api.kalshi.co"),
+            (
+                KalshiEnvironment::production(),
+                "https://api.elections.kalshi.com",
+            ),
+        ];
+        for (env, origin) in cases {
+            let client = KalshiRestClient::new(env);
+            let path = KalshiRestClient::full_path("/markets/ABC/orderbook");
+            let url = client.build_url(&path).expect("url");
+            assert_eq!(
+                url.as_str(),
+                format!("{origin}/trade-api/v2/markets/ABC/orderbook")
+            );
+        }
+    }
+
     #[test]
     fn http_error_parses_json_body() {
         let body = br#"{"code":"rate_limit","message":"too fast"}"#;
@@ -2671,6 +3835,7 @@ mod tests {
             StatusCode::TOO_MANY_REQUESTS,
             body,
             Some("req-1".to_string()),
+            None,
         );
         match err {
             KalshiError::Http {
@@ -2678,6 +3843,7 @@ mod tests {
                 api_error,
                 raw_body,
                 request_id,
+                ..
             } => {
                 assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
                 assert_eq!(request_id.as_deref(), Some("req-1"));
@@ -2693,13 +3859,14 @@ mod tests {
     #[test]
     fn http_error_handles_non_json_body() {
         let body = b"plain error body";
-        let err = build_http_error(StatusCode::BAD_REQUEST, body, None);
+        let err = build_http_error(StatusCode::BAD_REQUEST, body, None, None);
         match err {
             KalshiError::Http {
                 status,
                 api_error,
                 raw_body,
                 request_id,
+                ..
             } => {
                 assert_eq!(status, StatusCode::BAD_REQUEST);
                 assert!(api_error.is_none());
@@ -2713,7 +3880,7 @@ mod tests {
     #[test]
     fn http_error_parses_wrapped_error_envelope() {
         let body = br#"{"error":{"code":"bad_request","message":"invalid","service":"trade-api"}}"#;
-        let err = build_http_error(StatusCode::BAD_REQUEST, body, None);
+        let err = build_http_error(StatusCode::BAD_REQUEST, body, None, None);
         match err {
             KalshiError::Http { api_error, .. } => {
                 let api_error = api_error.expect("expected api error");
@@ -2762,35 +3929,105 @@ mod tests {
         server.await.expect("server").expect("server ok");
     }
 
-    #[test]
-    fn builder_accepts_proxy_result_input() {
-        let client = KalshiRestClient::builder(KalshiEnvironment::demo())
-            .with_proxy(reqwest::Proxy::all("http://127.0.0.1:8080"))
-            .build();
-        assert!(client.is_ok());
+    #[tokio::test]
+    async fn lenient_parsing_drops_invalid_values_and_records_warnings() {
+        let body = r#"{"markets":[{"ticker":"A","volume":{"new":"shape"},"yes_bid":40},{"volume":5},{"ticker":"C"}],"cursor":""}"#;
+        let (rest_origin, _hits, server) = spawn_http_sequence_server(vec![
+            TestHttpResponse::new(StatusCode::OK, body),
+            TestHttpResponse::new(StatusCode::OK, body),
+            TestHttpResponse::new(StatusCode::OK, body),
+            TestHttpResponse::new(StatusCode::OK, r#"{"markets":[],"cursor":""}"#),
+        ])
+        .await;
+
+        let strict = KalshiRestClient::builder(test_env(rest_origin.clone()))
+            .build()
+            .expect("build client");
+        let err = strict
+            .get_markets(GetMarketsParams::default())
+            .await
+            .expect_err("strict parse fails");
+        assert!(matches!(err, KalshiError::Parse { .. }));
+
+        // A per-call scope decodes leniently even on a strict client.
+        let Lenient { data, warnings } = strict
+            .lenient(strict.get_markets(GetMarketsParams::default()))
+            .await
+            .expect("lenient call");
+        assert_eq!(data.markets.len(), 2);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].path, "markets[0].volume");
+        assert_eq!(warnings[0].raw, serde_json::json!({"new": "shape"}));
+        assert_eq!(warnings[0].context, "REST GET /trade-api/v2/markets");
+        assert_eq!(warnings[1].path, "markets[1]");
+        assert!(warnings[1].reason.contains("ticker"));
+
+        let lenient = KalshiRestClient::builder(test_env(rest_origin))
+            .with_lenient_parsing(true)
+            .build()
+            .expect("build client");
+        let resp = lenient
+            .get_markets(GetMarketsParams::default())
+            .await
+            .expect("lenient parse");
+        let tickers: Vec<_> = resp.markets.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "C"]);
+        assert_eq!(resp.markets[0].volume, None);
+        assert_eq!(resp.markets[0].yes_bid, Some(40));
+
+        let clean = lenient
+            .lenient(lenient.get_markets(GetMarketsParams::default()))
+            .await
+            .expect("clean call");
+        assert!(clean.data.markets.is_empty());
+        assert!(clean.warnings.is_empty());
+
+        server.await.expect("server").expect("server ok");
     }
 
-    #[test]
-    fn builder_rejects_invalid_proxy_result_input() {
-        let err = KalshiRestClient::builder(KalshiEnvironment::demo())
-            .with_proxy(reqwest::Proxy::all("not a url"))
+    #[tokio::test]
+    async fn watch_order_yields_only_progress_changes() {
+        let order = |status: &str, filled: i64, remaining: i64| {
+            TestHttpResponse::new(
+                StatusCode::OK,
+                format!(
+                    r#"{{"order":{{"order_id":"o1","ticker":"MKT-1","status":"{status}","filled_count":{filled},"remaining_count":{remaining}}}}}"#
+                ),
+            )
+        };
+        let (origin, hits, server) = spawn_http_sequence_server(vec![
+            order("resting", 0, 10),
+            order("resting", 0, 10),
+            order("resting", 4, 6),
+            order("executed", 10, 0),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_auth(crate::auth::tests::load_test_auth())
             .build()
-            .expect_err("invalid proxy should fail at build");
+            .expect("client");
+        let updates: Vec<_> = client
+            .watch_order("o1", Duration::from_millis(1))
+            .collect()
+            .await;
+        server.await.expect("server").expect("server ok");
 
-        match err {
-            KalshiError::InvalidParams(message) => {
-                assert!(message.contains("invalid proxy configuration"));
-            }
-            other => panic!("unexpected error: {:?}", other),
-        }
+        let filled: Vec<_> = updates
+            .iter()
+            .map(|u| u.as_ref().expect("update").filled_count)
+            .collect();
+        assert_eq!(filled, [Some(0), Some(4), Some(10)]);
+        assert!(updates[2].as_ref().expect("update").is_terminal());
+        assert_eq!(hits.load(Ordering::Relaxed), 4);
     }
 
     #[tokio::test]
-    async fn get_retries_on_503_then_succeeds() {
-        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![
+    async fn wait_for_trading_active_polls_until_active() {
+        let (origin, hits, server) = spawn_http_sequence_server(vec![
             TestHttpResponse::new(
-                StatusCode::SERVICE_UNAVAILABLE,
-                r#"{"code":"unavailable","message":"try again"}"#,
+                StatusCode::OK,
+                r#"{"exchange_active":true,"trading_active":false,"exchange_estimated_resume_time":"2020-01-01T00:00:00Z"}"#,
             ),
             TestHttpResponse::new(
                 StatusCode::OK,
@@ -2799,63 +4036,582 @@ mod tests {
         ])
         .await;
 
-        let client = KalshiRestClient::builder(test_env(rest_origin))
-            .with_retry_config(RetryConfig {
-                max_retries: 1,
-                base_delay: Duration::from_millis(1),
-                max_delay: Duration::from_millis(1),
-                jitter: 0.0,
-                retry_non_idempotent: false,
-            })
+        let client = KalshiRestClient::builder(test_env(origin))
             .build()
-            .expect("build client");
-
-        let response = client
-            .get_exchange_status()
+            .expect("client");
+        let status = client
+            .wait_for_trading_active(Duration::from_millis(1), Duration::from_secs(5))
             .await
-            .expect("request succeeds");
-        assert!(response.exchange_active);
-        assert_eq!(hits.load(Ordering::Relaxed), 2);
+            .expect("status");
         server.await.expect("server").expect("server ok");
+
+        assert!(status.trading_active);
+        assert_eq!(hits.load(Ordering::Relaxed), 2);
     }
 
     #[tokio::test]
-    async fn post_does_not_retry_by_default() {
-        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![TestHttpResponse::new(
-            StatusCode::SERVICE_UNAVAILABLE,
-            r#"{"code":"unavailable","message":"retry me"}"#,
-        )])
-        .await;
-
-        let client = KalshiRestClient::builder(test_env(rest_origin))
-            .with_retry_config(RetryConfig {
-                max_retries: 2,
-                base_delay: Duration::from_millis(1),
-                max_delay: Duration::from_millis(1),
-                jitter: 0.0,
-                retry_non_idempotent: false,
-            })
-            .build()
-            .expect("build client");
-
-        let path = KalshiRestClient::full_path("/test-post");
-        let result = client
-            .send::<(), _, serde_json::Value>(
-                Method::POST,
-                &path,
-                Option::<&()>::None,
-                Some(&json!({"x": 1})),
-                false,
-            )
+    async fn new_with_detected_limits_applies_account_tier() {
+        let (origin, _hits, requests, server) =
+            spawn_http_recording_server(vec![TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"usage_tier":"premier","read_limit":100,"write_limit":0}"#,
+            )])
             .await;
-        assert!(matches!(result, Err(KalshiError::Http { .. })));
-        assert_eq!(hits.load(Ordering::Relaxed), 1);
+
+        let client = KalshiRestClient::new_with_detected_limits(
+            test_env(origin),
+            crate::auth::tests::load_test_auth(),
+        )
+        .await
+        .expect("client");
         server.await.expect("server").expect("server ok");
+
+        assert!(requests.lock().expect("requests lock")[0].contains("/account/limits"));
+        assert_eq!(client.rate_limiter.read.interval, Duration::from_millis(10));
+        assert_eq!(client.rate_limiter.write.interval, Duration::from_secs(1));
     }
 
     #[tokio::test]
-    async fn post_retry_opt_in_retries_and_succeeds() {
-        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![
+    async fn cancel_all_orders_batches_resting_orders() {
+        let order =
+            |i: usize| format!(r#"{{"order_id":"o{i}","ticker":"MKT-1","status":"resting"}}"#);
+        let cancelled = |range: std::ops::Range<usize>| {
+            let entries: Vec<_> = range
+                .map(|i| format!(r#"{{"order_id":"o{i}","reduced_by":1,"reduced_by_fp":"1.00"}}"#))
+                .collect();
+            format!(r#"{{"orders":[{}]}}"#, entries.join(","))
+        };
+        let first_page: Vec<_> = (0..15).map(order).collect();
+        let second_page: Vec<_> = (15..25).map(order).collect();
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(
+                StatusCode::OK,
+                format!(r#"{{"orders":[{}],"cursor":"next"}}"#, first_page.join(",")),
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                format!(r#"{{"orders":[{}],"cursor":""}}"#, second_page.join(",")),
+            ),
+            TestHttpResponse::new(StatusCode::OK, cancelled(0..20)),
+            TestHttpResponse::new(StatusCode::OK, cancelled(20..25)),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .build()
+            .expect("client");
+        let results = client.cancel_all_orders().await.expect("cancel all");
+        server.await.expect("server").expect("server ok");
+
+        assert_eq!(results.len(), 25);
+        let requests = requests.lock().expect("requests lock");
+        assert!(requests[0].starts_with("GET /trade-api/v2/portfolio/orders?status=resting"));
+        assert!(requests[1].contains("cursor=next"));
+        assert!(requests[2].starts_with("DELETE /trade-api/v2/portfolio/orders/batched"));
+        assert!(requests[3].starts_with("DELETE /trade-api/v2/portfolio/orders/batched"));
+    }
+
+    #[tokio::test]
+    async fn get_unsettled_positions_drops_settled_markets() {
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"market_positions":[{"ticker":"OPEN"},{"ticker":"DONE"}],"event_positions":[{"event_ticker":"EVT-OPEN"},{"event_ticker":"EVT-DONE"}],"cursor":""}"#,
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"markets":[{"ticker":"DONE","event_ticker":"EVT-DONE","status":"finalized"},{"ticker":"OPEN","event_ticker":"EVT-OPEN","status":"active"}],"cursor":""}"#,
+            ),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .build()
+            .expect("client");
+        let page = client
+            .get_unsettled_positions(GetPositionsParams::default())
+            .await
+            .expect("positions");
+        server.await.expect("server").expect("server ok");
+
+        let tickers: Vec<_> = page
+            .market_positions
+            .iter()
+            .map(|p| p.ticker.as_str())
+            .collect();
+        assert_eq!(tickers, ["OPEN"]);
+        let events: Vec<_> = page
+            .event_positions
+            .iter()
+            .map(|p| p.event_ticker.as_str())
+            .collect();
+        assert_eq!(events, ["EVT-OPEN"]);
+        let requests = requests.lock().expect("requests lock");
+        assert!(requests[1].starts_with("GET /trade-api/v2/markets?limit=100&tickers=DONE%2COPEN"));
+    }
+
+    #[tokio::test]
+    async fn with_http_client_bounds_hung_requests() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            // Accept and never answer.
+            let (_stream, _) = listener.accept().await.expect("accept");
+            std::future::pending::<()>().await;
+        });
+
+        let http = Client::builder()
+            .timeout(Duration::from_millis(100))
+            .build()
+            .expect("http client");
+        let client = KalshiRestClient::new(test_env(
+            Url::parse(&format!("http://{addr}/")).expect("url"),
+        ))
+        .with_retry_config(RetryConfig {
+            max_retries: 0,
+            ..Default::default()
+        })
+        .with_http_client(http);
+
+        let err = tokio::time::timeout(Duration::from_secs(5), client.get_series("KXBTC"))
+            .await
+            .expect("request bounded by the client timeout")
+            .expect_err("timed out");
+        assert!(
+            matches!(&err, KalshiError::Reqwest(e) if e.is_timeout()),
+            "{err:?}"
+        );
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn get_order_book_keeps_sub_contract_levels() {
+        let mock = crate::rest::MockTransport::new();
+        mock.push_response(
+            StatusCode::OK,
+            r#"{"orderbook":{"yes":[[44,0]],"no":[]},"orderbook_fp":{"yes_dollars":[["0.4400","0.25"]],"no_dollars":[["0.5500","3.00"]]}}"#,
+        );
+        let client = KalshiRestClient::builder(test_env(Url::parse("http://mock/").expect("url")))
+            .with_transport(mock)
+            .build()
+            .expect("client");
+
+        let book = client.get_order_book("MKT", None).await.expect("book");
+        assert_eq!(book.market_ticker, "MKT");
+        assert_eq!(book.top_of_book().bid_ask(), Some((44, 45)), "{book:?}");
+        assert_eq!(
+            book.best_yes_bid(),
+            Some((44, crate::Quantity::from_hundredths(25)))
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_transport_records_requests_and_maps_errors() {
+        let mock = crate::rest::MockTransport::new();
+        mock.push_response(StatusCode::OK, r#"{"fills":[],"cursor":""}"#)
+            .push_response(
+                StatusCode::NOT_FOUND,
+                r#"{"error":{"code":"not_found","message":"no such market"}}"#,
+            );
+        let client = KalshiRestClient::builder(test_env(Url::parse("http://mock/").expect("url")))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .with_transport(mock.clone())
+            .build()
+            .expect("client");
+
+        client
+            .get_fills(GetFillsParams {
+                limit: Some(5),
+                ticker: Some("MKT 1".to_string()),
+                ..Default::default()
+            })
+            .await
+            .expect("fills");
+        let err = client.get_market("NOPE").await.expect_err("404");
+        assert!(matches!(
+            &err,
+            KalshiError::Http { status: StatusCode::NOT_FOUND, api_error: Some(api), .. }
+                if api.code.as_deref() == Some("not_found")
+        ));
+        assert!(matches!(
+            client.get_market("NOPE").await,
+            Err(KalshiError::InvalidParams(msg)) if msg.starts_with("MockTransport")
+        ));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "http://mock/trade-api/v2/portfolio/fills?limit=5&ticker=MKT+1"
+        );
+        assert!(requests[0].headers.contains_key("kalshi-access-signature"));
+        assert!(requests[0].body.is_none());
+        assert_eq!(requests[1].url.path(), "/trade-api/v2/markets/NOPE");
+        assert_eq!(requests[1].url.query(), None);
+        assert!(!requests[1].headers.contains_key("kalshi-access-signature"));
+    }
+
+    #[tokio::test]
+    async fn max_response_bytes_rejects_oversized_bodies() {
+        let big = format!(r#"{{"series":[],"pad":"{}"}}"#, "x".repeat(256));
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(StatusCode::OK, big),
+            TestHttpResponse::new(StatusCode::OK, r#"{"series":[]}"#),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_max_response_bytes(64)
+            .build()
+            .expect("client");
+        let err = client
+            .get_series_list(GetSeriesListParams::default())
+            .await
+            .expect_err("oversized body");
+        match err {
+            KalshiError::Http {
+                status, raw_body, ..
+            } => {
+                assert_eq!(status, StatusCode::OK);
+                assert!(raw_body.starts_with("response too large"), "{raw_body}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        client
+            .get_series_list(GetSeriesListParams::default())
+            .await
+            .expect("small body");
+        server.await.expect("server").expect("server ok");
+
+        let requests = requests.lock().expect("requests lock");
+        let head = requests[0].to_ascii_lowercase();
+        assert!(head.contains("accept-encoding: gzip,br"), "{head}");
+    }
+
+    #[tokio::test]
+    async fn get_events_by_category_fans_out_over_series() {
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"series":[{"ticker":"KXFED"},{"ticker":"KXCPI"}]}"#,
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"events":[{"event_ticker":"KXFED-1"}],"cursor":""}"#,
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"events":[{"event_ticker":"KXCPI-1"}],"cursor":""}"#,
+            ),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .build()
+            .expect("client");
+        let events = client
+            .get_events_by_category("Economics", GetEventsParams::default())
+            .await
+            .expect("events");
+        server.await.expect("server").expect("server ok");
+
+        let tickers: Vec<_> = events.iter().map(|e| e.event_ticker.as_str()).collect();
+        assert_eq!(tickers, ["KXFED-1", "KXCPI-1"]);
+
+        let requests = requests.lock().expect("requests lock");
+        assert!(requests[0].contains("/series?category=Economics"));
+        assert!(requests[1].contains("series_ticker=KXFED"));
+        assert!(requests[2].contains("series_ticker=KXCPI"));
+    }
+
+    #[tokio::test]
+    async fn default_headers_are_sent_and_auth_headers_win() {
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"balance":0,"portfolio_value":0,"updated_ts":0}"#,
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"balance":0,"portfolio_value":0,"updated_ts":0}"#,
+            ),
+        ])
+        .await;
+
+        let default_client = KalshiRestClient::builder(test_env(origin.clone()))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .build()
+            .expect("client");
+        default_client.get_balance().await.expect("balance");
+
+        let client = default_client
+            .with_user_agent(HeaderValue::from_static("my-bot/1.0"))
+            .with_default_header(
+                HeaderName::from_static("x-app"),
+                HeaderValue::from_static("strategy-a"),
+            )
+            .with_default_header(
+                HeaderName::from_static("kalshi-access-key"),
+                HeaderValue::from_static("overridden"),
+            );
+        client.get_balance().await.expect("balance");
+        server.await.expect("server").expect("server ok");
+
+        let requests = requests.lock().expect("requests lock");
+        let first = requests[0].to_ascii_lowercase();
+        assert!(first.contains(&format!("user-agent: {DEFAULT_USER_AGENT}")));
+
+        let second = requests[1].to_ascii_lowercase();
+        assert!(second.contains("user-agent: my-bot/1.0"));
+        assert!(second.contains("x-app: strategy-a"));
+        assert!(!second.contains("kalshi-access-key: overridden"));
+        assert!(second.contains("kalshi-access-key:"));
+    }
+
+    #[tokio::test]
+    async fn order_group_lifecycle_round_trips() {
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(StatusCode::CREATED, r#"{"order_group_id":"og-1"}"#),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"is_auto_cancel_enabled":true,"contracts_limit":10,"contracts_limit_fp":"10.00","orders":[]}"#,
+            ),
+            TestHttpResponse::new(StatusCode::OK, "{}"),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .build()
+            .expect("client");
+        let created = client
+            .create_order_group(CreateOrderGroupRequest {
+                contracts_limit: Some(10),
+                ..Default::default()
+            })
+            .await
+            .expect("create");
+        assert_eq!(created.order_group_id, "og-1");
+
+        let group = client
+            .get_order_group(&created.order_group_id, SubaccountQueryParams::default())
+            .await
+            .expect("get");
+        assert_eq!(group.contracts_limit, Some(10));
+        assert_eq!(group.contracts_limit_fp.as_deref(), Some("10.00"));
+
+        client
+            .delete_order_group(&created.order_group_id, SubaccountQueryParams::default())
+            .await
+            .expect("delete");
+        server.await.expect("server").expect("server ok");
+
+        let requests = requests.lock().expect("requests lock");
+        assert!(requests[0].starts_with("POST /trade-api/v2/portfolio/order_groups/create"));
+        assert!(requests[1].starts_with("GET /trade-api/v2/portfolio/order_groups/og-1"));
+        assert!(requests[2].starts_with("DELETE /trade-api/v2/portfolio/order_groups/og-1"));
+    }
+
+    #[tokio::test]
+    async fn default_subaccount_fills_unset_params_only() {
+        let (origin, _hits, requests, server) = spawn_http_recording_server(vec![
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"market_positions":[],"event_positions":[]}"#,
+            ),
+            TestHttpResponse::new(StatusCode::OK, r#"{"fills":[],"cursor":""}"#),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_auth(crate::auth::tests::load_test_auth())
+            .with_default_subaccount(2)
+            .build()
+            .expect("client");
+        client
+            .get_positions(GetPositionsParams::default())
+            .await
+            .expect("positions");
+        client
+            .get_fills(GetFillsParams {
+                subaccount: Some(0),
+                ..Default::default()
+            })
+            .await
+            .expect("fills");
+        server.await.expect("server").expect("server ok");
+
+        let requests = requests.lock().expect("requests lock");
+        assert!(requests[0].contains("subaccount=2"));
+        assert!(requests[1].contains("subaccount=0"));
+        assert!(!requests[1].contains("subaccount=2"));
+    }
+
+    #[test]
+    fn default_subaccount_applies_to_batch_orders() {
+        let client = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_default_subaccount(5)
+            .build()
+            .expect("client");
+        let order = CreateOrderRequest::market_buy_budget("MKT-1", crate::YesNo::Yes, 100);
+        let batch = client.scoped(BatchCreateOrdersRequest {
+            orders: vec![
+                order.clone(),
+                CreateOrderRequest {
+                    subaccount: Some(1),
+                    ..order
+                },
+            ],
+        });
+        let subaccounts: Vec<_> = batch.orders.iter().map(|o| o.subaccount).collect();
+        assert_eq!(subaccounts, [Some(5), Some(1)]);
+    }
+
+    #[test]
+    fn builder_rejects_out_of_range_default_subaccount() {
+        let err = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_default_subaccount(33)
+            .build()
+            .expect_err("subaccount 33 should fail at build");
+        assert!(matches!(err, KalshiError::InvalidParams(_)));
+    }
+
+    #[test]
+    fn rate_limit_tiers_map_to_published_limits() {
+        let limits = |tier: RateLimitTier| {
+            let config = tier.config();
+            (config.read_rps, config.write_rps)
+        };
+        assert_eq!(limits(RateLimitTier::Basic), (20, 10));
+        assert_eq!(limits(RateLimitTier::Advanced), (30, 30));
+        assert_eq!(limits(RateLimitTier::Premier), (100, 100));
+    }
+
+    #[test]
+    fn builder_require_auth_fails_without_credentials() {
+        let err = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_rate_limit_tier(RateLimitTier::Basic)
+            .require_auth()
+            .build()
+            .expect_err("missing auth should fail at build");
+        assert!(matches!(err, KalshiError::AuthRequired(_)));
+
+        let client = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_auth(crate::auth::tests::load_test_auth())
+            .require_auth()
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn builder_rejects_invalid_user_agent() {
+        let err = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_user_agent("bad\nagent")
+            .build()
+            .expect_err("invalid user agent should fail at build");
+        assert!(matches!(err, KalshiError::Header(_)));
+    }
+
+    #[test]
+    fn builder_accepts_proxy_result_input() {
+        let client = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_proxy(reqwest::Proxy::all("http://127.0.0.1:8080"))
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn builder_rejects_invalid_proxy_result_input() {
+        let err = KalshiRestClient::builder(KalshiEnvironment::demo())
+            .with_proxy(reqwest::Proxy::all("not a url"))
+            .build()
+            .expect_err("invalid proxy should fail at build");
+
+        match err {
+            KalshiError::InvalidParams(message) => {
+                assert!(message.contains("invalid proxy configuration"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_retries_on_503_then_succeeds() {
+        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![
+            TestHttpResponse::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                r#"{"code":"unavailable","message":"try again"}"#,
+            ),
+            TestHttpResponse::new(
+                StatusCode::OK,
+                r#"{"exchange_active":true,"trading_active":true}"#,
+            ),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(rest_origin))
+            .with_retry_config(RetryConfig {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+                jitter: 0.0,
+                retry_non_idempotent: false,
+            })
+            .build()
+            .expect("build client");
+
+        let response = client
+            .get_exchange_status()
+            .await
+            .expect("request succeeds");
+        assert!(response.exchange_active);
+        assert_eq!(hits.load(Ordering::Relaxed), 2);
+        server.await.expect("server").expect("server ok");
+    }
+
+    #[tokio::test]
+    async fn post_does_not_retry_by_default() {
+        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![TestHttpResponse::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            r#"{"code":"unavailable","message":"retry me"}"#,
+        )])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(rest_origin))
+            .with_retry_config(RetryConfig {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+                jitter: 0.0,
+                retry_non_idempotent: false,
+            })
+            .build()
+            .expect("build client");
+
+        let path = KalshiRestClient::full_path("/test-post");
+        let result = client
+            .send::<(), _, serde_json::Value>(
+                Method::POST,
+                &path,
+                Option::<&()>::None,
+                Some(&json!({"x": 1})),
+                false,
+            )
+            .await;
+        assert!(matches!(result, Err(KalshiError::Http { .. })));
+        assert_eq!(hits.load(Ordering::Relaxed), 1);
+        server.await.expect("server").expect("server ok");
+    }
+
+    #[tokio::test]
+    async fn post_retry_opt_in_retries_and_succeeds() {
+        let (rest_origin, hits, server) = spawn_http_sequence_server(vec![
             TestHttpResponse::new(
                 StatusCode::SERVICE_UNAVAILABLE,
                 r#"{"code":"unavailable","message":"retry me"}"#,
@@ -2892,6 +4648,31 @@ mod tests {
         server.await.expect("server").expect("server ok");
     }
 
+    #[test]
+    fn retry_after_parses_seconds_and_http_date() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+            headers
+        };
+        assert_eq!(
+            retry_after_delay(&headers("7")),
+            Some(Duration::from_secs(7))
+        );
+
+        let at = SystemTime::now() + Duration::from_secs(30);
+        let delay = retry_after_delay(&headers(&httpdate::fmt_http_date(at))).expect("date");
+        assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+
+        let past = SystemTime::now() - Duration::from_secs(30);
+        assert_eq!(
+            retry_after_delay(&headers(&httpdate::fmt_http_date(past))),
+            None
+        );
+        assert_eq!(retry_after_delay(&headers("soon")), None);
+        assert_eq!(retry_after_delay(&HeaderMap::new()), None);
+    }
+
     #[tokio::test]
     async fn retry_after_header_is_honored_for_429() {
         let (rest_origin, hits, server) = spawn_http_sequence_server(vec![
@@ -2928,6 +4709,27 @@ mod tests {
         server.await.expect("server").expect("server ok");
     }
 
+    #[tokio::test]
+    async fn http_errors_carry_retry_after() {
+        let mock = crate::rest::MockTransport::new();
+        let mut resp = crate::rest::TransportResponse::new(StatusCode::SERVICE_UNAVAILABLE, "{}");
+        resp.headers
+            .insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        mock.push(resp);
+        let client = KalshiRestClient::builder(test_env(Url::parse("http://mock/").expect("url")))
+            .with_retry_config(RetryConfig {
+                max_retries: 0,
+                ..Default::default()
+            })
+            .with_transport(mock)
+            .build()
+            .expect("client");
+
+        let err = client.get_exchange_status().await.expect_err("503");
+        assert!(err.is_retryable());
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+    }
+
     #[tokio::test]
     async fn request_id_extraction_supports_both_header_names() {
         let (rest_origin_a, _hits_a, server_a) = spawn_http_sequence_server(vec![
@@ -2992,6 +4794,7 @@ mod tests {
         let limiter = RateLimiter::new(RateLimitConfig {
             read_rps: 0,
             write_rps: 0,
+            ..RateLimitConfig::default()
         });
 
         timeout(Duration::from_millis(10), limiter.wait(RateLimitKind::Read))
@@ -3005,6 +4808,170 @@ mod tests {
         .expect("write wait timed out");
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn rate_limiter_spaces_writes_in_virtual_time() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            read_rps: 0,
+            write_rps: 10,
+            ..RateLimitConfig::default()
+        });
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.wait(RateLimitKind::Write).await;
+        }
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(399), "{elapsed:?}");
+        assert!(elapsed <= Duration::from_millis(401), "{elapsed:?}");
+
+        let start = Instant::now();
+        limiter.wait(RateLimitKind::Read).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limiter_allows_burst_then_refills_at_rps() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            read_rps: 10,
+            write_rps: 0,
+            read_burst: 5,
+            ..RateLimitConfig::default()
+        });
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.wait(RateLimitKind::Read).await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Bucket empty: the next two wait one interval each.
+        limiter.wait(RateLimitKind::Read).await;
+        limiter.wait(RateLimitKind::Read).await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(199), "{elapsed:?}");
+        assert!(elapsed <= Duration::from_millis(201), "{elapsed:?}");
+
+        // A quiet second refills the bucket, capped at the burst size.
+        tokio::time::advance(Duration::from_secs(1)).await;
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.wait(RateLimitKind::Read).await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        limiter.wait(RateLimitKind::Read).await;
+        assert!(start.elapsed() >= Duration::from_millis(99));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_headers_stretch_spacing_and_are_reported() {
+        let market = r#"{"market":{"ticker":"MKT-1","event_ticker":"EV","status":"active"}}"#;
+        let with_limits = |remaining: &'static str, reset: &'static str| {
+            let mut resp = crate::rest::TransportResponse::new(StatusCode::OK, market);
+            resp.headers
+                .insert("x-ratelimit-remaining", HeaderValue::from_static(remaining));
+            resp.headers
+                .insert("x-ratelimit-reset", HeaderValue::from_static(reset));
+            resp.headers
+                .insert("x-ratelimit-limit", HeaderValue::from_static("20"));
+            resp
+        };
+        let mock = crate::rest::MockTransport::new();
+        mock.push(with_limits("1", "3"))
+            .push(with_limits("15", "1"))
+            .push_response(StatusCode::OK, market)
+            .push_response(StatusCode::OK, market);
+        let client = KalshiRestClient::builder(test_env(Url::parse("http://mock/").expect("url")))
+            .with_rate_limit_config(RateLimitConfig {
+                read_rps: 0,
+                ..RateLimitConfig::default()
+            })
+            .with_transport(mock.clone())
+            .build()
+            .expect("client");
+        assert_eq!(client.rate_limit_state(), RateLimitState::default());
+
+        client.get_market("MKT-1").await.expect("market");
+        let state = client.rate_limit_state();
+        let read = state.read.expect("read observation");
+        assert_eq!((read.remaining, read.limit), (1, Some(20)));
+        assert_eq!(
+            read.reset_at.map(|at| at - read.observed_at),
+            Some(Duration::from_secs(3))
+        );
+        assert!(state.write.is_none());
+
+        // One request left for three seconds: the next waits half of that.
+        let start = Instant::now();
+        client.get_market("MKT-1").await.expect("market");
+        assert_eq!(start.elapsed(), Duration::from_millis(1500));
+        assert_eq!(
+            client.rate_limit_state().read.map(|r| r.remaining),
+            Some(15)
+        );
+
+        // Plenty of headroom: no delay, and header-less responses keep the last state.
+        let start = Instant::now();
+        client.get_market("MKT-1").await.expect("market");
+        client.get_market("MKT-1").await.expect("market");
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(
+            client.rate_limit_state().read.map(|r| r.remaining),
+            Some(15)
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("4"));
+        let epoch = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60;
+        headers.insert(
+            "ratelimit-reset",
+            HeaderValue::from_str(&epoch.to_string()).unwrap(),
+        );
+        let now = Instant::now();
+        let parsed = parse_rate_limit_headers(&headers, now).expect("parsed");
+        let until_reset = parsed.reset_at.expect("reset") - now;
+        assert_eq!(parsed.remaining, 4);
+        assert!(until_reset > Duration::from_secs(58) && until_reset <= Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn concurrency_limiter_bounds_reads_independently_of_writes() {
+        let limiter = ConcurrencyLimiter::new(ConcurrencyConfig {
+            max_concurrent_reads: 1,
+            max_concurrent_writes: 0,
+        });
+
+        let held = limiter.acquire(RateLimitKind::Read).await;
+        assert!(held.is_some());
+        assert!(
+            timeout(
+                Duration::from_millis(20),
+                limiter.acquire(RateLimitKind::Read)
+            )
+            .await
+            .is_err(),
+            "second read should wait for a permit"
+        );
+        let write = timeout(
+            Duration::from_millis(20),
+            limiter.acquire(RateLimitKind::Write),
+        )
+        .await
+        .expect("writes are unbounded");
+        assert!(write.is_none());
+
+        drop(held);
+        timeout(
+            Duration::from_millis(20),
+            limiter.acquire(RateLimitKind::Read),
+        )
+        .await
+        .expect("read permit released");
+    }
+
     #[tokio::test]
     async fn paginate_cursor_collects_all_pages() {
         let client = KalshiRestClient::new(KalshiEnvironment::demo());
@@ -3043,12 +5010,38 @@ mod tests {
             Box::pin(async move { Ok(page) })
         });
 
+        assert_eq!(pager.last_page_len(), None);
+        assert_eq!(pager.pages_fetched(), 0);
         let first = pager.next_page().await.unwrap().unwrap();
         assert_eq!(first, vec![1, 2]);
+        assert_eq!(pager.last_page_len(), Some(2));
         let second = pager.next_page().await.unwrap().unwrap();
         assert_eq!(second, vec![3]);
+        assert_eq!(pager.last_page_len(), Some(1));
+        assert_eq!(pager.total_fetched(), 3);
         let done = pager.next_page().await.unwrap();
         assert!(done.is_none());
+        assert_eq!(pager.total_fetched(), 3);
+        assert_eq!(pager.pages_fetched(), 2);
+    }
+
+    #[test]
+    fn scope_to_series_sets_and_checks_series_ticker() {
+        let params = scope_to_series("KXFED", GetEventsParams::default()).expect("scoped");
+        assert_eq!(params.series_ticker.as_deref(), Some("KXFED"));
+
+        let same = GetEventsParams {
+            series_ticker: Some("KXFED".to_string()),
+            ..Default::default()
+        };
+        assert!(scope_to_series("KXFED", same).is_ok());
+
+        let other = GetEventsParams {
+            series_ticker: Some("KXCPI".to_string()),
+            ..Default::default()
+        };
+        let err = scope_to_series("KXFED", other).unwrap_err();
+        assert!(matches!(err, KalshiError::InvalidParams(_)));
     }
 
     #[tokio::test]
@@ -3072,4 +5065,142 @@ mod tests {
         // Should only fetch as many pages as needed to reach 3 items.
         assert_eq!(call_count.load(std::sync::atomic::Ordering::Relaxed), 2);
     }
+
+    #[tokio::test]
+    async fn stream_items_buffered_prefetches_and_truncates() {
+        let pager = |count: Arc<AtomicUsize>| {
+            let mut pages = VecDeque::from(vec![
+                (vec![1, 2], Some("c1".to_string())),
+                (vec![], Some("c2".to_string())),
+                (vec![3, 4], Some("c3".to_string())),
+                (vec![5], None),
+            ]);
+            CursorPager::new(None, move |_cursor| {
+                count.fetch_add(1, Ordering::Relaxed);
+                let page = pages.pop_front().unwrap_or((Vec::<i32>::new(), None));
+                Box::pin(async move { Ok(page) })
+            })
+        };
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut stream = Box::pin(stream_items_buffered(pager(count.clone()), None, 8));
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        timeout(Duration::from_secs(1), async {
+            while count.load(Ordering::Relaxed) < 4 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("pages fetched ahead of the consumer");
+        let rest: Vec<i32> = stream.try_collect().await.unwrap();
+        assert_eq!(rest, vec![2, 3, 4, 5]);
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let items: Vec<i32> = stream_items_buffered(pager(count.clone()), Some(3), 8)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn dropping_stream_items_buffered_aborts_the_prefetch_task() {
+        struct SetOnDrop(Arc<AtomicBool>);
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = dropped.clone();
+        let mut first = true;
+        let pager = CursorPager::new(None, move |_cursor| {
+            let guard = SetOnDrop(flag.clone());
+            let page = std::mem::take(&mut first).then(|| (vec![1], Some("c1".to_string())));
+            Box::pin(async move {
+                let _guard = guard;
+                match page {
+                    Some(page) => Ok(page),
+                    None => std::future::pending().await,
+                }
+            })
+        });
+
+        let mut stream = Box::pin(stream_items_buffered(pager, None, 1));
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        // The second fetch never finishes; dropping the stream must cancel it.
+        tokio::task::yield_now().await;
+        dropped.store(false, Ordering::Relaxed);
+        drop(stream);
+        timeout(Duration::from_secs(1), async {
+            while !dropped.load(Ordering::Relaxed) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("prefetch task aborted");
+    }
+
+    #[tokio::test]
+    async fn stream_trades_window_splits_chain_and_stops_at_start() {
+        let trade = |id: &str, time: &str| {
+            format!(r#"{{"trade_id":"{id}","ticker":"T","created_time":"{time}"}}"#)
+        };
+        let page = |trades: Vec<String>, cursor: Option<&str>| {
+            let cursor = cursor.map_or("null".to_string(), |c| format!("\"{c}\""));
+            TestHttpResponse::new(
+                StatusCode::OK,
+                format!(r#"{{"trades":[{}],"cursor":{cursor}}}"#, trades.join(",")),
+            )
+        };
+
+        // start_ts = 100, end_ts = 200; split after every page.
+        let (origin, hits, server) = spawn_http_sequence_server(vec![
+            page(
+                vec![
+                    trade("a", "1970-01-01T00:03:20Z"),
+                    trade("b", "1970-01-01T00:02:30Z"),
+                ],
+                Some("c1"),
+            ),
+            // New chain with max_ts = 150 re-returns "b" at the boundary.
+            page(
+                vec![
+                    trade("b", "1970-01-01T00:02:30Z"),
+                    trade("c", "1970-01-01T00:02:30Z"),
+                    trade("d", "1970-01-01T00:01:40Z"),
+                ],
+                Some("c2"),
+            ),
+            page(
+                vec![
+                    trade("e", "1970-01-01T00:01:40Z"),
+                    trade("f", "1970-01-01T00:01:39Z"),
+                ],
+                Some("c3"),
+            ),
+        ])
+        .await;
+
+        let client = KalshiRestClient::builder(test_env(origin))
+            .with_retry_config(RetryConfig {
+                max_retries: 0,
+                ..RetryConfig::default()
+            })
+            .build()
+            .expect("client");
+
+        let trades: Vec<Trade> =
+            stream_trades_window_inner(client, "T".to_string(), 100, 200, None, 1)
+                .try_collect()
+                .await
+                .expect("stream");
+
+        let ids: Vec<&str> = trades.iter().map(|t| t.trade_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(hits.load(Ordering::Relaxed), 3);
+        server.await.expect("server task").expect("server io");
+    }
 }
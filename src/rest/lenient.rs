@@ -0,0 +1,124 @@
+use crate::error::KalshiError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+
+/// Upper bound on fields dropped from a single response before giving up.
+const MAX_REPAIRS: usize = 64;
+
+tokio::task_local! {
+    /// Warnings for the enclosing [`KalshiRestClient::lenient`](crate::KalshiRestClient::lenient) call.
+    static WARNINGS: RefCell<Vec<ParseWarning>>;
+}
+
+/// A value dropped from a response decoded in lenient mode.
+///
+/// Returned by [`KalshiRestClient::lenient`](crate::KalshiRestClient::lenient).
+#[derive(Debug, Clone)]
+pub struct ParseWarning {
+    /// Request that returned the value, e.g. `REST GET /trade-api/v2/markets`.
+    pub context: String,
+    /// The response's `x-request-id`, if sent.
+    pub request_id: Option<String>,
+    /// Location of the dropped value, e.g. `markets[3].volume`.
+    pub path: String,
+    /// Why the typed decode rejected it.
+    pub reason: String,
+    /// The value as sent by the server.
+    pub raw: Value,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: dropped `{}`: {}",
+            self.context, self.path, self.reason
+        )
+    }
+}
+
+/// Data from a call made through [`KalshiRestClient::lenient`](crate::KalshiRestClient::lenient),
+/// with the values its responses dropped.
+#[derive(Debug, Clone)]
+pub struct Lenient<T> {
+    pub data: T,
+    /// Empty when every response matched its typed schema.
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// Run `call`, collecting the warnings recorded by responses it decodes.
+pub(crate) async fn collect_warnings<T>(
+    call: impl Future<Output = Result<T, KalshiError>>,
+) -> Result<Lenient<T>, KalshiError> {
+    WARNINGS
+        .scope(RefCell::new(Vec::new()), async move {
+            let data = call.await?;
+            let warnings = WARNINGS.with(RefCell::take);
+            Ok(Lenient { data, warnings })
+        })
+        .await
+}
+
+/// Whether the current task is inside [`collect_warnings`].
+pub(crate) fn collecting_warnings() -> bool {
+    WARNINGS.try_with(|_| ()).is_ok()
+}
+
+/// Hand `warnings` to the enclosing [`collect_warnings`], if any.
+pub(crate) fn record_warnings(warnings: Vec<ParseWarning>) {
+    let _ = WARNINGS.try_with(|collected| collected.borrow_mut().extend(warnings));
+}
+
+/// Decode `bytes` as `T`, dropping each value the typed decode rejects.
+///
+/// A bad field is removed so it falls back to its default; a list element
+/// missing a required field is removed from the list. Returns `None` if the
+/// body isn't JSON or the failure can't be isolated below the top level.
+pub(crate) fn decode_dropping_invalid<T: DeserializeOwned>(
+    context: &str,
+    request_id: Option<&str>,
+    bytes: &[u8],
+) -> Option<(T, Vec<ParseWarning>)> {
+    let mut value: Value = serde_json::from_slice(bytes).ok()?;
+    let mut warnings = Vec::new();
+    for _ in 0..MAX_REPAIRS {
+        let err = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(parsed) => return Some((parsed, warnings)),
+            Err(err) => err,
+        };
+        let raw = remove_at(&mut value, err.path())?;
+        warnings.push(ParseWarning {
+            context: context.to_string(),
+            request_id: request_id.map(str::to_string),
+            path: err.path().to_string(),
+            reason: err.inner().to_string(),
+            raw,
+        });
+    }
+    None
+}
+
+fn remove_at(value: &mut Value, path: &Path) -> Option<Value> {
+    let segments: Vec<&Segment> = path.iter().collect();
+    let (last, parents) = segments.split_last()?;
+    let mut target = value;
+    for segment in parents {
+        target = match segment {
+            Segment::Map { key } => target.get_mut(key.as_str())?,
+            Segment::Seq { index } => target.get_mut(*index)?,
+            _ => return None,
+        };
+    }
+    match last {
+        Segment::Map { key } => target.as_object_mut()?.remove(key.as_str()),
+        Segment::Seq { index } => {
+            let items = target.as_array_mut()?;
+            (*index < items.len()).then(|| items.remove(*index))
+        }
+        _ => None,
+    }
+}
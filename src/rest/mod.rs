@@ -57,11 +57,25 @@
 //! # }
 //! ```
 
+#[cfg(feature = "blocking")]
+mod blocking;
+mod catalog;
 mod client;
+mod lenient;
+mod transport;
 pub mod types;
 
+#[cfg(feature = "blocking")]
+pub use blocking::KalshiRestClientSync;
+
+pub use catalog::MarketCatalog;
+pub use lenient::{Lenient, ParseWarning};
+#[cfg(any(test, feature = "test-util"))]
+pub use transport::MockTransport;
+pub use transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
+
 pub use client::{
-    CursorPager, KalshiRestClient, KalshiRestClientBuilder, RateLimitConfig, RateLimitTier,
-    RetryConfig,
+    ConcurrencyConfig, CursorPager, KalshiRestClient, KalshiRestClientBuilder, RateLimitConfig,
+    RateLimitObservation, RateLimitState, RateLimitTier, RetryConfig,
 };
 pub use types::*;
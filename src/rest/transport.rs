@@ -0,0 +1,220 @@
+use crate::error::KalshiError;
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode};
+use std::fmt;
+use url::Url;
+
+/// One HTTP request produced by [`KalshiRestClient`](crate::KalshiRestClient).
+///
+/// The URL already carries the query string, and `headers` include the
+/// client's default headers and, for authenticated endpoints, the signed
+/// `KALSHI-ACCESS-*` headers.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// JSON body, if the endpoint sends one.
+    pub body: Option<Bytes>,
+    /// Body limit from
+    /// [`with_max_response_bytes`](crate::KalshiRestClientBuilder::with_max_response_bytes).
+    /// The client also checks it after the transport returns.
+    pub max_response_bytes: Option<usize>,
+}
+
+/// A complete HTTP response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl TransportResponse {
+    pub fn new(status: StatusCode, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Sends the requests built by [`KalshiRestClient`](crate::KalshiRestClient).
+///
+/// The client keeps rate limiting, retries, signing, and decoding; a
+/// transport only moves bytes. Install one with
+/// [`KalshiRestClientBuilder::with_transport`](crate::KalshiRestClientBuilder::with_transport).
+/// Failures are retried when they are a [`KalshiError::Reqwest`] connect,
+/// timeout, or request error; return any other variant to fail immediately.
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn send(
+        &self,
+        request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, KalshiError>>;
+}
+
+/// The default [`Transport`], backed by a `reqwest::Client`.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(
+        &self,
+        request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, KalshiError>> {
+        Box::pin(async move {
+            let mut req = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                req = req.body(body);
+            }
+            let mut resp = req.send().await?;
+            let status = resp.status();
+            let headers = std::mem::take(resp.headers_mut());
+            let too_large = |limit: usize| KalshiError::Http {
+                status,
+                api_error: None,
+                raw_body: format!("response too large: exceeded {limit} bytes"),
+                request_id: request_id(&headers),
+                retry_after: None,
+            };
+
+            let body = match request.max_response_bytes {
+                None => resp.bytes().await?,
+                Some(limit) => {
+                    // Fail fast on a declared length; streamed bodies are counted as they arrive.
+                    if resp.content_length().is_some_and(|len| len > limit as u64) {
+                        return Err(too_large(limit));
+                    }
+                    let mut buf = Vec::new();
+                    while let Some(chunk) = resp.chunk().await? {
+                        if buf.len() + chunk.len() > limit {
+                            return Err(too_large(limit));
+                        }
+                        buf.extend_from_slice(&chunk);
+                    }
+                    buf.into()
+                }
+            };
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("x-request-id")
+        .or_else(|| headers.get("request-id"))
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::MockTransport;
+
+#[cfg(any(test, feature = "test-util"))]
+mod mock {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct MockState {
+        responses: VecDeque<TransportResponse>,
+        requests: Vec<TransportRequest>,
+    }
+
+    /// A [`Transport`] that records requests and replays queued responses.
+    ///
+    /// Clones share state, so keep one to inspect what the client sent:
+    ///
+    /// ```
+    /// use kalshi_fast::{KalshiEnvironment, KalshiRestClient, MockTransport};
+    /// use reqwest::StatusCode;
+    ///
+    /// # async fn run() -> Result<(), kalshi_fast::KalshiError> {
+    /// let mock = MockTransport::new();
+    /// mock.push_response(StatusCode::OK, r#"{"series":{"ticker":"KXBTC"}}"#);
+    /// let client = KalshiRestClient::builder(KalshiEnvironment::demo())
+    ///     .with_transport(mock.clone())
+    ///     .build()?;
+    ///
+    /// client.get_series("KXBTC").await?;
+    /// assert_eq!(mock.requests()[0].url.path(), "/trade-api/v2/series/KXBTC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires the `test-util` feature.
+    #[derive(Debug, Clone, Default)]
+    pub struct MockTransport {
+        state: Arc<Mutex<MockState>>,
+    }
+
+    impl MockTransport {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Queue a response with `status` and `body`. Responses are returned
+        /// in the order queued, one per request (including retries).
+        pub fn push_response(&self, status: StatusCode, body: impl Into<Bytes>) -> &Self {
+            self.push(TransportResponse::new(status, body))
+        }
+
+        /// Queue a fully built response.
+        pub fn push(&self, response: TransportResponse) -> &Self {
+            self.lock().responses.push_back(response);
+            self
+        }
+
+        /// Every request sent so far, oldest first.
+        pub fn requests(&self) -> Vec<TransportRequest> {
+            self.lock().requests.clone()
+        }
+
+        /// Number of queued responses not yet returned.
+        pub fn remaining(&self) -> usize {
+            self.lock().responses.len()
+        }
+
+        fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+            self.state.lock().expect("mock transport lock")
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn send(
+            &self,
+            request: TransportRequest,
+        ) -> BoxFuture<'_, Result<TransportResponse, KalshiError>> {
+            let mut state = self.lock();
+            let description = format!("{} {}", request.method, request.url);
+            state.requests.push(request);
+            let response = state.responses.pop_front().ok_or_else(|| {
+                KalshiError::InvalidParams(format!(
+                    "MockTransport: no response queued for {description}"
+                ))
+            });
+            Box::pin(async move { response })
+        }
+    }
+}
@@ -1,17 +1,22 @@
 use crate::error::KalshiError;
 use crate::types::{
-    BuySell, ErrorResponse, EventStatus, FeeType, FixedPointCount, FixedPointDollars,
-    MarketStatusQuery, MveFilter, OrderStatus, OrderType, PositionCountFilter,
+    BuySell, ErrorResponse, EventStatus, FeeType, FixedPoint, FixedPointCount, FixedPointDollars,
+    MarketStatusQuery, MveFilter, OrderStatus, OrderType, PositionCountFilter, Quantity,
     SelfTradePreventionType, TimeInForce, TradeTakerSide, YesNo, deserialize_null_as_empty_vec,
-    deserialize_string_or_number, serialize_csv_opt,
+    deserialize_string_or_number, parse_decimal_scaled, parse_rfc3339_unix_seconds,
+    serialize_csv_opt,
 };
+use crate::ws::{WsMultivariateSelectedMarket, best_level_price};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 /// --- Series ---
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct SettlementSource {
     #[serde(default)]
     pub name: Option<String>,
@@ -22,6 +27,7 @@ pub struct SettlementSource {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct MarketMetadata {
     pub market_ticker: String,
     #[serde(default)]
@@ -33,6 +39,7 @@ pub struct MarketMetadata {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct EventMetadata {
     #[serde(default)]
     pub image_url: Option<String>,
@@ -154,6 +161,7 @@ impl GetEventsParams {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Milestone {
     #[serde(default)]
     pub id: Option<String>,
@@ -195,6 +203,7 @@ pub struct Milestone {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct EventData {
     pub event_ticker: String,
     #[serde(default)]
@@ -312,6 +321,12 @@ impl MarketStatus {
             MarketStatus::Unknown => "unknown",
         }
     }
+
+    /// Whether positions in the market have been paid out (`finalized`).
+    /// `determined` and later review states can still change.
+    pub fn is_settled(self) -> bool {
+        matches!(self, MarketStatus::Finalized)
+    }
 }
 
 /// Error returned by strict lifecycle/query status conversions.
@@ -410,6 +425,7 @@ impl TryFrom<&MarketStatusQuery> for MarketStatus {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct MveSelectedLeg {
     #[serde(default)]
     pub event_ticker: Option<String>,
@@ -424,6 +440,7 @@ pub struct MveSelectedLeg {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct PriceRange {
     #[serde(alias = "min_price")]
     pub start: String,
@@ -434,6 +451,7 @@ pub struct PriceRange {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Market {
     pub ticker: String,
     #[serde(default)]
@@ -612,6 +630,268 @@ pub struct Market {
     pub extra: Map<String, Value>,
 }
 
+/// Fixed-point digits used for price ladder arithmetic (micro-dollars).
+const PRICE_LADDER_DIGITS: u32 = 6;
+/// Price-ladder units in one cent.
+const CENT_UNITS: i64 = 10_i64.pow(PRICE_LADDER_DIGITS - 2);
+
+/// Unit of a price value: integer cents or a fixed-point dollar string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceUnit {
+    Cents,
+    Dollars,
+}
+
+impl PriceUnit {
+    /// Parse a `response_price_units` value such as `"usd_cent"`, ignoring
+    /// ASCII case.
+    pub fn parse(value: &str) -> Option<Self> {
+        const CENTS: [&str; 4] = ["usd_cent", "usd_cents", "cent", "cents"];
+        const DOLLARS: [&str; 5] = ["usd", "usd_dollar", "usd_dollars", "dollar", "dollars"];
+        let is = |names: &[&str]| names.iter().any(|name| value.eq_ignore_ascii_case(name));
+        if is(&CENTS) {
+            Some(PriceUnit::Cents)
+        } else if is(&DOLLARS) {
+            Some(PriceUnit::Dollars)
+        } else {
+            None
+        }
+    }
+}
+
+/// A [`Market`] price carried as parallel cents and `_dollars` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketPriceField {
+    YesBid,
+    YesAsk,
+    NoBid,
+    NoAsk,
+    LastPrice,
+    PreviousYesBid,
+    PreviousYesAsk,
+    PreviousPrice,
+}
+
+/// Client-side ordering for [`KalshiRestClient::get_markets_sorted`].
+///
+/// `GET /markets` has no sort parameters. Size keys sort largest first and
+/// time keys soonest first; markets missing the value sort last, and ties
+/// fall back to `ticker`.
+///
+/// [`KalshiRestClient::get_markets_sorted`]: crate::rest::KalshiRestClient::get_markets_sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketSortKey {
+    /// Lifetime volume (`volume_fp`, else `volume`).
+    Volume,
+    /// Trailing 24h volume (`volume_24h_fp`, else `volume_24h`).
+    Volume24h,
+    /// Open interest (`open_interest_fp`, else `open_interest`).
+    OpenInterest,
+    /// Liquidity (`liquidity_dollars`, else `liquidity` in cents).
+    Liquidity,
+    /// Close time (`close_ts`, else `close_time`).
+    CloseTime,
+    /// Expiration time (`expiration_ts`, else `expiration_time`).
+    ExpirationTime,
+}
+
+impl MarketSortKey {
+    /// Compare two markets with this key's ordering.
+    pub fn compare(self, a: &Market, b: &Market) -> Ordering {
+        self.rank(a)
+            .cmp(&self.rank(b))
+            .then_with(|| a.ticker.cmp(&b.ticker))
+    }
+
+    /// Sort `markets` with this key's ordering, reading each market's value
+    /// once.
+    pub fn sort(self, markets: &mut [Market]) {
+        markets.sort_by_cached_key(|market| (self.rank(market), market.ticker.clone()));
+    }
+
+    /// Ascending sort key: missing values last, sizes negated so the
+    /// largest comes first.
+    fn rank(self, market: &Market) -> (bool, i64) {
+        let value = match self {
+            MarketSortKey::CloseTime | MarketSortKey::ExpirationTime => self.time(market),
+            _ => self.size(market).map(|size| -size),
+        };
+        (value.is_none(), value.unwrap_or_default())
+    }
+
+    /// Size in hundredths of a contract, or micro-dollars for liquidity.
+    fn size(self, market: &Market) -> Option<i64> {
+        let (fp, whole) = match self {
+            MarketSortKey::Volume => (&market.volume_fp, market.volume),
+            MarketSortKey::Volume24h => (&market.volume_24h_fp, market.volume_24h),
+            MarketSortKey::OpenInterest => (&market.open_interest_fp, market.open_interest),
+            MarketSortKey::Liquidity => {
+                return market
+                    .liquidity_dollars
+                    .as_deref()
+                    .and_then(parse_signed_price_units)
+                    .or_else(|| market.liquidity?.checked_mul(CENT_UNITS));
+            }
+            _ => return None,
+        };
+        fp.as_deref()
+            .and_then(Quantity::parse)
+            .map(Quantity::hundredths)
+            .or_else(|| whole?.checked_mul(100))
+    }
+
+    fn time(self, market: &Market) -> Option<i64> {
+        let (ts, time) = match self {
+            MarketSortKey::CloseTime => (market.close_ts, &market.close_time),
+            MarketSortKey::ExpirationTime => (market.expiration_ts, &market.expiration_time),
+            _ => return None,
+        };
+        ts.or_else(|| time.as_deref().and_then(parse_rfc3339_unix_seconds))
+    }
+}
+
+impl Market {
+    /// [`response_price_units`](Self::response_price_units) as a [`PriceUnit`].
+    pub fn response_price_unit(&self) -> Option<PriceUnit> {
+        self.response_price_units
+            .as_deref()
+            .and_then(PriceUnit::parse)
+    }
+
+    /// Read `field` in `unit`, converting from the other representation when
+    /// only that one was populated. Dollars are preferred as the source since
+    /// they keep subpenny precision, e.g. `"0.4350"` reads as `43.50` cents.
+    pub fn price_in(&self, field: MarketPriceField, unit: PriceUnit) -> Option<FixedPoint> {
+        let (cents, dollars) = match field {
+            MarketPriceField::YesBid => (self.yes_bid, &self.yes_bid_dollars),
+            MarketPriceField::YesAsk => (self.yes_ask, &self.yes_ask_dollars),
+            MarketPriceField::NoBid => (self.no_bid, &self.no_bid_dollars),
+            MarketPriceField::NoAsk => (self.no_ask, &self.no_ask_dollars),
+            MarketPriceField::LastPrice => (self.last_price, &self.last_price_dollars),
+            MarketPriceField::PreviousYesBid => {
+                (self.previous_yes_bid, &self.previous_yes_bid_dollars)
+            }
+            MarketPriceField::PreviousYesAsk => {
+                (self.previous_yes_ask, &self.previous_yes_ask_dollars)
+            }
+            MarketPriceField::PreviousPrice => (self.previous_price, &self.previous_price_dollars),
+        };
+        let units = match dollars.as_deref().and_then(parse_price_units) {
+            Some(units) => units,
+            None => cents?.checked_mul(CENT_UNITS)?,
+        };
+        match unit {
+            PriceUnit::Cents => Some(FixedPoint::from_micro_units(units.checked_mul(100)?, 2)),
+            PriceUnit::Dollars => Some(FixedPoint::from_micro_units(units, 4)),
+        }
+    }
+
+    /// Whether the market is `active` and not flagged `can_trade = false`.
+    ///
+    /// Pair with [`GetMarketsParams::tradable_open`]: `status=open` can still
+    /// return markets the exchange won't accept orders on.
+    pub fn is_tradable_now(&self) -> bool {
+        self.status == Some(MarketStatus::Active) && self.can_trade != Some(false)
+    }
+
+    /// Whether `price_dollars` sits on one of the market's [`PriceRange`] ticks.
+    ///
+    /// Returns `false` if the price can't be parsed or the market has no
+    /// `price_ranges`.
+    pub fn is_valid_price(&self, price_dollars: &str) -> bool {
+        let Some(price) = parse_price_units(price_dollars) else {
+            return false;
+        };
+        self.price_ladder()
+            .any(|(start, end, step)| price >= start && price <= end && (price - start) % step == 0)
+    }
+
+    /// Snap `price_dollars` to the nearest legal tick, clamping into the ladder.
+    ///
+    /// Returns the input unchanged if it can't be parsed or the market has no
+    /// usable `price_ranges`.
+    pub fn round_to_tick(&self, price_dollars: &str) -> String {
+        let Some(price) = parse_price_units(price_dollars) else {
+            return price_dollars.to_string();
+        };
+        let mut best: Option<(i64, usize)> = None;
+        for (range, (start, end, step)) in self
+            .price_ranges
+            .iter()
+            .flatten()
+            .filter_map(|r| Some((r, r.ladder()?)))
+        {
+            let clamped = price.clamp(start, end);
+            let mut snapped = start + ((clamped - start) + step / 2) / step * step;
+            if snapped > end {
+                snapped -= step;
+            }
+            let digits = fraction_digits(&range.step).max(fraction_digits(&range.start));
+            if best.is_none_or(|(prev, _)| (snapped - price).abs() < (prev - price).abs()) {
+                best = Some((snapped, digits));
+            }
+        }
+        match best {
+            Some((units, digits)) => format_price_units(units, digits.max(2)),
+            None => price_dollars.to_string(),
+        }
+    }
+
+    fn price_ladder(&self) -> impl Iterator<Item = (i64, i64, i64)> + '_ {
+        self.price_ranges
+            .iter()
+            .flatten()
+            .filter_map(PriceRange::ladder)
+    }
+}
+
+impl PriceRange {
+    /// `(start, end, step)` in micro-dollars, or `None` if unparsable or degenerate.
+    fn ladder(&self) -> Option<(i64, i64, i64)> {
+        let start = parse_price_units(&self.start)?;
+        let end = parse_price_units(&self.end)?;
+        let step = parse_price_units(&self.step)?;
+        (step > 0 && start <= end).then_some((start, end, step))
+    }
+}
+
+fn parse_price_units(value: &str) -> Option<i64> {
+    if value.trim().starts_with('-') {
+        return None;
+    }
+    parse_signed_price_units(value)
+}
+
+fn format_price_units(units: i64, digits: usize) -> String {
+    let digits = digits.min(PRICE_LADDER_DIGITS as usize);
+    let scale = 10_i64.pow(PRICE_LADDER_DIGITS);
+    let frac = format!(
+        "{:0width$}",
+        units % scale,
+        width = PRICE_LADDER_DIGITS as usize
+    );
+    format!("{}.{}", units / scale, &frac[..digits])
+}
+
+pub(crate) fn parse_signed_price_units(value: &str) -> Option<i64> {
+    match parse_decimal_scaled(value, PRICE_LADDER_DIGITS)? {
+        (units, false) => Some(units),
+        (_, true) => None,
+    }
+}
+
+pub(crate) fn format_signed_price_units(units: i64, digits: usize) -> String {
+    let abs = format_price_units(units.abs(), digits);
+    if units < 0 { format!("-{abs}") } else { abs }
+}
+
+fn fraction_digits(value: &str) -> usize {
+    value
+        .split_once('.')
+        .map(|(_, frac)| frac.trim_end_matches('0').len())
+        .unwrap_or(0)
+}
+
 /// GET /markets query params and constraints
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct GetMarketsParams {
@@ -665,6 +945,17 @@ pub struct GetMarketsParams {
 }
 
 impl GetMarketsParams {
+    /// `status=open`, the closest server-side filter for tradable markets.
+    ///
+    /// The API has no `can_trade` filter; check [`Market::is_tradable_now`]
+    /// on each result.
+    pub fn tradable_open() -> Self {
+        Self {
+            status: Some(MarketStatusQuery::Open),
+            ..Default::default()
+        }
+    }
+
     pub fn validate(&self) -> Result<(), KalshiError> {
         if let Some(limit) = self.limit
             && (limit == 0 || limit > 1000)
@@ -809,6 +1100,38 @@ pub struct OrderbookFp {
     pub no_dollars: Vec<(FixedPointDollars, String)>,
 }
 
+impl Orderbook {
+    /// Best YES bid and best YES ask in cents, or `None` unless both sides
+    /// have a bid. The ask is implied by the best NO bid (`100 - price`).
+    ///
+    /// Reads the levels as [`OrderBook::from_rest`](crate::OrderBook::from_rest) does, without building one.
+    pub fn best_bid_ask(&self) -> Option<(i64, i64)> {
+        let yes = best_level_price(&[], &self.yes, &self.yes_dollars)?;
+        let no = best_level_price(&[], &self.no, &self.no_dollars)?;
+        Some((yes, 100 - no))
+    }
+}
+
+impl OrderbookFp {
+    /// Levels on `side` as (price_dollars, quantity_fp), in the order the API
+    /// sent them. Empty for [`YesNo::Unknown`].
+    pub fn to_fixed_levels(&self, side: YesNo) -> Vec<(FixedPointDollars, FixedPointCount)> {
+        match side {
+            YesNo::Yes => self.yes_dollars.clone(),
+            YesNo::No => self.no_dollars.clone(),
+            YesNo::Unknown => Vec::new(),
+        }
+    }
+
+    /// Best YES bid and best YES ask in cents; see [`Orderbook::best_bid_ask`].
+    /// Levels with a zero or malformed quantity are ignored.
+    pub fn best_bid_ask(&self) -> Option<(i64, i64)> {
+        let yes = best_level_price(&self.yes_dollars, &[], &[])?;
+        let no = best_level_price(&self.no_dollars, &[], &[])?;
+        Some((yes, 100 - no))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct GetMarketOrderbookParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -822,9 +1145,44 @@ pub struct GetMarketOrderbookResponse {
     pub orderbook_fp: Option<OrderbookFp>,
 }
 
+impl GetMarketOrderbookResponse {
+    /// Best YES bid and ask in cents from `orderbook_fp` when present,
+    /// otherwise from `orderbook`.
+    pub fn best_bid_ask(&self) -> Option<(i64, i64)> {
+        match &self.orderbook_fp {
+            Some(fp) => fp.best_bid_ask(),
+            None => self.orderbook.best_bid_ask(),
+        }
+    }
+
+    /// Levels on `side` as fixed-point strings. Uses `orderbook_fp` when
+    /// present; otherwise the cent levels are formatted as dollars
+    /// (`"0.4500"`) and counts (`"10.00"`).
+    pub fn fixed_levels(&self, side: YesNo) -> Vec<(FixedPointDollars, FixedPointCount)> {
+        if let Some(fp) = &self.orderbook_fp {
+            return fp.to_fixed_levels(side);
+        }
+        let levels = match side {
+            YesNo::Yes => &self.orderbook.yes,
+            YesNo::No => &self.orderbook.no,
+            YesNo::Unknown => return Vec::new(),
+        };
+        levels
+            .iter()
+            .map(|(price, qty)| {
+                (
+                    format!("{}.{:02}00", price / 100, price % 100),
+                    format!("{qty}.00"),
+                )
+            })
+            .collect()
+    }
+}
+
 /// --- Trades ---
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Trade {
     pub trade_id: String,
     pub ticker: String,
@@ -866,6 +1224,37 @@ pub struct GetTradesParams {
     pub cursor: Option<String>,
 }
 
+impl GetTradesParams {
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        validate_ts_window("GET /markets/trades", self.min_ts, self.max_ts)
+    }
+}
+
+/// Reject negative timestamps and `min_ts > max_ts`.
+fn validate_ts_window(
+    endpoint: &str,
+    min_ts: Option<i64>,
+    max_ts: Option<i64>,
+) -> Result<(), KalshiError> {
+    for (name, ts) in [("min_ts", min_ts), ("max_ts", max_ts)] {
+        if let Some(ts) = ts
+            && ts < 0
+        {
+            return Err(KalshiError::InvalidParams(format!(
+                "{endpoint}: {name} must be >= 0"
+            )));
+        }
+    }
+    if let (Some(min), Some(max)) = (min_ts, max_ts)
+        && min > max
+    {
+        return Err(KalshiError::InvalidParams(format!(
+            "{endpoint}: min_ts ({min}) must be <= max_ts ({max})"
+        )));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct GetTradesResponse {
     #[serde(default, deserialize_with = "deserialize_null_as_empty_vec")]
@@ -1059,7 +1448,7 @@ impl GetPositionsParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketPosition {
     pub ticker: String,
     #[serde(default)]
@@ -1082,7 +1471,40 @@ pub struct MarketPosition {
     pub subaccount: Option<u32>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl MarketPosition {
+    /// Signed contract count: positive for YES, negative for NO. Prefers
+    /// `position_fp` so fractional positions are kept.
+    pub fn contracts(&self) -> Option<Quantity> {
+        self.position_fp
+            .as_deref()
+            .and_then(Quantity::parse)
+            .or(self.position.map(Quantity::from_contracts))
+    }
+
+    /// Mark-to-market value in dollars given the market's YES price in
+    /// cents: YES contracts at `yes_price`, NO contracts at
+    /// `100 - yes_price`. `yes_price` is clamped to 0–100, so the result is
+    /// never negative; zero when flat or the count is missing.
+    pub fn notional_exposure(&self, yes_price: i64) -> FixedPoint {
+        let Some(count) = self.contracts() else {
+            return FixedPoint::ZERO;
+        };
+        let yes_price = yes_price.clamp(0, 100);
+        let (price, count) = if count.is_positive() {
+            (yes_price, count)
+        } else {
+            (
+                100 - yes_price,
+                Quantity::from_hundredths(-count.hundredths()),
+            )
+        };
+        FixedPoint::from_cents(price)
+            .and_then(|price| price.checked_mul_quantity(count))
+            .unwrap_or(FixedPoint::ZERO)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventPosition {
     pub event_ticker: String,
     #[serde(default)]
@@ -1121,6 +1543,59 @@ pub struct PositionsPage {
     pub event_positions: Vec<EventPosition>,
 }
 
+impl PositionsPage {
+    /// Total [`MarketPosition::notional_exposure`] in cents, rounded to the
+    /// nearest cent, pricing each market from `yes_prices` (ticker to YES
+    /// price in cents). Positions without a price are left out.
+    pub fn notional_exposure(&self, yes_prices: &HashMap<String, i64>) -> i64 {
+        self.market_positions
+            .iter()
+            .filter_map(|position| {
+                let price = yes_prices.get(&position.ticker)?;
+                Some(position.notional_exposure(*price))
+            })
+            .sum::<FixedPoint>()
+            .micro_units()
+            .saturating_add(CENT_UNITS / 2)
+            / CENT_UNITS
+    }
+
+    /// Keep only positions carrying open risk, judged by the status of
+    /// `markets` (e.g. fetched with [`GetMarketsParams::tickers`]).
+    ///
+    /// A market position is dropped once its market
+    /// [`is_settled`](MarketStatus::is_settled); one whose market isn't in
+    /// `markets` is kept. An event position is dropped when `markets` lists
+    /// at least one market for the event and all of them are settled.
+    pub fn filter_unsettled(&self, markets: &[Market]) -> PositionsPage {
+        let settled = |market: &Market| market.status.is_some_and(MarketStatus::is_settled);
+        let mut events: BTreeMap<&str, bool> = BTreeMap::new();
+        for market in markets {
+            if let Some(event) = market.event_ticker.as_deref() {
+                *events.entry(event).or_insert(true) &= settled(market);
+            }
+        }
+        PositionsPage {
+            market_positions: self
+                .market_positions
+                .iter()
+                .filter(|position| {
+                    !markets
+                        .iter()
+                        .any(|market| market.ticker == position.ticker && settled(market))
+                })
+                .cloned()
+                .collect(),
+            event_positions: self
+                .event_positions
+                .iter()
+                .filter(|position| events.get(position.event_ticker.as_str()) != Some(&true))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
 impl From<GetPositionsResponse> for PositionsPage {
     fn from(resp: GetPositionsResponse) -> Self {
         Self {
@@ -1177,6 +1652,7 @@ impl GetOrdersParams {
                 "GET /portfolio/orders: event_ticker supports up to 10 tickers".to_string(),
             ));
         }
+        validate_ts_window("GET /portfolio/orders", self.min_ts, self.max_ts)?;
         if let Some(sub) = self.subaccount
             && sub > 32
         {
@@ -1189,6 +1665,7 @@ impl GetOrdersParams {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Order {
     pub order_id: String,
     pub ticker: String,
@@ -1257,6 +1734,27 @@ pub struct GetOrdersResponse {
 }
 
 /// Create Order body
+///
+/// # `count` and `buy_max_cost`
+///
+/// `count` (or `count_fp`) caps the contracts bought and is required on every
+/// order except a budget-sized market buy (see [`market_buy_budget`](Self::market_buy_budget)).
+/// `buy_max_cost` additionally caps total spend in cents; the exchange fills
+/// whichever limit is hit first, all at once or not at all. [`validate`](Self::validate)
+/// enforces:
+///
+/// | Combination | Allowed |
+/// |-------------|---------|
+/// | `count` only, market buy | yes |
+/// | `buy_max_cost` without `count`, market buy | yes; the budget alone sizes the order |
+/// | neither `count` nor `buy_max_cost` | no |
+/// | `count` + `buy_max_cost`, market buy | yes |
+/// | `count` + `buy_max_cost` + own-side price, limit buy | yes; price bounds each contract |
+/// | `buy_max_cost` on a sell | no |
+/// | `buy_max_cost` with `good_till_canceled` / `immediate_or_cancel` | no; it implies `fill_or_kill` |
+/// | `buy_max_cost` with `expiration_ts` or `post_only` | no; the order never rests |
+/// | `buy_max_cost` with an opposite-side price | no |
+/// | `buy_max_cost` of 0, or below the limit price of one contract | no; it could never fill |
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct CreateOrderRequest {
     /// required
@@ -1297,6 +1795,7 @@ pub struct CreateOrderRequest {
     pub time_in_force: Option<TimeInForce>,
 
     /// Maximum cost in cents; when specified, order auto has FoK behavior.
+    /// Combines with `count`; see the type-level table.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buy_max_cost: Option<u32>,
 
@@ -1326,10 +1825,30 @@ pub struct CreateOrderRequest {
 }
 
 impl CreateOrderRequest {
+    /// Market buy sized by a budget: spend at most `max_cost_cents` on `side`.
+    ///
+    /// Sets `buy_max_cost`, so the exchange treats the order as fill-or-kill:
+    /// it fills up to the budget immediately or is cancelled. `count` is left
+    /// unset so the budget alone sizes the order; set `count` to cap contracts too.
+    pub fn market_buy_budget(ticker: impl Into<String>, side: YesNo, max_cost_cents: u32) -> Self {
+        Self {
+            ticker: ticker.into(),
+            side,
+            action: BuySell::Buy,
+            r#type: Some(OrderType::Market),
+            buy_max_cost: Some(max_cost_cents),
+            ..Default::default()
+        }
+    }
+
     pub fn validate(&self) -> Result<(), KalshiError> {
-        if self.count.is_none() && self.count_fp.is_none() {
+        let budget_sized = matches!(self.r#type, Some(OrderType::Market))
+            && matches!(self.action, BuySell::Buy)
+            && self.buy_max_cost.is_some();
+        if self.count.is_none() && self.count_fp.is_none() && !budget_sized {
             return Err(KalshiError::InvalidParams(
-                "CreateOrderRequest: must provide count or count_fp".to_string(),
+                "CreateOrderRequest: must provide count or count_fp (or buy_max_cost on a market buy)"
+                    .to_string(),
             ));
         }
 
@@ -1381,6 +1900,86 @@ impl CreateOrderRequest {
             ));
         }
 
+        let tif = self.time_in_force;
+        let non_resting = matches!(
+            tif,
+            Some(TimeInForce::FillOrKill | TimeInForce::ImmediateOrCancel)
+        );
+
+        if self.expiration_ts.is_some() && non_resting {
+            return Err(KalshiError::InvalidParams(format!(
+                "CreateOrderRequest: expiration_ts not allowed with time_in_force {}",
+                tif.map(TimeInForce::as_str).unwrap_or_default()
+            )));
+        }
+        if self.post_only == Some(true) && non_resting {
+            return Err(KalshiError::InvalidParams(format!(
+                "CreateOrderRequest: post_only not allowed with time_in_force {}",
+                tif.map(TimeInForce::as_str).unwrap_or_default()
+            )));
+        }
+
+        if self.buy_max_cost.is_some() {
+            if !matches!(self.action, BuySell::Buy) {
+                return Err(KalshiError::InvalidParams(
+                    "CreateOrderRequest: buy_max_cost only allowed on buy orders".to_string(),
+                ));
+            }
+            if matches!(
+                tif,
+                Some(TimeInForce::GoodTillCanceled | TimeInForce::ImmediateOrCancel)
+            ) {
+                return Err(KalshiError::InvalidParams(
+                    "CreateOrderRequest: buy_max_cost implies fill_or_kill time_in_force"
+                        .to_string(),
+                ));
+            }
+            if self.expiration_ts.is_some() {
+                return Err(KalshiError::InvalidParams(
+                    "CreateOrderRequest: buy_max_cost orders cannot set expiration_ts".to_string(),
+                ));
+            }
+            if self.post_only == Some(true) {
+                return Err(KalshiError::InvalidParams(
+                    "CreateOrderRequest: buy_max_cost orders are fill_or_kill and cannot be post_only"
+                        .to_string(),
+                ));
+            }
+            let opposite_price = match self.side {
+                YesNo::Yes => has_no_cents || has_no_dollars,
+                YesNo::No => has_yes_cents || has_yes_dollars,
+                YesNo::Unknown => false,
+            };
+            if opposite_price {
+                return Err(KalshiError::InvalidParams(format!(
+                    "CreateOrderRequest: buy_max_cost on side {} cannot set a price on the opposite side",
+                    self.side.as_str()
+                )));
+            }
+
+            let max_cost = self.buy_max_cost.unwrap_or_default();
+            if max_cost == 0 {
+                return Err(KalshiError::InvalidParams(
+                    "CreateOrderRequest: buy_max_cost must be > 0".to_string(),
+                ));
+            }
+            let (cents, dollars) = match self.side {
+                YesNo::No => (self.no_price, self.no_price_dollars.as_deref()),
+                _ => (self.yes_price, self.yes_price_dollars.as_deref()),
+            };
+            let price_units = cents
+                .map(|c| i64::from(c) * CENT_UNITS)
+                .or_else(|| dollars.and_then(parse_price_units));
+            if let Some(price_units) = price_units
+                && i64::from(max_cost) * CENT_UNITS < price_units
+            {
+                return Err(KalshiError::InvalidParams(format!(
+                    "CreateOrderRequest: buy_max_cost {max_cost}c is below the limit price of one contract (${})",
+                    format_price_units(price_units, 4)
+                )));
+            }
+        }
+
         if let Some(sub) = self.subaccount
             && sub > 32
         {
@@ -1416,11 +2015,14 @@ pub struct CancelOrderParams {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CancelOrderResponse {
     pub order: Order,
+    #[serde(default)]
     pub reduced_by: i64,
+    #[serde(default)]
     pub reduced_by_fp: FixedPointCount,
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Fill {
     pub fill_id: String,
     pub order_id: String,
@@ -1485,6 +2087,7 @@ pub struct GetFillsResponse {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize-responses", derive(Serialize))]
 pub struct Settlement {
     pub settlement_id: String,
     pub ticker: String,
@@ -1518,6 +2121,86 @@ pub struct Settlement {
     pub created_time: Option<String>,
 }
 
+/// Realized P&L for a set of settlements, in micro-dollars (`1_000_000` = $1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SettlementPnl {
+    /// Payout received at settlement.
+    pub revenue: i64,
+    /// YES plus NO cost basis of the settled position.
+    pub cost: i64,
+    pub fees: i64,
+}
+
+impl SettlementPnl {
+    /// `revenue - cost - fees`.
+    pub fn net(&self) -> i64 {
+        self.revenue - self.cost - self.fees
+    }
+
+    /// [`net`](Self::net) as a dollar string with 4 decimal places, e.g. `"-1.2500"`.
+    pub fn net_dollars(&self) -> FixedPointDollars {
+        format_signed_price_units(self.net(), 4)
+    }
+
+    fn add(&mut self, other: SettlementPnl) {
+        self.revenue += other.revenue;
+        self.cost += other.cost;
+        self.fees += other.fees;
+    }
+}
+
+/// Realized P&L across settlements, in total and per market and event.
+///
+/// Built from the fixed-point dollar fields; a missing amount counts as zero.
+/// A settlement with an amount that can't be parsed is left out of every
+/// total and counted in [`skipped`](Self::skipped).
+#[derive(Debug, Clone, Default)]
+pub struct SettlementSummary {
+    pub total: SettlementPnl,
+    /// Keyed by [`Settlement::ticker`].
+    pub by_market: BTreeMap<String, SettlementPnl>,
+    /// Keyed by [`Settlement::event_ticker`], when present.
+    pub by_event: BTreeMap<String, SettlementPnl>,
+    pub skipped: usize,
+}
+
+impl From<&[Settlement]> for SettlementSummary {
+    fn from(settlements: &[Settlement]) -> Self {
+        let mut summary = Self::default();
+        for settlement in settlements {
+            let Some(pnl) = settlement.pnl() else {
+                summary.skipped += 1;
+                continue;
+            };
+            summary.total.add(pnl);
+            summary
+                .by_market
+                .entry(settlement.ticker.clone())
+                .or_default()
+                .add(pnl);
+            if let Some(event) = &settlement.event_ticker {
+                summary.by_event.entry(event.clone()).or_default().add(pnl);
+            }
+        }
+        summary
+    }
+}
+
+impl Settlement {
+    /// Realized P&L of this settlement, or `None` if an amount is unparsable.
+    pub fn pnl(&self) -> Option<SettlementPnl> {
+        let units = |value: &Option<FixedPointDollars>| match value {
+            Some(value) => parse_signed_price_units(value),
+            None => Some(0),
+        };
+        Some(SettlementPnl {
+            revenue: units(&self.revenue)?,
+            cost: units(&self.yes_total_cost)? + units(&self.no_total_cost)?,
+            fees: units(&self.fee_cost)?,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct GetSettlementsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1794,8 +2477,10 @@ pub struct GetQuoteResponse {
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateQuoteRequest {
     pub rfq_id: String,
-    pub yes_bid: String,
-    pub no_bid: String,
+    /// Bid for YES contracts, in dollars.
+    pub yes_bid: FixedPointDollars,
+    /// Bid for NO contracts, in dollars.
+    pub no_bid: FixedPointDollars,
     pub rest_remainder: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subaccount: Option<u32>,
@@ -2193,6 +2878,18 @@ pub struct TickerPair {
     pub extra: Map<String, Value>,
 }
 
+impl From<WsMultivariateSelectedMarket> for TickerPair {
+    /// Reuse a leg from a WebSocket multivariate lookup in a REST request.
+    fn from(leg: WsMultivariateSelectedMarket) -> Self {
+        Self {
+            market_ticker: leg.market_ticker,
+            event_ticker: leg.event_ticker,
+            side: leg.side,
+            extra: Map::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateMarketInMultivariateEventCollectionRequest {
     pub selected_markets: Vec<TickerPair>,
@@ -2279,6 +2976,53 @@ pub struct CreateOrderGroupRequest {
     pub contracts_limit_fp: Option<FixedPointCount>,
 }
 
+impl CreateOrderGroupRequest {
+    /// Checks the limit (see [`UpdateOrderGroupLimitRequest::validate`]) and
+    /// that `subaccount` is `0..=32`.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        validate_contracts_limit(
+            "CreateOrderGroupRequest",
+            self.contracts_limit,
+            self.contracts_limit_fp.as_deref(),
+        )?;
+        if let Some(sub) = self.subaccount
+            && sub > 32
+        {
+            return Err(KalshiError::InvalidParams(
+                "CreateOrderGroupRequest: subaccount must be 0..=32".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Order group limits are whole contracts, at least 1, given as
+/// `contracts_limit`, `contracts_limit_fp`, or both if they agree.
+fn validate_contracts_limit(
+    name: &str,
+    limit: Option<i64>,
+    limit_fp: Option<&str>,
+) -> Result<(), KalshiError> {
+    let invalid = |reason: &str| Err(KalshiError::InvalidParams(format!("{name}: {reason}")));
+    let fp = match limit_fp {
+        Some(value) => match parse_decimal_scaled(value, 2) {
+            Some((hundredths, false)) if hundredths % 100 == 0 => Some(hundredths / 100),
+            _ => return invalid("contracts_limit_fp must be a whole number of contracts"),
+        },
+        None => None,
+    };
+    match (limit, fp) {
+        (None, None) => invalid("must provide contracts_limit or contracts_limit_fp"),
+        (Some(limit), Some(fp)) if limit != fp => {
+            invalid("contracts_limit and contracts_limit_fp must match")
+        }
+        (Some(limit), _) | (None, Some(limit)) if limit < 1 => {
+            invalid("contracts_limit must be >= 1")
+        }
+        _ => Ok(()),
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateOrderGroupResponse {
     pub order_group_id: String,
@@ -2303,11 +3047,62 @@ pub struct UpdateOrderGroupLimitRequest {
     pub contracts_limit_fp: Option<FixedPointCount>,
 }
 
+impl UpdateOrderGroupLimitRequest {
+    /// Set the limit from a fixed-point count such as `"25.00"`.
+    pub fn fp(contracts_limit_fp: impl Into<FixedPointCount>) -> Self {
+        Self {
+            contracts_limit: None,
+            contracts_limit_fp: Some(contracts_limit_fp.into()),
+        }
+    }
+
+    /// Requires `contracts_limit` or `contracts_limit_fp` (matching if both
+    /// are set), as a whole number of contracts, at least 1.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        validate_contracts_limit(
+            "UpdateOrderGroupLimitRequest",
+            self.contracts_limit,
+            self.contracts_limit_fp.as_deref(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct BatchCreateOrdersRequest {
     pub orders: Vec<CreateOrderRequest>,
 }
 
+impl BatchCreateOrdersRequest {
+    /// Most orders Kalshi accepts in one batch.
+    pub const MAX_ORDERS: usize = 20;
+
+    /// Checks the batch size and runs [`CreateOrderRequest::validate`] on
+    /// every order.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        if self.orders.is_empty() {
+            return Err(KalshiError::InvalidParams(
+                "BatchCreateOrdersRequest: orders must not be empty".to_string(),
+            ));
+        }
+        if self.orders.len() > Self::MAX_ORDERS {
+            return Err(KalshiError::InvalidParams(format!(
+                "BatchCreateOrdersRequest: at most {} orders per batch (got {})",
+                Self::MAX_ORDERS,
+                self.orders.len()
+            )));
+        }
+        for (i, order) in self.orders.iter().enumerate() {
+            order.validate().map_err(|e| match e {
+                KalshiError::InvalidParams(msg) => {
+                    KalshiError::InvalidParams(format!("orders[{i}]: {msg}"))
+                }
+                other => other,
+            })?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BatchCreateOrdersResponse {
     #[serde(default, deserialize_with = "deserialize_null_as_empty_vec")]
@@ -2341,6 +3136,48 @@ pub struct BatchCancelOrdersRequest {
     pub orders: Option<Vec<BatchCancelOrdersRequestOrder>>,
 }
 
+impl BatchCancelOrdersRequest {
+    /// Most orders Kalshi cancels in one batch.
+    pub const MAX_ORDERS: usize = 20;
+
+    /// Cancel `ids` on the default subaccount.
+    pub fn from_ids(ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            ids: Some(ids.into_iter().map(Into::into).collect()),
+            orders: None,
+        }
+    }
+
+    /// Checks that `ids` and `orders` together hold 1..=[`MAX_ORDERS`](Self::MAX_ORDERS)
+    /// entries and that every subaccount is `0..=32`.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        let count =
+            self.ids.as_ref().map_or(0, Vec::len) + self.orders.as_ref().map_or(0, Vec::len);
+        if count == 0 {
+            return Err(KalshiError::InvalidParams(
+                "BatchCancelOrdersRequest: must provide at least one order".to_string(),
+            ));
+        }
+        if count > Self::MAX_ORDERS {
+            return Err(KalshiError::InvalidParams(format!(
+                "BatchCancelOrdersRequest: at most {} orders per batch (got {count})",
+                Self::MAX_ORDERS
+            )));
+        }
+        if self
+            .orders
+            .iter()
+            .flatten()
+            .any(|order| order.subaccount.is_some_and(|sub| sub > 32))
+        {
+            return Err(KalshiError::InvalidParams(
+                "BatchCancelOrdersRequest: subaccount must be 0..=32".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BatchCancelOrdersResponse {
     #[serde(default, deserialize_with = "deserialize_null_as_empty_vec")]
@@ -2365,6 +3202,50 @@ pub struct GetOrderResponse {
     pub order: Order,
 }
 
+/// A fill-progress or status change observed by
+/// [`KalshiRestClient::watch_order`](crate::KalshiRestClient::watch_order).
+#[derive(Debug, Clone)]
+pub struct OrderUpdate {
+    pub status: Option<OrderStatus>,
+    pub filled_count: Option<i64>,
+    pub remaining_count: Option<i64>,
+    /// The full order as returned by the poll that produced this update.
+    pub order: Order,
+}
+
+impl OrderUpdate {
+    pub fn from_order(order: Order) -> Self {
+        Self {
+            status: order.status,
+            filled_count: order.filled_count,
+            remaining_count: order.remaining_count,
+            order,
+        }
+    }
+
+    /// Whether the order can no longer change (executed or canceled).
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status,
+            Some(OrderStatus::Executed | OrderStatus::Canceled)
+        )
+    }
+
+    fn progress_key(&self) -> (Option<OrderStatus>, Option<i64>, Option<i64>, Option<&str>) {
+        (
+            self.status,
+            self.filled_count,
+            self.remaining_count,
+            self.order.filled_count_fp.as_deref(),
+        )
+    }
+
+    /// Whether `other` reports the same status and fill progress.
+    pub fn same_progress(&self, other: &OrderUpdate) -> bool {
+        self.progress_key() == other.progress_key()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct AmendOrderRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2390,12 +3271,67 @@ pub struct AmendOrderRequest {
     pub count_fp: Option<FixedPointCount>,
 }
 
+impl AmendOrderRequest {
+    /// Same count, price, and subaccount rules as [`CreateOrderRequest::validate`].
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        if self.count.is_none() && self.count_fp.is_none() {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: must provide count or count_fp".to_string(),
+            ));
+        }
+
+        if let (Some(count), Some(count_fp)) = (self.count, self.count_fp.as_deref())
+            && let Ok(fp_val) = count_fp.parse::<f64>()
+            && (fp_val - count as f64).abs() > 1e-9
+        {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: count and count_fp must match".to_string(),
+            ));
+        }
+
+        let has_yes = self.yes_price.is_some() || self.yes_price_dollars.is_some();
+        let has_no = self.no_price.is_some() || self.no_price_dollars.is_some();
+        if self.yes_price.is_some() && self.yes_price_dollars.is_some() {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: cannot set both yes_price and yes_price_dollars".to_string(),
+            ));
+        }
+        if self.no_price.is_some() && self.no_price_dollars.is_some() {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: cannot set both no_price and no_price_dollars".to_string(),
+            ));
+        }
+        if has_yes && has_no {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: cannot set both yes and no prices".to_string(),
+            ));
+        }
+
+        if let Some(sub) = self.subaccount
+            && sub > 32
+        {
+            return Err(KalshiError::InvalidParams(
+                "AmendOrderRequest: subaccount must be 0..=32".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct AmendOrderResponse {
     pub old_order: Order,
     pub order: Order,
 }
 
+impl AmendOrderResponse {
+    /// ID of the amended order; Kalshi may assign a new one.
+    pub fn order_id(&self) -> &str {
+        &self.order.order_id
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct DecreaseOrderRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2410,11 +3346,55 @@ pub struct DecreaseOrderRequest {
     pub reduce_to_fp: Option<FixedPointCount>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct DecreaseOrderResponse {
-    pub order: Order,
+impl DecreaseOrderRequest {
+    /// Requires exactly one of `reduce_by`/`reduce_by_fp` or
+    /// `reduce_to`/`reduce_to_fp`; when both halves of a pair are set they
+    /// must match.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        let has_by = self.reduce_by.is_some() || self.reduce_by_fp.is_some();
+        let has_to = self.reduce_to.is_some() || self.reduce_to_fp.is_some();
+        if has_by && has_to {
+            return Err(KalshiError::InvalidParams(
+                "DecreaseOrderRequest: cannot set both reduce_by and reduce_to".to_string(),
+            ));
+        }
+        if !has_by && !has_to {
+            return Err(KalshiError::InvalidParams(
+                "DecreaseOrderRequest: must provide reduce_by(_fp) or reduce_to(_fp)".to_string(),
+            ));
+        }
+
+        let pairs = [
+            ("reduce_by", self.reduce_by, self.reduce_by_fp.as_deref()),
+            ("reduce_to", self.reduce_to, self.reduce_to_fp.as_deref()),
+        ];
+        for (name, count, count_fp) in pairs {
+            if let (Some(count), Some(count_fp)) = (count, count_fp)
+                && let Ok(fp_val) = count_fp.parse::<f64>()
+                && (fp_val - count as f64).abs() > 1e-9
+            {
+                return Err(KalshiError::InvalidParams(format!(
+                    "DecreaseOrderRequest: {name} and {name}_fp must match"
+                )));
+            }
+        }
+
+        if let Some(sub) = self.subaccount
+            && sub > 32
+        {
+            return Err(KalshiError::InvalidParams(
+                "DecreaseOrderRequest: subaccount must be 0..=32".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
+/// Kalshi answers a decrease like a cancel: the reduced order and how much
+/// was taken off.
+pub type DecreaseOrderResponse = CancelOrderResponse;
+
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct GetOrderQueuePositionsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2472,11 +3452,44 @@ pub struct GetFiltersBySportsResponse {
 pub struct GetMarketCandlesticksParams {
     pub start_ts: i64,
     pub end_ts: i64,
+    /// Candle length in minutes: 1, 60, or 1440.
     pub period_interval: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_latest_before_start: Option<bool>,
 }
 
+impl GetMarketCandlesticksParams {
+    /// Most candles Kalshi returns for one request.
+    pub const MAX_CANDLESTICKS: i64 = 5000;
+
+    /// Checks the period and that `start_ts..=end_ts` spans at most
+    /// [`MAX_CANDLESTICKS`](Self::MAX_CANDLESTICKS) periods; split longer
+    /// ranges into several requests.
+    pub fn validate(&self) -> Result<(), KalshiError> {
+        const ENDPOINT: &str = "GET /series/{series_ticker}/markets/{ticker}/candlesticks";
+        if !matches!(self.period_interval, 1 | 60 | 1440) {
+            return Err(KalshiError::InvalidParams(format!(
+                "{ENDPOINT}: period_interval must be 1, 60, or 1440 (got {})",
+                self.period_interval
+            )));
+        }
+        if self.start_ts < 0 || self.start_ts > self.end_ts {
+            return Err(KalshiError::InvalidParams(format!(
+                "{ENDPOINT}: need 0 <= start_ts ({}) <= end_ts ({})",
+                self.start_ts, self.end_ts
+            )));
+        }
+        let periods = (self.end_ts - self.start_ts) / (i64::from(self.period_interval) * 60);
+        if periods > Self::MAX_CANDLESTICKS {
+            return Err(KalshiError::InvalidParams(format!(
+                "{ENDPOINT}: range covers {periods} periods, more than {}",
+                Self::MAX_CANDLESTICKS
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct GetMarketCandlesticksHistoricalParams {
     pub start_ts: i64,
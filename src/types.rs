@@ -74,6 +74,66 @@ where
     deserializer.deserialize_any(StringOrNumber)
 }
 
+/// Deserialize an integer sent as either a JSON number or a numeric string.
+///
+/// Integral floats (`123.0`, `"123.00"`) are accepted; fractions and values
+/// outside `i64` are rejected.
+pub fn deserialize_i64_or_string<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct I64OrString;
+
+    impl<'de> serde::de::Visitor<'de> for I64OrString {
+        type Value = i64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an integer or numeric string")
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(v)
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            i64::try_from(v).map_err(|_| E::custom(format!("integer {v} out of range for i64")))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            if v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 {
+                Ok(v as i64)
+            } else {
+                Err(E::custom(format!("expected an integer, got {v}")))
+            }
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            let trimmed = v.trim();
+            if let Ok(n) = trimmed.parse::<i64>() {
+                return Ok(n);
+            }
+            match trimmed.parse::<f64>() {
+                Ok(f) if f.is_finite() => self.visit_f64(f),
+                _ => Err(E::custom(format!("expected an integer string, got {v:?}"))),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(I64OrString)
+}
+
 /// Deserialize a null or array into a `Vec<T>` (null maps to empty vec).
 pub fn deserialize_null_as_empty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
 where
@@ -84,12 +144,429 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+/// Parse an RFC 3339 timestamp (e.g. `"2024-01-02T03:04:05.123Z"`) into unix seconds.
+///
+/// Fractional seconds are truncated. Returns `None` for malformed input.
+pub(crate) fn parse_rfc3339_unix_seconds(value: &str) -> Option<i64> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' {
+        return None;
+    }
+    if !matches!(bytes[10], b'T' | b't' | b' ') || bytes[16] != b':' {
+        return None;
+    }
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let s = value.get(range)?;
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if second > 60 {
+        return None;
+    }
+
+    let mut rest = &value[19..];
+    if let Some(frac) = rest.strip_prefix('.') {
+        let digits = frac.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        rest = &frac[digits..];
+    }
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && (rest.starts_with('+') || rest.starts_with('-')) => {
+            if rest.as_bytes()[3] != b':' {
+                return None;
+            }
+            let off_h: i64 = rest.get(1..3)?.parse().ok()?;
+            let off_m: i64 = rest.get(4..6)?.parse().ok()?;
+            let sign = if rest.starts_with('-') { -1 } else { 1 };
+            sign * (off_h * 3600 + off_m * 60)
+        }
+        _ => return None,
+    };
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
 /// Fixed-point dollar string (e.g. "0.5600").
 pub type FixedPointDollars = String;
 
 /// Fixed-point contract count string (e.g. "10.00").
 pub type FixedPointCount = String;
 
+/// Parse a plain decimal string such as `"-12.345"` into an integer scaled
+/// by `10^digits`.
+///
+/// Returns the value truncated toward zero, and whether nonzero digits past
+/// `digits` were dropped. `None` unless the input (after trimming) is an
+/// optional `-`, ASCII digits, and at most one `.`, so exponents, `inf`, and
+/// `+` are rejected, as is any value that overflows `i64`. Every fixed-point
+/// type in the crate parses through this.
+pub(crate) fn parse_decimal_scaled(value: &str, digits: u32) -> Option<(i64, bool)> {
+    let value = value.trim();
+    let (negative, abs) = match value.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, value),
+    };
+    let (whole, frac) = abs.split_once('.').unwrap_or((abs, ""));
+    if (whole.is_empty() && frac.is_empty())
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !frac.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut units: i64 = 0;
+    for b in whole.bytes() {
+        units = units.checked_mul(10)?.checked_add(i64::from(b - b'0'))?;
+    }
+    let mut frac = frac.bytes();
+    for _ in 0..digits {
+        let digit = frac.next().map_or(0, |b| i64::from(b - b'0'));
+        units = units.checked_mul(10)?.checked_add(digit)?;
+    }
+    let truncated = frac.any(|b| b != b'0');
+    Some((if negative { -units } else { units }, truncated))
+}
+
+/// Decimal places a [`FixedPoint`] holds exactly (micro-units).
+const FIXED_POINT_DIGITS: u8 = 6;
+const FIXED_POINT_SCALE: i64 = 10_i64.pow(FIXED_POINT_DIGITS as u32);
+
+/// Exact value of a [`FixedPointDollars`] or [`FixedPointCount`] string.
+///
+/// Stored as a scaled `i64` with six decimal places, so arithmetic never
+/// rounds. Parse a field with `order.yes_price_dollars.parse::<FixedPoint>()`;
+/// `Display` keeps the precision it was parsed with, so `"0.5600"` prints as
+/// `"0.5600"`. Equality and ordering compare the value, not the formatting.
+///
+/// ```
+/// use kalshi_fast::FixedPoint;
+///
+/// let price: FixedPoint = "0.5600".parse()?;
+/// let cost = price * 3;
+/// assert_eq!(cost.to_string(), "1.6800");
+/// assert_eq!(cost.as_cents(), 168);
+/// # Ok::<(), kalshi_fast::ParseFixedPointError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedPoint {
+    units: i64,
+    /// Decimal places shown by `Display`.
+    digits: u8,
+}
+
+/// Error from parsing a string that is not a decimal with at most six
+/// significant fractional digits into [`FixedPoint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFixedPointError(pub String);
+
+impl fmt::Display for ParseFixedPointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid fixed-point value `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseFixedPointError {}
+
+impl FixedPoint {
+    pub const ZERO: FixedPoint = FixedPoint {
+        units: 0,
+        digits: 0,
+    };
+
+    /// A whole number of cents, shown with two decimal places, or `None` if
+    /// it does not fit in micro-units.
+    pub fn from_cents(cents: i64) -> Option<Self> {
+        Some(Self {
+            units: cents.checked_mul(FIXED_POINT_SCALE / 100)?,
+            digits: 2,
+        })
+    }
+
+    /// A value in millionths, shown with `digits` decimal places.
+    pub(crate) fn from_micro_units(units: i64, digits: u8) -> Self {
+        Self {
+            units,
+            digits: digits.min(FIXED_POINT_DIGITS),
+        }
+    }
+
+    /// The value in millionths, e.g. `560_000` for `"0.56"`.
+    pub fn micro_units(self) -> i64 {
+        self.units
+    }
+
+    /// Whole cents, truncating any sub-cent remainder toward zero.
+    pub fn as_cents(self) -> i64 {
+        self.units / (FIXED_POINT_SCALE / 100)
+    }
+
+    /// Nearest `f64`, for display or statistics; not for further arithmetic.
+    pub fn as_dollars_f64(self) -> f64 {
+        self.units as f64 / FIXED_POINT_SCALE as f64
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.units == 0
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_add(rhs.units)?,
+            digits: self.digits.max(rhs.digits),
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_sub(rhs.units)?,
+            digits: self.digits.max(rhs.digits),
+        })
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_neg()?,
+            digits: self.digits,
+        })
+    }
+
+    /// Multiply by a whole number, e.g. a price by a contract count.
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_mul(rhs)?,
+            digits: self.digits,
+        })
+    }
+
+    /// Multiply by a fractional contract count, e.g. a price by a
+    /// `position_fp`. Exact for prices in whole hundredths of a cent.
+    pub fn checked_mul_quantity(self, rhs: Quantity) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_mul(rhs.hundredths())? / 100,
+            digits: self.digits.max(4),
+        })
+    }
+}
+
+impl std::str::FromStr for FixedPoint {
+    type Err = ParseFixedPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((units, false)) = parse_decimal_scaled(s, u32::from(FIXED_POINT_DIGITS)) else {
+            return Err(ParseFixedPointError(s.to_string()));
+        };
+        let frac_len = s.trim().split_once('.').map_or(0, |(_, frac)| frac.len());
+        Ok(Self {
+            units,
+            digits: frac_len.min(usize::from(FIXED_POINT_DIGITS)) as u8,
+        })
+    }
+}
+
+impl fmt::Display for FixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let abs = self.units.unsigned_abs();
+        let scale = FIXED_POINT_SCALE as u64;
+        write!(f, "{sign}{}", abs / scale)?;
+        if self.digits > 0 {
+            let width = usize::from(self.digits);
+            let shown = abs % scale / 10_u64.pow(u32::from(FIXED_POINT_DIGITS - self.digits));
+            write!(f, ".{shown:0width$}")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for FixedPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.units == other.units
+    }
+}
+
+impl Eq for FixedPoint {}
+
+impl std::hash::Hash for FixedPoint {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.units.hash(state);
+    }
+}
+
+impl PartialOrd for FixedPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FixedPoint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.units.cmp(&other.units)
+    }
+}
+
+impl std::ops::Add for FixedPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .expect("FixedPoint addition overflowed")
+    }
+}
+
+impl std::ops::Sub for FixedPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("FixedPoint subtraction overflowed")
+    }
+}
+
+impl std::ops::Mul<i64> for FixedPoint {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        self.checked_mul(rhs)
+            .expect("FixedPoint multiplication overflowed")
+    }
+}
+
+impl std::ops::Neg for FixedPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("FixedPoint negation overflowed")
+    }
+}
+
+impl std::iter::Sum for FixedPoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl Serialize for FixedPoint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Accepts the wire string, or a JSON number as [`deserialize_string_or_number`] does.
+impl<'de> Deserialize<'de> for FixedPoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_string_or_number(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Fixed-point contract quantity with two decimal places, matching the
+/// `*_fp` fields Kalshi sends for fractional markets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quantity(i64);
+
+impl Quantity {
+    pub const ZERO: Quantity = Quantity(0);
+
+    /// Whole contracts, as carried by the integer `delta`/level fields.
+    pub const fn from_contracts(contracts: i64) -> Self {
+        Self(contracts * 100)
+    }
+
+    /// Hundredths of a contract.
+    pub const fn from_hundredths(hundredths: i64) -> Self {
+        Self(hundredths)
+    }
+
+    /// Parse a fixed-point string such as `"12.50"` or `"-3"`.
+    ///
+    /// Digits past the second decimal place are truncated.
+    pub fn parse(value: &str) -> Option<Self> {
+        parse_decimal_scaled(value, 2).map(|(hundredths, _)| Self(hundredths))
+    }
+
+    pub const fn hundredths(self) -> i64 {
+        self.0
+    }
+
+    /// Whole contracts, truncating any fractional part.
+    pub const fn contracts(self) -> i64 {
+        self.0 / 100
+    }
+
+    pub fn as_f64(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+}
+
+impl std::ops::Add for Quantity {
+    type Output = Quantity;
+
+    fn add(self, rhs: Quantity) -> Quantity {
+        Quantity(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for Quantity {
+    fn add_assign(&mut self, rhs: Quantity) {
+        self.0 += rhs.0;
+    }
+}
+
+impl std::ops::Sub for Quantity {
+    type Output = Quantity;
+
+    fn sub(self, rhs: Quantity) -> Quantity {
+        Quantity(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::SubAssign for Quantity {
+    fn sub_assign(&mut self, rhs: Quantity) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        write!(f, "{sign}{}.{:02}", abs / 100, abs % 100)
+    }
+}
+
+/// Convert a fixed-point dollar string (e.g. `"0.4500"`) to whole cents.
+///
+/// `None` for sub-cent prices such as `"0.4550"`.
+pub(crate) fn dollars_to_cents(value: &str) -> Option<i64> {
+    match parse_decimal_scaled(value, 2)? {
+        (cents, false) => Some(cents),
+        (_, true) => None,
+    }
+}
+
 /// Typed wrapper for arbitrary JSON payloads.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct AnyJson(pub Value);
@@ -130,6 +607,105 @@ pub struct ErrorResponse {
     pub service: Option<String>,
 }
 
+impl ErrorResponse {
+    /// The typed [`code`](Self::code), if the server sent one.
+    pub fn error_code(&self) -> Option<KalshiErrorCode> {
+        self.code.as_deref().map(KalshiErrorCode::from)
+    }
+}
+
+/// Error codes returned in Kalshi REST error bodies.
+///
+/// The OpenAPI spec types `ErrorResponse.code` as a free-form string and
+/// lists no values, so these variants are the codes seen in live API error
+/// bodies, not a published contract. Anything else maps to
+/// [`Unknown`](Self::Unknown); match with a fallback arm.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KalshiErrorCode {
+    /// `bad_request`: the request was malformed.
+    BadRequest,
+    /// `missing_parameters`: a required parameter is missing.
+    MissingParameters,
+    /// `invalid_parameters`: a parameter is invalid.
+    InvalidParameters,
+    /// `unauthorized`: credentials are missing or invalid.
+    Unauthorized,
+    /// `forbidden`: the key may not perform this action.
+    Forbidden,
+    /// `not_found`: the resource does not exist.
+    NotFound,
+    /// `market_not_found`: no such market.
+    MarketNotFound,
+    /// `order_not_found`: no such order.
+    OrderNotFound,
+    /// `insufficient_balance`: not enough balance for the order.
+    InsufficientBalance,
+    /// `market_closed`: the market is not open for trading.
+    MarketClosed,
+    /// `trading_is_paused`: trading is halted exchange-wide.
+    TradingIsPaused,
+    /// `too_many_requests`: rate limit exceeded.
+    TooManyRequests,
+    /// `internal_server_error`: server-side failure.
+    InternalServerError,
+    /// `service_unavailable`: the service is temporarily unavailable.
+    ServiceUnavailable,
+    /// A code this crate does not recognise, kept as sent.
+    Unknown(String),
+}
+
+impl KalshiErrorCode {
+    /// The code string sent on the wire.
+    pub fn as_str(&self) -> &str {
+        match self {
+            KalshiErrorCode::BadRequest => "bad_request",
+            KalshiErrorCode::MissingParameters => "missing_parameters",
+            KalshiErrorCode::InvalidParameters => "invalid_parameters",
+            KalshiErrorCode::Unauthorized => "unauthorized",
+            KalshiErrorCode::Forbidden => "forbidden",
+            KalshiErrorCode::NotFound => "not_found",
+            KalshiErrorCode::MarketNotFound => "market_not_found",
+            KalshiErrorCode::OrderNotFound => "order_not_found",
+            KalshiErrorCode::InsufficientBalance => "insufficient_balance",
+            KalshiErrorCode::MarketClosed => "market_closed",
+            KalshiErrorCode::TradingIsPaused => "trading_is_paused",
+            KalshiErrorCode::TooManyRequests => "too_many_requests",
+            KalshiErrorCode::InternalServerError => "internal_server_error",
+            KalshiErrorCode::ServiceUnavailable => "service_unavailable",
+            KalshiErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<&str> for KalshiErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "bad_request" => KalshiErrorCode::BadRequest,
+            "missing_parameters" => KalshiErrorCode::MissingParameters,
+            "invalid_parameters" => KalshiErrorCode::InvalidParameters,
+            "unauthorized" => KalshiErrorCode::Unauthorized,
+            "forbidden" => KalshiErrorCode::Forbidden,
+            "not_found" => KalshiErrorCode::NotFound,
+            "market_not_found" => KalshiErrorCode::MarketNotFound,
+            "order_not_found" => KalshiErrorCode::OrderNotFound,
+            "insufficient_balance" => KalshiErrorCode::InsufficientBalance,
+            "market_closed" => KalshiErrorCode::MarketClosed,
+            "trading_is_paused" => KalshiErrorCode::TradingIsPaused,
+            "too_many_requests" => KalshiErrorCode::TooManyRequests,
+            "internal_server_error" => KalshiErrorCode::InternalServerError,
+            "service_unavailable" => KalshiErrorCode::ServiceUnavailable,
+            other => KalshiErrorCode::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for KalshiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// --- Fee Type ---
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -510,6 +1086,92 @@ impl Serialize for TradeTakerSide {
 mod tests {
     use super::*;
 
+    #[test]
+    fn dollars_to_cents_rejects_sub_cent_prices() {
+        assert_eq!(dollars_to_cents("0.4500"), Some(45));
+        assert_eq!(dollars_to_cents("1"), Some(100));
+        assert_eq!(dollars_to_cents("0.4550"), None);
+        assert_eq!(dollars_to_cents("0.45e2"), None);
+        assert_eq!(
+            Quantity::parse("1.239"),
+            Some(Quantity::from_hundredths(123))
+        );
+    }
+
+    #[test]
+    fn fixed_point_roundtrips_and_computes_exactly() {
+        let price: FixedPoint = "0.5600".parse().unwrap();
+        assert_eq!(price.to_string(), "0.5600");
+        assert_eq!(price.micro_units(), 560_000);
+        assert_eq!(price.as_cents(), 56);
+        assert_eq!(Some(price), FixedPoint::from_cents(56));
+        assert_eq!(FixedPoint::from_cents(56).unwrap().to_string(), "0.56");
+        assert!(FixedPoint::from_cents(i64::MAX).is_none());
+
+        let fee: FixedPoint = "0.0175".parse().unwrap();
+        assert_eq!((price * 3 - fee).to_string(), "1.6625");
+        assert_eq!((-fee).to_string(), "-0.0175");
+        assert_eq!("-0.0175".parse::<FixedPoint>().unwrap(), -fee);
+        assert_eq!((fee * 100).as_cents(), 175);
+        assert_eq!("10".parse::<FixedPoint>().unwrap().to_string(), "10");
+        assert_eq!(".5".parse::<FixedPoint>().unwrap().to_string(), "0.5");
+        assert_eq!(
+            "1.50000000".parse::<FixedPoint>().unwrap().to_string(),
+            "1.500000"
+        );
+        assert!(fee < price);
+        assert_eq!(
+            [price, fee]
+                .into_iter()
+                .sum::<FixedPoint>()
+                .as_dollars_f64(),
+            0.5775
+        );
+
+        for bad in [
+            "",
+            "-",
+            ".",
+            "abc",
+            "1.2.3",
+            "0.0000001",
+            "1e5",
+            "+1",
+            "inf",
+            "9223372036854.9",
+            "99999999999999999999",
+        ] {
+            assert!(bad.parse::<FixedPoint>().is_err(), "{bad:?}");
+        }
+        let cent = FixedPoint::from_cents(1).unwrap();
+        assert!(cent.checked_mul(i64::MAX).is_none());
+        assert_eq!(cent.checked_neg(), FixedPoint::from_cents(-1));
+        assert!(
+            FixedPoint::from_micro_units(i64::MIN, 6)
+                .checked_neg()
+                .is_none()
+        );
+
+        let parsed: Vec<FixedPoint> = serde_json::from_str(r#"["0.5600", 3]"#).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), r#"["0.5600","3"]"#);
+        assert!(serde_json::from_str::<FixedPoint>(r#""nope""#).is_err());
+    }
+
+    #[test]
+    fn rfc3339_parses_to_unix_seconds() {
+        assert_eq!(parse_rfc3339_unix_seconds("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339_unix_seconds("2024-02-29T12:30:15.123456Z"),
+            Some(1_709_209_815)
+        );
+        assert_eq!(
+            parse_rfc3339_unix_seconds("2024-02-29T14:30:15+02:00"),
+            Some(1_709_209_815)
+        );
+        assert_eq!(parse_rfc3339_unix_seconds("2024-02-29"), None);
+        assert_eq!(parse_rfc3339_unix_seconds("2024-13-01T00:00:00Z"), None);
+    }
+
     #[test]
     fn market_status_query_deserialize_known() {
         let status: MarketStatusQuery = serde_json::from_str("\"open\"").unwrap();
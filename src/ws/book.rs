@@ -0,0 +1,638 @@
+use crate::rest::types::{GetMarketOrderbookResponse, Orderbook, OrderbookFp};
+use crate::types::{Quantity, YesNo, dollars_to_cents};
+use crate::ws::types::{WsDataMessage, WsMessage, WsOrderbookDelta, WsOrderbookSnapshot};
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+/// What [`OrderBook::apply_delta`] did to the book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaOutcome {
+    /// The level was adjusted, or removed on reaching exactly zero.
+    Applied,
+    /// The delta would have left the level negative by `excess`; the level
+    /// was removed instead. Usually a missed delta; resubscribe for a fresh
+    /// snapshot if it matters.
+    Clamped { excess: Quantity },
+    /// The delta named an unknown side and was ignored.
+    UnknownSide,
+}
+
+/// Locally reconstructed order book for a single market.
+///
+/// Levels are keyed by price in cents and hold resting [`Quantity`]. Kalshi
+/// only publishes bids: a YES ask at `p` is a NO bid at `100 - p`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderBook {
+    pub market_ticker: String,
+    pub market_id: String,
+    /// YES bids: price_cents -> quantity
+    pub yes: BTreeMap<i64, Quantity>,
+    /// NO bids: price_cents -> quantity
+    pub no: BTreeMap<i64, Quantity>,
+    /// Sequence number of the last applied snapshot or delta.
+    pub seq: Option<u64>,
+}
+
+/// Best bid on each side of an [`OrderBook`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TopOfBook {
+    /// Best YES bid: (price_cents, quantity)
+    pub yes_bid: Option<(i64, Quantity)>,
+    /// Best NO bid: (price_cents, quantity)
+    pub no_bid: Option<(i64, Quantity)>,
+}
+
+impl TopOfBook {
+    /// Best YES ask implied by the best NO bid: (price_cents, quantity).
+    pub fn yes_ask(&self) -> Option<(i64, Quantity)> {
+        self.no_bid.map(|(price, qty)| (100 - price, qty))
+    }
+
+    /// Best NO ask implied by the best YES bid: (price_cents, quantity).
+    pub fn no_ask(&self) -> Option<(i64, Quantity)> {
+        self.yes_bid.map(|(price, qty)| (100 - price, qty))
+    }
+
+    /// Best YES bid and best YES ask in cents, or `None` unless both sides
+    /// have a bid.
+    pub fn bid_ask(&self) -> Option<(i64, i64)> {
+        Some((self.yes_bid?.0, self.yes_ask()?.0))
+    }
+}
+
+impl OrderBook {
+    /// Build a book from an `orderbook_snapshot` message.
+    ///
+    /// Prefers the fully fixed-point `*_dollars_fp` levels, then the integer
+    /// cent levels, then the `*_dollars` levels.
+    pub fn from_snapshot(snapshot: &WsOrderbookSnapshot, seq: Option<u64>) -> Self {
+        Self {
+            market_ticker: snapshot.market_ticker.clone(),
+            market_id: snapshot.market_id.clone(),
+            yes: collect_levels(
+                &snapshot.yes_dollars_fp,
+                &snapshot.yes,
+                &snapshot.yes_dollars,
+            ),
+            no: collect_levels(&snapshot.no_dollars_fp, &snapshot.no, &snapshot.no_dollars),
+            seq,
+        }
+    }
+
+    /// Build a book from a REST `GET /markets/{ticker}/orderbook` response.
+    ///
+    /// The REST payload carries no market id or sequence number; seed a
+    /// [`BookManager`] with it and let WS deltas take over.
+    pub fn from_rest(market_ticker: impl Into<String>, orderbook: &Orderbook) -> Self {
+        Self {
+            market_ticker: market_ticker.into(),
+            market_id: String::new(),
+            yes: collect_levels(&[], &orderbook.yes, &orderbook.yes_dollars),
+            no: collect_levels(&[], &orderbook.no, &orderbook.no_dollars),
+            seq: None,
+        }
+    }
+
+    /// Build a book from the fixed-point `orderbook_fp` levels of a REST
+    /// orderbook response; see [`OrderBook::from_rest`].
+    pub fn from_rest_fp(market_ticker: impl Into<String>, orderbook: &OrderbookFp) -> Self {
+        Self {
+            market_ticker: market_ticker.into(),
+            market_id: String::new(),
+            yes: collect_levels(&orderbook.yes_dollars, &[], &[]),
+            no: collect_levels(&orderbook.no_dollars, &[], &[]),
+            seq: None,
+        }
+    }
+
+    /// Build a book from a full REST orderbook response, preferring the
+    /// `orderbook_fp` levels so fractional quantities survive, then the
+    /// levels [`OrderBook::from_rest`] reads.
+    pub fn from_rest_response(
+        market_ticker: impl Into<String>,
+        response: &GetMarketOrderbookResponse,
+    ) -> Self {
+        let (yes_fp, no_fp) = response
+            .orderbook_fp
+            .as_ref()
+            .map_or((&[][..], &[][..]), |fp| {
+                (&fp.yes_dollars[..], &fp.no_dollars[..])
+            });
+        let orderbook = &response.orderbook;
+        Self {
+            market_ticker: market_ticker.into(),
+            market_id: String::new(),
+            yes: collect_levels(yes_fp, &orderbook.yes, &orderbook.yes_dollars),
+            no: collect_levels(no_fp, &orderbook.no, &orderbook.no_dollars),
+            seq: None,
+        }
+    }
+
+    /// Apply an `orderbook_delta` message. Levels that drop to zero are
+    /// removed, and a level driven below zero is clamped to zero (removed).
+    ///
+    /// Uses `delta_fp`, falling back to the integer `delta` when it is
+    /// missing or malformed.
+    pub fn apply_delta(&mut self, delta: &WsOrderbookDelta, seq: Option<u64>) -> DeltaOutcome {
+        let levels = match delta.side {
+            YesNo::Yes => &mut self.yes,
+            YesNo::No => &mut self.no,
+            YesNo::Unknown => return DeltaOutcome::UnknownSide,
+        };
+        let change = Quantity::parse(&delta.delta_fp)
+            .unwrap_or_else(|| Quantity::from_contracts(delta.delta));
+        let qty = levels.entry(delta.price).or_default();
+        *qty += change;
+        let outcome = if qty.hundredths() < 0 {
+            DeltaOutcome::Clamped {
+                excess: Quantity::from_hundredths(-qty.hundredths()),
+            }
+        } else {
+            DeltaOutcome::Applied
+        };
+        if !qty.is_positive() {
+            levels.remove(&delta.price);
+        }
+        if seq.is_some() {
+            self.seq = seq;
+        }
+        outcome
+    }
+
+    /// Best YES bid: (price_cents, quantity).
+    pub fn best_yes_bid(&self) -> Option<(i64, Quantity)> {
+        self.yes.iter().next_back().map(|(p, q)| (*p, *q))
+    }
+
+    /// Best NO bid: (price_cents, quantity).
+    pub fn best_no_bid(&self) -> Option<(i64, Quantity)> {
+        self.no.iter().next_back().map(|(p, q)| (*p, *q))
+    }
+
+    pub fn top_of_book(&self) -> TopOfBook {
+        TopOfBook {
+            yes_bid: self.best_yes_bid(),
+            no_bid: self.best_no_bid(),
+        }
+    }
+
+    /// Up to `n` bid levels on `side`, best (highest price) first.
+    pub fn depth(&self, side: YesNo, n: usize) -> Vec<(i64, Quantity)> {
+        let levels = match side {
+            YesNo::Yes => &self.yes,
+            YesNo::No => &self.no,
+            YesNo::Unknown => return Vec::new(),
+        };
+        levels.iter().rev().take(n).map(|(p, q)| (*p, *q)).collect()
+    }
+}
+
+/// Collect levels from the first non-empty representation: fixed-point,
+/// integer cents, then dollar prices with integer quantities.
+fn collect_levels(
+    fixed: &[(String, String)],
+    cents: &[(i64, i64)],
+    dollars: &[(String, i64)],
+) -> BTreeMap<i64, Quantity> {
+    levels(fixed, cents, dollars).collect()
+}
+
+/// Highest bid price in cents among the levels [`collect_levels`] would
+/// keep, without building the map.
+pub(crate) fn best_level_price(
+    fixed: &[(String, String)],
+    cents: &[(i64, i64)],
+    dollars: &[(String, i64)],
+) -> Option<i64> {
+    levels(fixed, cents, dollars).map(|(price, _)| price).max()
+}
+
+/// Valid positive levels from the first representation that has any.
+fn levels<'a>(
+    fixed: &'a [(String, String)],
+    cents: &'a [(i64, i64)],
+    dollars: &'a [(String, i64)],
+) -> impl Iterator<Item = (i64, Quantity)> + 'a {
+    let fixed_levels = || {
+        fixed
+            .iter()
+            .filter_map(|(price, qty)| Some((dollars_to_cents(price)?, Quantity::parse(qty)?)))
+            .filter(|(_, qty)| qty.is_positive())
+    };
+    let cent_levels = || {
+        cents
+            .iter()
+            .filter(|(_, qty)| *qty > 0)
+            .map(|(price, qty)| (*price, Quantity::from_contracts(*qty)))
+    };
+    let dollar_levels = || {
+        dollars
+            .iter()
+            .filter(|(_, qty)| *qty > 0)
+            .filter_map(|(price, qty)| {
+                Some((dollars_to_cents(price)?, Quantity::from_contracts(*qty)))
+            })
+    };
+    let use_fixed = fixed_levels().next().is_some();
+    let use_cents = !use_fixed && cent_levels().next().is_some();
+    let use_dollars = !use_fixed && !use_cents;
+    let all_or_none = |used: bool| if used { usize::MAX } else { 0 };
+    fixed_levels()
+        .take(all_or_none(use_fixed))
+        .chain(cent_levels().take(all_or_none(use_cents)))
+        .chain(dollar_levels().take(all_or_none(use_dollars)))
+}
+
+#[derive(Debug, Default)]
+struct SnapshotCacheInner {
+    books: HashMap<String, OrderBook>,
+    /// Insertion order, used to evict the oldest market once full.
+    order: VecDeque<String>,
+}
+
+/// Bounded, shareable cache holding the latest [`OrderBook`] per market.
+///
+/// Cloning is cheap; all clones share the same storage. Reads are
+/// synchronous so they can be served from any task or thread while the
+/// WebSocket reader keeps the cache up to date. Once `capacity` markets
+/// are cached, inserting a new market evicts the oldest one.
+#[derive(Debug, Clone)]
+pub struct SnapshotCache {
+    inner: Arc<RwLock<SnapshotCacheInner>>,
+    capacity: usize,
+}
+
+impl SnapshotCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(SnapshotCacheInner::default())),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().books.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Clone of the latest book for `ticker`.
+    pub fn get(&self, ticker: &str) -> Option<OrderBook> {
+        self.read().books.get(ticker).cloned()
+    }
+
+    /// Best bids for `ticker` without cloning the full book.
+    pub fn top_of_book(&self, ticker: &str) -> Option<TopOfBook> {
+        self.read().books.get(ticker).map(OrderBook::top_of_book)
+    }
+
+    pub fn tickers(&self) -> Vec<String> {
+        self.read().order.iter().cloned().collect()
+    }
+
+    pub fn remove(&self, ticker: &str) -> Option<OrderBook> {
+        let mut inner = self.write();
+        inner.order.retain(|t| t != ticker);
+        inner.books.remove(ticker)
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.write();
+        inner.books.clear();
+        inner.order.clear();
+    }
+
+    /// Remove every ticker in `tickers` under one lock.
+    pub(crate) fn remove_all<'a>(&self, tickers: impl Iterator<Item = &'a String>) {
+        let mut inner = self.write();
+        let mut removed = false;
+        for ticker in tickers {
+            removed |= inner.books.remove(ticker).is_some();
+        }
+        if removed {
+            let SnapshotCacheInner { books, order } = &mut *inner;
+            order.retain(|t| books.contains_key(t));
+        }
+    }
+
+    pub(crate) fn insert(&self, book: OrderBook) {
+        let mut inner = self.write();
+        if !inner.books.contains_key(&book.market_ticker) {
+            while inner.books.len() >= self.capacity {
+                match inner.order.pop_front() {
+                    Some(oldest) => {
+                        inner.books.remove(&oldest);
+                    }
+                    None => break,
+                }
+            }
+            inner.order.push_back(book.market_ticker.clone());
+        }
+        inner.books.insert(book.market_ticker.clone(), book);
+    }
+
+    /// Apply `delta` to the cached book in place. Returns `false` if the
+    /// market isn't cached.
+    pub(crate) fn apply_delta(&self, delta: &WsOrderbookDelta, seq: Option<u64>) -> bool {
+        match self.write().books.get_mut(&delta.market_ticker) {
+            Some(book) => {
+                book.apply_delta(delta, seq);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, SnapshotCacheInner> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, SnapshotCacheInner> {
+        self.inner.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Maintains local order books from `orderbook_snapshot` / `orderbook_delta`
+/// messages.
+///
+/// Attach one to [`KalshiWsClient`](crate::KalshiWsClient) with
+/// [`attach_book_manager`](crate::KalshiWsClient::attach_book_manager) and
+/// pair it with a [`SnapshotCache`] to query books from other tasks.
+///
+/// The manager keeps every subscribed book while the cache is bounded and
+/// may evict, so a cached market is held in both; without a cache each book
+/// is stored once.
+#[derive(Debug, Clone, Default)]
+pub struct BookManager {
+    books: HashMap<String, OrderBook>,
+    cache: Option<SnapshotCache>,
+    orphan_deltas: u64,
+    clamped_deltas: u64,
+}
+
+impl BookManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publish every updated book to `cache`.
+    pub fn with_cache(mut self, cache: SnapshotCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn cache(&self) -> Option<&SnapshotCache> {
+        self.cache.as_ref()
+    }
+
+    pub fn book(&self, ticker: &str) -> Option<&OrderBook> {
+        self.books.get(ticker)
+    }
+
+    pub fn books(&self) -> impl Iterator<Item = &OrderBook> {
+        self.books.values()
+    }
+
+    /// Apply an incoming message. Returns the updated book, if any.
+    ///
+    /// Deltas for markets without a prior snapshot are ignored and counted
+    /// in [`orphan_deltas`](Self::orphan_deltas); deltas clamped at zero are
+    /// counted in [`clamped_deltas`](Self::clamped_deltas).
+    pub fn apply(&mut self, msg: &WsMessage) -> Option<&OrderBook> {
+        let ticker = match msg {
+            WsMessage::Data(WsDataMessage::OrderbookSnapshot { seq, msg, .. }) => {
+                self.insert(OrderBook::from_snapshot(msg, *seq));
+                &msg.market_ticker
+            }
+            WsMessage::Data(WsDataMessage::OrderbookDelta { seq, msg, .. }) => {
+                let Some(book) = self.books.get_mut(&msg.market_ticker) else {
+                    self.orphan_deltas += 1;
+                    return None;
+                };
+                if let DeltaOutcome::Clamped { .. } = book.apply_delta(msg, *seq) {
+                    self.clamped_deltas += 1;
+                }
+                // The cache keeps its own copy; only re-seed it if the
+                // market was evicted.
+                if let Some(cache) = &self.cache
+                    && !cache.apply_delta(msg, *seq)
+                {
+                    cache.insert(book.clone());
+                }
+                &msg.market_ticker
+            }
+            _ => return None,
+        };
+        self.books.get(ticker)
+    }
+
+    /// Seed or replace a book, e.g. from [`KalshiRestClient::get_order_book`](crate::KalshiRestClient::get_order_book).
+    pub fn insert(&mut self, book: OrderBook) {
+        if let Some(cache) = &self.cache {
+            cache.insert(book.clone());
+        }
+        self.books.insert(book.market_ticker.clone(), book);
+    }
+
+    /// Drop all local books, and their entries in the attached cache, so
+    /// no reader sees a pre-disconnect book. Called on reconnect; fresh
+    /// snapshots follow for the markets that are resubscribed.
+    pub fn clear(&mut self) {
+        if let Some(cache) = &self.cache {
+            cache.remove_all(self.books.keys());
+        }
+        self.books.clear();
+    }
+
+    /// Deltas ignored because their market had no snapshot yet.
+    pub fn orphan_deltas(&self) -> u64 {
+        self.orphan_deltas
+    }
+
+    /// Deltas that would have left a level negative.
+    pub fn clamped_deltas(&self) -> u64 {
+        self.clamped_deltas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(ticker: &str) -> WsMessage {
+        let raw = format!(
+            r#"{{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{{"market_ticker":"{ticker}","market_id":"m","yes":[[40,10],[45,5]],"no":[[50,7]]}}}}"#
+        );
+        WsMessage::from_bytes(raw.as_bytes()).expect("snapshot")
+    }
+
+    fn delta(ticker: &str, price: i64, delta: i64, side: &str, seq: u64) -> WsMessage {
+        let raw = format!(
+            r#"{{"type":"orderbook_delta","sid":1,"seq":{seq},"msg":{{"market_ticker":"{ticker}","market_id":"m","price":{price},"price_dollars":"0.{price}","delta":{delta},"delta_fp":"{delta}","side":"{side}"}}}}"#
+        );
+        WsMessage::from_bytes(raw.as_bytes()).expect("delta")
+    }
+
+    #[test]
+    fn book_manager_applies_snapshot_and_deltas() {
+        let mut manager = BookManager::new();
+        assert!(manager.apply(&delta("A", 45, 1, "yes", 1)).is_none());
+
+        manager.apply(&snapshot("A")).expect("book");
+        manager.apply(&delta("A", 46, 3, "yes", 2));
+        manager.apply(&delta("A", 50, -7, "no", 3));
+
+        let book = manager.book("A").expect("book");
+        assert_eq!(book.seq, Some(3));
+        assert_eq!(book.best_yes_bid(), Some((46, Quantity::from_contracts(3))));
+        assert_eq!(book.best_no_bid(), None);
+        assert_eq!(
+            book.top_of_book().no_ask(),
+            Some((54, Quantity::from_contracts(3)))
+        );
+    }
+
+    #[test]
+    fn deltas_clamp_at_zero_and_depth_lists_best_first() {
+        let mut manager = BookManager::new();
+        manager.apply(&delta("A", 45, 1, "yes", 1));
+        assert_eq!(manager.orphan_deltas(), 1);
+
+        manager.apply(&snapshot("A"));
+        manager.apply(&delta("A", 45, -8, "yes", 2));
+        assert_eq!(manager.clamped_deltas(), 1);
+        let book = manager.book("A").expect("book");
+        assert_eq!(
+            book.depth(YesNo::Yes, 5),
+            [(40, Quantity::from_contracts(10))]
+        );
+
+        let mut book = book.clone();
+        let WsMessage::Data(WsDataMessage::OrderbookDelta { msg, .. }) =
+            delta("A", 50, -9, "no", 3)
+        else {
+            panic!("expected delta");
+        };
+        assert_eq!(
+            book.apply_delta(&msg, Some(3)),
+            DeltaOutcome::Clamped {
+                excess: Quantity::from_contracts(2)
+            }
+        );
+        assert!(book.no.is_empty());
+
+        manager.apply(&delta("A", 47, 2, "yes", 3));
+        manager.apply(&delta("A", 43, 4, "yes", 4));
+        let depth = manager.book("A").expect("book").depth(YesNo::Yes, 2);
+        assert_eq!(
+            depth,
+            [
+                (47, Quantity::from_contracts(2)),
+                (43, Quantity::from_contracts(4))
+            ]
+        );
+    }
+
+    #[test]
+    fn rest_orderbook_seeds_book_with_dollar_fallback() {
+        let orderbook: Orderbook = serde_json::from_str(
+            r#"{"yes":null,"no":null,"yes_dollars":[["0.4200",3],["0.4500",0]],"no_dollars":[["0.5100",8]]}"#,
+        )
+        .expect("orderbook");
+        let mut manager = BookManager::new();
+        manager.insert(OrderBook::from_rest("A", &orderbook));
+        manager.apply(&delta("A", 44, 2, "yes", 5));
+
+        let book = manager.book("A").expect("book");
+        assert_eq!(book.yes.len(), 2);
+        assert_eq!(book.best_yes_bid(), Some((44, Quantity::from_contracts(2))));
+        assert_eq!(book.best_no_bid(), Some((51, Quantity::from_contracts(8))));
+        assert_eq!(book.seq, Some(5));
+    }
+
+    #[test]
+    fn rest_response_prefers_fixed_point_levels() {
+        let response: GetMarketOrderbookResponse = serde_json::from_str(
+            r#"{"orderbook":{"yes":[[44,0]],"no":[[53,2]]},"orderbook_fp":{"yes_dollars":[["0.4400","0.50"]],"no_dollars":[]}}"#,
+        )
+        .expect("response");
+        let book = OrderBook::from_rest_response("A", &response);
+        assert_eq!(
+            book.best_yes_bid(),
+            Some((44, Quantity::from_hundredths(50)))
+        );
+        assert_eq!(book.best_no_bid(), Some((53, Quantity::from_contracts(2))));
+    }
+
+    #[test]
+    fn fixed_point_levels_and_deltas_keep_fractions() {
+        let raw = r#"{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{"market_ticker":"F","market_id":"m","yes":[[40,10]],"yes_dollars_fp":[["0.4000","10.25"]],"no_dollars_fp":[["0.5500","1.50"]]}}"#;
+        let mut manager = BookManager::new();
+        manager.apply(&WsMessage::from_bytes(raw.as_bytes()).expect("snapshot"));
+
+        let raw = r#"{"type":"orderbook_delta","sid":1,"seq":2,"msg":{"market_ticker":"F","market_id":"m","price":40,"price_dollars":"0.4000","delta":0,"delta_fp":"-0.75","side":"yes"}}"#;
+        manager.apply(&WsMessage::from_bytes(raw.as_bytes()).expect("delta"));
+        let raw = r#"{"type":"orderbook_delta","sid":1,"seq":3,"msg":{"market_ticker":"F","market_id":"m","price":55,"price_dollars":"0.5500","delta":-1,"delta_fp":"-1.50","side":"no"}}"#;
+        manager.apply(&WsMessage::from_bytes(raw.as_bytes()).expect("delta"));
+
+        let book = manager.book("F").expect("book");
+        let (price, qty) = book.best_yes_bid().expect("yes bid");
+        assert_eq!(price, 40);
+        assert_eq!(qty, Quantity::from_hundredths(950));
+        assert_eq!(qty.to_string(), "9.50");
+        assert!(book.no.is_empty());
+
+        assert_eq!(
+            Quantity::parse("-0.125"),
+            Some(Quantity::from_hundredths(-12))
+        );
+        assert_eq!(Quantity::parse("abc"), None);
+    }
+
+    #[test]
+    fn snapshot_cache_is_shared_and_bounded() {
+        let cache = SnapshotCache::new(2);
+        let mut manager = BookManager::new().with_cache(cache.clone());
+
+        manager.apply(&snapshot("A"));
+        manager.apply(&snapshot("B"));
+        manager.apply(&delta("A", 47, 2, "yes", 2));
+        let top = cache.top_of_book("A").expect("top");
+        assert_eq!(top.yes_bid, Some((47, Quantity::from_contracts(2))));
+        assert_eq!(top.yes_ask(), Some((50, Quantity::from_contracts(7))));
+
+        manager.apply(&snapshot("C"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("A").is_none());
+        assert_eq!(cache.tickers(), vec!["B".to_string(), "C".to_string()]);
+
+        manager.apply(&delta("C", 47, 1, "yes", 2));
+        manager.apply(&delta("A", 48, 1, "yes", 3));
+        assert_eq!(cache.get("C").as_ref(), manager.book("C"));
+        assert_eq!(cache.get("A").as_ref(), manager.book("A"));
+        assert_eq!(cache.tickers(), vec!["C".to_string(), "A".to_string()]);
+    }
+
+    #[test]
+    fn clear_drops_the_managers_cached_books() {
+        let cache = SnapshotCache::new(4);
+        let mut other = BookManager::new().with_cache(cache.clone());
+        other.apply(&snapshot("X"));
+        let mut manager = BookManager::new().with_cache(cache.clone());
+        manager.apply(&snapshot("A"));
+        manager.apply(&snapshot("B"));
+
+        manager.clear();
+        assert!(manager.book("A").is_none());
+        assert!(cache.top_of_book("A").is_none());
+        assert!(cache.get("B").is_none());
+        assert_eq!(cache.tickers(), vec!["X".to_string()]);
+    }
+}
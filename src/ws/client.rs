@@ -1,10 +1,15 @@
 use crate::auth::KalshiAuth;
 use crate::env::{KalshiEnvironment, WS_PATH};
 use crate::error::KalshiError;
+use crate::rest::KalshiRestClient;
+use crate::ws::book::{BookManager, OrderBook};
+use crate::ws::fills::FillDeduper;
+use crate::ws::proxy::{self, WsProxy};
 use crate::ws::types::{
-    WsEnvelope, WsListSubscriptionsCmd, WsMessage, WsRawEvent, WsSubscribeCmd,
-    WsSubscriptionParams, WsUnsubscribeCmd, WsUnsubscribeParams, WsUpdateSubscriptionCmd,
-    WsUpdateSubscriptionParams, validate_subscription, validate_update,
+    WsChannel, WsDataMessage, WsEnvelope, WsError, WsListSubscriptionsCmd, WsMessage, WsMsgType,
+    WsRawEvent, WsSubscribeCmd, WsSubscriptionInfo, WsSubscriptionParams, WsUnsubscribeCmd,
+    WsUnsubscribeParams, WsUpdateAction, WsUpdateSubscriptionCmd, WsUpdateSubscriptionParams,
+    validate_subscription, validate_update,
 };
 
 use futures::{SinkExt, StreamExt};
@@ -12,23 +17,28 @@ use futures::{SinkExt, StreamExt};
 use bytes::Bytes;
 use rand::random;
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::value::RawValue;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use tokio::sync::{Mutex, mpsc, watch};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::runtime::Handle;
+use tokio::sync::{Mutex, Notify, mpsc, oneshot, watch};
 use tokio::task::JoinHandle;
-use tokio::time::{Duration, sleep, timeout as tokio_timeout};
-use tokio_tungstenite::connect_async;
+use tokio::time::{Duration, Instant, sleep, timeout as tokio_timeout};
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::http::{HeaderValue, Request};
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{client_async_tls_with_config, connect_async};
 
 type WsStream =
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
 
 /// Configuration for automatic WebSocket reconnection in [`KalshiWsClient`].
 ///
-/// Uses exponential backoff with jitter. The delay for attempt *n* is
-/// `min(base_delay * 2^(n-1), max_delay)` ± `jitter`.
+/// Uses exponential backoff with jitter unless `backoff` is set. The delay
+/// for attempt *n* is `min(base_delay * 2^(n-1), max_delay)` ± `jitter`.
 ///
 /// # Default
 ///
@@ -39,6 +49,7 @@ type WsStream =
 /// | `max_delay` | 30 s |
 /// | `jitter` | 0.2 |
 /// | `resubscribe` | `true` |
+/// | `backoff` | `None` (exponential from the fields above) |
 #[derive(Debug, Clone)]
 pub struct WsReconnectConfig {
     /// Maximum reconnection attempts. `None` means unlimited.
@@ -51,6 +62,9 @@ pub struct WsReconnectConfig {
     pub jitter: f64,
     /// Whether to resubscribe to active channels after reconnecting.
     pub resubscribe: bool,
+    /// Custom delay schedule. When set, `base_delay`, `max_delay`, and
+    /// `jitter` are ignored; `max_retries` still applies.
+    pub backoff: Option<Arc<dyn BackoffStrategy>>,
 }
 
 impl Default for WsReconnectConfig {
@@ -61,12 +75,51 @@ impl Default for WsReconnectConfig {
             max_delay: Duration::from_secs(30),
             jitter: 0.2,
             resubscribe: true,
+            backoff: None,
         }
     }
 }
 
 impl WsReconnectConfig {
+    /// Use `strategy` for reconnect delays instead of the exponential fields.
+    pub fn with_backoff(mut self, strategy: impl BackoffStrategy + 'static) -> Self {
+        self.backoff = Some(Arc::new(strategy));
+        self
+    }
+
     fn backoff_delay(&self, attempt: u32) -> Duration {
+        match &self.backoff {
+            Some(strategy) => strategy.delay(attempt),
+            None => ExponentialBackoff {
+                base_delay: self.base_delay,
+                max_delay: self.max_delay,
+                jitter: self.jitter,
+            }
+            .delay(attempt),
+        }
+    }
+}
+
+/// How long to wait before each reconnect attempt.
+///
+/// Set with [`WsReconnectConfig::with_backoff`] for schedules such as
+/// decorrelated jitter or fixed intervals.
+pub trait BackoffStrategy: std::fmt::Debug + Send + Sync {
+    /// Delay before reconnect attempt `attempt` (1-based).
+    fn delay(&self, attempt: u32) -> Duration;
+}
+
+/// `min(base_delay * 2^(n-1), max_delay)` ± `jitter`, the default schedule.
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Random jitter factor applied to each delay (0.0–1.0).
+    pub jitter: f64,
+}
+
+impl BackoffStrategy for ExponentialBackoff {
+    fn delay(&self, attempt: u32) -> Duration {
         let exp = 2f64.powi(attempt.saturating_sub(1) as i32);
         let mut delay = self.base_delay.mul_f64(exp);
         if delay > self.max_delay {
@@ -89,8 +142,27 @@ pub enum WsReaderMode {
 
 #[derive(Debug, Clone)]
 pub struct WsReaderConfig {
+    /// Maximum number of events buffered between the reader and the consumer.
     pub buffer_size: usize,
     pub mode: WsReaderMode,
+    /// Also cap buffered events by the total size of the frames they were
+    /// parsed from. The reader stops reading until the consumer drains enough
+    /// bytes; a single frame larger than the budget is still delivered once
+    /// the buffer is empty. `None` (the default) bounds by count only.
+    pub max_buffered_bytes: Option<usize>,
+    /// Send a Ping after this long without any frame from the server, so
+    /// idle connections stay open behind load balancers and dead ones are
+    /// noticed. `None` (the default) only answers the server's Pings.
+    pub ping_interval: Option<Duration>,
+    /// With `ping_interval` set, how long to wait for any frame after a
+    /// Ping before treating the connection as dead and reconnecting.
+    pub pong_timeout: Duration,
+    /// Copy every text and binary frame to this tap as NDJSON, alongside the
+    /// events delivered to the consumer. `None` (the default) disables it.
+    pub tap: Option<WsFrameTap>,
+    /// Report received messages, parse failures, and reconnect attempts to
+    /// these hooks. `None` (the default) skips the bookkeeping entirely.
+    pub metrics: Option<Arc<dyn WsMetrics>>,
 }
 
 impl Default for WsReaderConfig {
@@ -98,6 +170,188 @@ impl Default for WsReaderConfig {
         Self {
             buffer_size: 1024,
             mode: WsReaderMode::Owned,
+            max_buffered_bytes: None,
+            ping_interval: None,
+            pong_timeout: Duration::from_secs(10),
+            tap: None,
+            metrics: None,
+        }
+    }
+}
+
+/// Counters fed by the reader task, for exporting to Prometheus or similar.
+///
+/// Set as [`WsReaderConfig::metrics`]. Hooks run inline on the reader task,
+/// so they should only bump counters; every method defaults to a no-op.
+pub trait WsMetrics: std::fmt::Debug + Send + Sync {
+    /// A text or binary frame arrived with this `type`, before it is
+    /// delivered. Control frames (`subscribed`, `ok`, `error`, ...) count too.
+    /// In [`WsReaderMode::Owned`] the type comes from the parsed message
+    /// ([`WsMessage::msg_type`]), so a frame that fails to parse is only
+    /// reported to [`on_parse_error`](Self::on_parse_error).
+    fn on_message(&self, msg_type: &WsMsgType) {
+        let _ = msg_type;
+    }
+
+    /// A frame could not be parsed. In [`WsReaderMode::Owned`] the reader
+    /// then reconnects; in [`WsReaderMode::Raw`] this means the frame was not
+    /// JSON with a `type` field, and it is still delivered.
+    fn on_parse_error(&self) {}
+
+    /// Reconnect attempt `attempt` (1-based) is starting, after its backoff.
+    fn on_reconnect(&self, attempt: u32) {
+        let _ = attempt;
+    }
+}
+
+type TapWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Writes raw WebSocket frames, one per line, to an [`AsyncWrite`].
+///
+/// Set as [`WsReaderConfig::tap`]. The writer runs on its own task behind a
+/// queue of `capacity` frames, so a slow writer never holds up the reader:
+/// when the queue is full the frame is dropped from the tap (the consumer
+/// still gets its event) and [`WsEvent::TapDropped`] is emitted when the
+/// tap first falls behind. A write error stops the tap; later frames
+/// count as dropped. The writer is flushed whenever the queue drains and
+/// shut down when the reader stops.
+///
+/// A tap serves one reader; starting a second reader with it fails.
+#[derive(Clone)]
+pub struct WsFrameTap {
+    writer: Arc<std::sync::Mutex<Option<TapWriter>>>,
+    capacity: usize,
+    dropped: Arc<AtomicU64>,
+}
+
+impl WsFrameTap {
+    /// Tap frames into `writer`, queueing up to `capacity` of them.
+    pub fn new(writer: impl AsyncWrite + Send + Unpin + 'static, capacity: usize) -> Self {
+        Self {
+            writer: Arc::new(std::sync::Mutex::new(Some(Box::new(writer)))),
+            capacity,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Frames dropped from the tap so far.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Spawn the writer task and return the reader's end of the queue.
+    fn start(&self, handle: Option<&Handle>) -> Result<TapSender, KalshiError> {
+        let writer = self
+            .writer
+            .lock()
+            .expect("frame tap lock poisoned")
+            .take()
+            .ok_or_else(|| {
+                KalshiError::InvalidParams("websocket frame tap already in use".to_string())
+            })?;
+        let (tx, rx) = mpsc::channel(self.capacity);
+        let task = write_tap(writer, rx);
+        match handle {
+            Some(handle) => drop(handle.spawn(task)),
+            None => drop(tokio::spawn(task)),
+        }
+        Ok(TapSender {
+            tx,
+            dropped: self.dropped.clone(),
+            stalled: false,
+        })
+    }
+}
+
+impl std::fmt::Debug for WsFrameTap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WsFrameTap")
+            .field("capacity", &self.capacity)
+            .field("dropped", &self.dropped())
+            .finish_non_exhaustive()
+    }
+}
+
+async fn write_tap(mut writer: TapWriter, mut rx: mpsc::Receiver<Bytes>) {
+    while let Some(frame) = rx.recv().await {
+        if writer.write_all(&frame).await.is_err() || writer.write_all(b"\n").await.is_err() {
+            return;
+        }
+        if rx.is_empty() && writer.flush().await.is_err() {
+            return;
+        }
+    }
+    let _ = writer.shutdown().await;
+}
+
+/// The reader loop's handle on a [`WsFrameTap`].
+struct TapSender {
+    tx: mpsc::Sender<Bytes>,
+    dropped: Arc<AtomicU64>,
+    stalled: bool,
+}
+
+impl TapSender {
+    /// Queue `frame` without waiting. Returns the event to emit when this
+    /// frame is the first one dropped since the tap last kept up.
+    fn offer(&mut self, frame: Bytes) -> Option<WsEvent> {
+        if self.tx.try_send(frame).is_ok() {
+            self.stalled = false;
+            return None;
+        }
+        let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        if std::mem::replace(&mut self.stalled, true) {
+            None
+        } else {
+            Some(WsEvent::TapDropped { dropped })
+        }
+    }
+}
+
+/// Client-initiated Ping schedule for the reader loop.
+#[derive(Debug, Clone, Copy)]
+struct Keepalive {
+    interval: Duration,
+    pong_timeout: Duration,
+}
+
+impl Keepalive {
+    /// When the reader must act: send a Ping once `interval` has passed
+    /// since the last frame, or fail once `pong_timeout` has passed since
+    /// an unanswered Ping.
+    fn deadline(&self, last_frame: Instant, ping_sent: Option<Instant>) -> Instant {
+        match ping_sent {
+            Some(at) => at + self.pong_timeout,
+            None => last_frame + self.interval,
+        }
+    }
+}
+
+/// What [`KalshiWsClient`] does when the connection drops, before the first
+/// [`WsEvent::Reconnecting`] (or [`WsEvent::Disconnected`] when no reconnect
+/// is attempted). Set via [`KalshiWsClient::on_disconnect`].
+///
+/// It runs once per drop, however many reconnect attempts follow and
+/// whether or not they succeed.
+#[derive(Debug, Clone, Default)]
+pub enum DisconnectAction {
+    #[default]
+    Nothing,
+    /// Call [`KalshiRestClient::cancel_all_orders`] as a kill switch.
+    ///
+    /// Best-effort: the client needs REST auth, errors are discarded, and
+    /// nothing runs on a user-initiated [`close`](KalshiWsClient::close).
+    /// Orders are cancelled even if the reconnect that follows succeeds.
+    CancelAllOrders(KalshiRestClient),
+}
+
+impl DisconnectAction {
+    async fn run(&self) {
+        match self {
+            DisconnectAction::Nothing => {}
+            DisconnectAction::CancelAllOrders(rest) => {
+                let _ = rest.cancel_all_orders().await;
+            }
         }
     }
 }
@@ -106,11 +360,33 @@ impl Default for WsReaderConfig {
 ///
 /// The high-level client wraps every raw WebSocket message as well as
 /// connection lifecycle transitions into this enum.
+///
+/// # Ordering
+///
+/// Events are delivered in the order their frames arrived, in both
+/// [`WsReaderMode`]s and with or without a background reader. Lifecycle
+/// events sit where the transition happened: [`Reconnecting`](Self::Reconnecting)
+/// and [`Reconnected`](Self::Reconnected) follow every event from the old
+/// connection and precede every event from the new one, and
+/// [`Disconnected`](Self::Disconnected) is last.
+/// Nothing is dropped to make room: when the reader's buffer is full
+/// ([`WsReaderConfig::buffer_size`], [`WsReaderConfig::max_buffered_bytes`])
+/// it stops reading the socket until the consumer catches up, so there is
+/// no lag marker to account for. The only events removed from the sequence
+/// are fills screened out by an attached [`FillDeduper`] and book messages
+/// consumed by [`KalshiWsClient::subscribe_orderbook_ready`].
 #[derive(Debug)]
 pub enum WsEvent {
     /// A parsed WebSocket message (data, ack, error, etc.).
     Message(WsMessage),
     Raw(WsRawEvent),
+    /// Connection is down and reconnect attempt `attempt` (1-based) starts
+    /// after waiting `delay`. Emitted before every attempt, so a long outage
+    /// shows up as a series of these rather than silence.
+    Reconnecting {
+        attempt: u32,
+        delay: Duration,
+    },
     /// Connection was lost and successfully re-established.
     ///
     /// `attempt` is the 1-based retry count that succeeded.
@@ -120,27 +396,223 @@ pub enum WsEvent {
         attempt: u32,
     },
     /// Connection was lost and could not be restored within
-    /// [`WsReconnectConfig::max_retries`].
+    /// [`WsReconnectConfig::max_retries`], or the server closed it with a
+    /// code that reconnecting won't fix (see [`KalshiError::is_retryable`]),
+    /// such as a policy or auth-rejected close. No reconnect is attempted in
+    /// the second case.
+    ///
+    /// `error` is the last reconnect failure, or the error that dropped the
+    /// connection (e.g. [`KalshiError::WsClosed`] with the server's close
+    /// code and reason) when no attempt was made.
     Disconnected {
         error: KalshiError,
+        /// The error that dropped the connection, when reconnect attempts
+        /// were made and `error` is the last of their failures.
+        cause: Option<KalshiError>,
     },
+    /// A fill the attached [`FillDeduper`] had already seen, typically
+    /// resent after a reconnect. Only emitted when
+    /// [`FillDeduper::report_duplicates`] is enabled; the fill itself is dropped.
+    DuplicateFill {
+        fill_id: String,
+    },
+    /// A data message on `sid` carried `seq` `got` instead of the `expected`
+    /// next value, so at least one message was missed (or the server
+    /// restarted the sequence). Emitted just before that message, only when
+    /// [`KalshiWsClient::detect_sequence_gaps`] is enabled. Order-book
+    /// consumers should resubscribe or reseed from REST.
+    SequenceGap {
+        sid: u64,
+        expected: u64,
+        got: u64,
+    },
+    /// The [`WsFrameTap`] fell behind (or its writer failed) and started
+    /// dropping frames. Emitted once per stall, before the event for the
+    /// first dropped frame; `dropped` is the tap's total so far. The events
+    /// themselves are still delivered.
+    TapDropped {
+        dropped: u64,
+    },
+}
+
+/// Difference between the subscriptions the client tracks and the ones the
+/// server reports, from [`KalshiWsClient::reconcile_subscriptions`].
+#[derive(Debug, Clone, Default)]
+pub struct WsSubscriptionDrift {
+    /// Subscriptions reported by the server.
+    pub server: Vec<WsSubscriptionInfo>,
+    /// Sids the client tracks as active that the server does not report.
+    pub missing_on_server: Vec<u64>,
+    /// Sids the server reports that the client does not track.
+    pub unknown_to_client: Vec<u64>,
+}
+
+impl WsSubscriptionDrift {
+    /// `true` when the client and server agree.
+    pub fn is_empty(&self) -> bool {
+        self.missing_on_server.is_empty() && self.unknown_to_client.is_empty()
+    }
+}
+
+/// Credentials shared between [`KalshiWsClient`] and its reader task.
+type SharedAuth = Arc<std::sync::Mutex<Option<KalshiAuth>>>;
+
+fn current_auth(auth: &SharedAuth) -> Option<KalshiAuth> {
+    auth.lock().expect("auth lock").clone()
+}
+
+/// Liveness shared between [`KalshiWsClient`] and its reader task.
+///
+/// Times are stored lock-free as nanoseconds since `base`, plus one so that
+/// zero means "never".
+#[derive(Debug)]
+struct ConnectionHealth {
+    connected: AtomicBool,
+    base: Instant,
+    connected_at: AtomicU64,
+    last_frame_at: AtomicU64,
+}
+
+impl ConnectionHealth {
+    fn connected() -> Arc<Self> {
+        let health = Arc::new(Self {
+            connected: AtomicBool::new(false),
+            base: Instant::now(),
+            connected_at: AtomicU64::new(0),
+            last_frame_at: AtomicU64::new(0),
+        });
+        health.set_connected(true);
+        health
+    }
+
+    fn set_connected(&self, connected: bool) {
+        self.connected.store(connected, Ordering::Release);
+        if connected {
+            self.connected_at.store(self.now(), Ordering::Release);
+        }
+    }
+
+    fn touch(&self) {
+        self.last_frame_at.store(self.now(), Ordering::Release);
+    }
+
+    fn now(&self) -> u64 {
+        u64::try_from(self.base.elapsed().as_nanos())
+            .unwrap_or(u64::MAX - 1)
+            .saturating_add(1)
+    }
+
+    fn load(&self, at: &AtomicU64) -> Option<Instant> {
+        match at.load(Ordering::Acquire) {
+            0 => None,
+            nanos => Some(self.base + Duration::from_nanos(nanos - 1)),
+        }
+    }
+
+    fn connected_at(&self) -> Option<Instant> {
+        self.load(&self.connected_at)
+    }
+
+    fn last_frame_at(&self) -> Option<Instant> {
+        self.load(&self.last_frame_at)
+    }
+}
+
+/// Frame bytes buffered between the reader task and [`WsEventReceiver`].
+#[derive(Debug)]
+struct ByteBudget {
+    max: usize,
+    used: AtomicUsize,
+    released: Notify,
+}
+
+impl ByteBudget {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            used: AtomicUsize::new(0),
+            released: Notify::new(),
+        }
+    }
+
+    /// Reserve `bytes`, waiting for the consumer while over budget. An empty
+    /// buffer always admits one event so oversized frames can't wedge the reader.
+    async fn acquire(&self, bytes: usize) {
+        loop {
+            let used = self.used.load(Ordering::Acquire);
+            if used == 0 || used.saturating_add(bytes) <= self.max {
+                if self
+                    .used
+                    .compare_exchange(used, used + bytes, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+                {
+                    return;
+                }
+                continue;
+            }
+            self.released.notified().await;
+        }
+    }
+
+    fn release(&self, bytes: usize) {
+        self.used.fetch_sub(bytes, Ordering::AcqRel);
+        self.released.notify_one();
+    }
+}
+
+/// Reader-side half of the event channel; each event carries its frame size.
+struct EventSender {
+    tx: mpsc::Sender<(WsEvent, usize)>,
+    budget: Option<Arc<ByteBudget>>,
+}
+
+impl EventSender {
+    /// Send a lifecycle event that doesn't count against the byte budget.
+    async fn send(&self, event: WsEvent) -> Result<(), KalshiError> {
+        self.send_sized(event, 0).await
+    }
+
+    async fn send_sized(&self, event: WsEvent, bytes: usize) -> Result<(), KalshiError> {
+        let closed = || KalshiError::Ws("websocket reader closed".to_string());
+        if let Some(budget) = &self.budget {
+            tokio::select! {
+                _ = budget.acquire(bytes) => {}
+                _ = self.tx.closed() => return Err(closed()),
+            }
+        }
+        self.tx.send((event, bytes)).await.map_err(|_| closed())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WsEventReceiver {
-    inner: Arc<Mutex<mpsc::Receiver<WsEvent>>>,
+    inner: Arc<Mutex<mpsc::Receiver<(WsEvent, usize)>>>,
+    budget: Option<Arc<ByteBudget>>,
 }
 
 impl WsEventReceiver {
-    fn new(rx: mpsc::Receiver<WsEvent>) -> Self {
+    fn new(rx: mpsc::Receiver<(WsEvent, usize)>, budget: Option<Arc<ByteBudget>>) -> Self {
         Self {
             inner: Arc::new(Mutex::new(rx)),
+            budget,
         }
     }
 
     pub async fn next(&self) -> Option<WsEvent> {
         let mut rx = self.inner.lock().await;
-        rx.recv().await
+        let (event, bytes) = rx.recv().await?;
+        if let Some(budget) = &self.budget {
+            budget.release(bytes);
+        }
+        Some(event)
+    }
+
+    /// Approximate frame bytes waiting to be consumed, or `None` when
+    /// [`WsReaderConfig::max_buffered_bytes`] is unset.
+    pub fn buffered_bytes(&self) -> Option<usize> {
+        self.budget
+            .as_ref()
+            .map(|budget| budget.used.load(Ordering::Acquire))
     }
 }
 
@@ -148,6 +620,14 @@ impl WsEventReceiver {
 struct SubscriptionTracker {
     pending: HashMap<u64, WsSubscriptionParams>,
     active: HashMap<u64, WsSubscriptionParams>,
+    pending_lists: HashMap<u64, oneshot::Sender<Vec<WsSubscriptionInfo>>>,
+    /// Waiters for `subscribed`/`error` replies, keyed by command id.
+    pending_subscribes: HashMap<u64, oneshot::Sender<Result<u64, WsError>>>,
+    /// Command ids in `pending` and sids in `active` not to restore on reconnect.
+    transient_pending: HashSet<u64>,
+    transient_active: HashSet<u64>,
+    /// Last data `seq` seen per sid, for gap detection.
+    last_seq: HashMap<u64, u64>,
 }
 
 impl SubscriptionTracker {
@@ -166,10 +646,73 @@ impl SubscriptionTracker {
             WsMessage::Unsubscribed { sid: Some(sid), .. } => {
                 self.handle_unsubscribed(Some(*sid));
             }
+            WsMessage::ListSubscriptions {
+                id: Some(id),
+                subscriptions,
+            } => {
+                if let Some(reply) = self.pending_lists.remove(id) {
+                    let _ = reply.send(subscriptions.clone());
+                }
+            }
+            WsMessage::Error {
+                id: Some(id),
+                error,
+            } => {
+                // A rejected subscribe must not be replayed on reconnect.
+                self.pending.remove(id);
+                self.transient_pending.remove(id);
+                if let Some(reply) = self.pending_subscribes.remove(id) {
+                    let _ = reply.send(Err(error.clone()));
+                }
+            }
             _ => {}
         }
     }
 
+    /// Record `seq` for `sid`, returning a [`WsEvent::SequenceGap`] if it
+    /// doesn't follow the last one. The first message on a sid starts fresh.
+    fn observe_seq(&mut self, sid: u64, seq: u64) -> Option<WsEvent> {
+        let last = self.last_seq.insert(sid, seq)?;
+        let expected = last.saturating_add(1);
+        (seq != expected).then_some(WsEvent::SequenceGap {
+            sid,
+            expected,
+            got: seq,
+        })
+    }
+
+    /// Forget sequence state: sids are reassigned after a reconnect.
+    fn reset_sequences(&mut self) {
+        self.last_seq.clear();
+    }
+
+    /// Drop `list_subscriptions` and `subscribe` waiters whose replies were
+    /// lost with the connection.
+    fn fail_pending_commands(&mut self) {
+        self.pending_lists.clear();
+        self.pending_subscribes.clear();
+    }
+
+    fn drift(&self, server: Vec<WsSubscriptionInfo>) -> WsSubscriptionDrift {
+        let mut missing_on_server: Vec<u64> = self
+            .active
+            .keys()
+            .copied()
+            .filter(|sid| !server.iter().any(|info| info.sid == *sid))
+            .collect();
+        missing_on_server.sort_unstable();
+        let unknown_to_client = server
+            .iter()
+            .map(|info| info.sid)
+            .filter(|sid| !self.active.contains_key(sid))
+            .collect();
+        WsSubscriptionDrift {
+            server,
+            missing_on_server,
+            unknown_to_client,
+        }
+    }
+
     fn handle_subscribed(&mut self, id: Option<u64>, sid: Option<u64>) {
         let (id, sid) = match (id, sid) {
             (Some(id), Some(sid)) => (id, sid),
@@ -177,22 +720,57 @@ impl SubscriptionTracker {
         };
         if let Some(params) = self.pending.remove(&id) {
             self.active.insert(sid, params);
+            if self.transient_pending.remove(&id) {
+                self.transient_active.insert(sid);
+            }
+        }
+        if let Some(reply) = self.pending_subscribes.remove(&id) {
+            let _ = reply.send(Ok(sid));
         }
     }
 
     fn handle_unsubscribed(&mut self, sid: Option<u64>) {
         if let Some(sid) = sid {
-            self.active.remove(&sid);
+            self.drop_active(sid);
         }
     }
 
+    fn active_snapshot(&self) -> Vec<(u64, WsSubscriptionParams)> {
+        let mut active: Vec<_> = self
+            .active
+            .iter()
+            .map(|(sid, params)| (*sid, params.clone()))
+            .collect();
+        active.sort_unstable_by_key(|(sid, _)| *sid);
+        active
+    }
+
+    /// Active sids subscribed to `channel` and, if given, covering
+    /// `market_ticker`, in ascending order.
+    fn matching_sids(&self, channel: WsChannel, market_ticker: Option<&str>) -> Vec<u64> {
+        let mut sids: Vec<u64> = self
+            .active
+            .iter()
+            .filter(|(_, params)| params.channels.contains(&channel))
+            .filter(|(_, params)| {
+                market_ticker.is_none_or(|ticker| {
+                    params.market_ticker.as_deref() == Some(ticker)
+                        || params.market_tickers.iter().flatten().any(|t| t == ticker)
+                })
+            })
+            .map(|(sid, _)| *sid)
+            .collect();
+        sids.sort_unstable();
+        sids
+    }
+
     fn drop_active(&mut self, sid: u64) {
         self.active.remove(&sid);
+        self.transient_active.remove(&sid);
+        self.last_seq.remove(&sid);
     }
 
     fn apply_update(&mut self, update: &WsUpdateSubscriptionParams) {
-        use crate::ws::types::WsUpdateAction;
-
         let sid = match update.target_sid() {
             Some(sid) => sid,
             None => return,
@@ -243,11 +821,44 @@ impl SubscriptionTracker {
         }
     }
 
+    /// Set `sid`'s tickers to exactly `tickers`. Returns the tickers removed
+    /// and added, or `None` if `sid` is not active.
+    fn replace_markets(
+        &mut self,
+        sid: u64,
+        tickers: Vec<String>,
+    ) -> Option<(Vec<String>, Vec<String>)> {
+        let params = self.active.get_mut(&sid)?;
+        let current = params.market_tickers.take().unwrap_or_default();
+        let removed = current
+            .iter()
+            .filter(|t| !tickers.contains(t))
+            .cloned()
+            .collect();
+        let added = tickers
+            .iter()
+            .filter(|t| !current.contains(t))
+            .cloned()
+            .collect();
+        params.market_tickers = Some(tickers);
+        Some((removed, added))
+    }
+
     fn prepare_resubscribe(&mut self) -> Vec<WsSubscriptionParams> {
-        let mut params: Vec<WsSubscriptionParams> = self.active.values().cloned().collect();
-        params.extend(self.pending.values().cloned());
-        self.active.clear();
-        self.pending.clear();
+        let mut params: Vec<WsSubscriptionParams> = self
+            .active
+            .drain()
+            .filter(|(sid, _)| !self.transient_active.contains(sid))
+            .map(|(_, params)| params)
+            .collect();
+        params.extend(
+            self.pending
+                .drain()
+                .filter(|(id, _)| !self.transient_pending.contains(id))
+                .map(|(_, params)| params),
+        );
+        self.transient_active.clear();
+        self.transient_pending.clear();
         params
     }
 }
@@ -275,17 +886,7 @@ impl KalshiWsLowLevelClient {
 
     /// Connect without auth (public channels only).
     pub async fn connect(env: KalshiEnvironment) -> Result<Self, KalshiError> {
-        let (ws_stream, _resp) = connect_async(&env.ws_url)
-            .await
-            .map_err(|e| KalshiError::Ws(e.to_string()))?;
-
-        let (write, read) = ws_stream.split();
-        Ok(Self {
-            write,
-            read,
-            next_id: 1,
-            authenticated: false,
-        })
+        Self::connect_via(env, None, &WsProxy::Direct).await
     }
 
     /// Connect with auth headers so you can subscribe to private channels.
@@ -295,39 +896,100 @@ impl KalshiWsLowLevelClient {
         env: KalshiEnvironment,
         auth: KalshiAuth,
     ) -> Result<Self, KalshiError> {
-        let mut req: Request<()> = env
-            .ws_url
-            .into_client_request()
-            .map_err(|e| KalshiError::Ws(e.to_string()))?;
+        Self::connect_via(env, Some(auth), &WsProxy::Direct).await
+    }
 
-        // WS signing: timestamp + "GET" + "/trade-api/ws/v2"
-        let headers = auth.build_headers("GET", WS_PATH)?;
+    /// Connect through `proxy`, with auth headers when `auth` is given.
+    pub async fn connect_via(
+        env: KalshiEnvironment,
+        auth: Option<KalshiAuth>,
+        proxy: &WsProxy,
+    ) -> Result<Self, KalshiError> {
+        Self::connect_request(env.ws_url.as_str(), auth, proxy).await
+    }
 
-        req.headers_mut().insert(
-            "KALSHI-ACCESS-KEY",
-            HeaderValue::from_str(&headers.key).map_err(|e| KalshiError::Header(e.to_string()))?,
-        );
-        req.headers_mut().insert(
-            "KALSHI-ACCESS-SIGNATURE",
-            HeaderValue::from_str(&headers.signature)
-                .map_err(|e| KalshiError::Header(e.to_string()))?,
-        );
-        req.headers_mut().insert(
-            "KALSHI-ACCESS-TIMESTAMP",
-            HeaderValue::from_str(&headers.timestamp_ms)
-                .map_err(|e| KalshiError::Header(e.to_string()))?,
-        );
+    /// Connect with a handshake request you build, e.g. to add a
+    /// `User-Agent` or other headers. Public channels only.
+    ///
+    /// The request is sent as given: the caller is responsible for pointing
+    /// it at the right [`ws_url`](KalshiEnvironment::ws_url). The connection
+    /// is direct; tunnel through a proxy with [`connect_via`](Self::connect_via).
+    pub async fn connect_with_request(req: impl IntoClientRequest) -> Result<Self, KalshiError> {
+        Self::connect_request(req, None, &WsProxy::Direct).await
+    }
 
-        let (ws_stream, _resp) = connect_async(req)
-            .await
+    /// Like [`connect_with_request`](Self::connect_with_request), adding the
+    /// `KALSHI-ACCESS-*` signing headers. The signature covers [`WS_PATH`],
+    /// so the request must use that path.
+    ///
+    /// **Requires auth.**
+    pub async fn connect_with_request_authenticated(
+        req: impl IntoClientRequest,
+        auth: KalshiAuth,
+    ) -> Result<Self, KalshiError> {
+        Self::connect_request(req, Some(auth), &WsProxy::Direct).await
+    }
+
+    async fn connect_request(
+        req: impl IntoClientRequest,
+        auth: Option<KalshiAuth>,
+        proxy: &WsProxy,
+    ) -> Result<Self, KalshiError> {
+        let mut req: Request<()> = req
+            .into_client_request()
             .map_err(|e| KalshiError::Ws(e.to_string()))?;
 
+        let authenticated = auth.is_some();
+        if let Some(auth) = auth {
+            // WS signing: timestamp + "GET" + "/trade-api/ws/v2"
+            let headers = auth.build_headers("GET", WS_PATH)?;
+
+            req.headers_mut().insert(
+                "KALSHI-ACCESS-KEY",
+                HeaderValue::from_str(&headers.key)
+                    .map_err(|e| KalshiError::Header(e.to_string()))?,
+            );
+            req.headers_mut().insert(
+                "KALSHI-ACCESS-SIGNATURE",
+                HeaderValue::from_str(&headers.signature)
+                    .map_err(|e| KalshiError::Header(e.to_string()))?,
+            );
+            req.headers_mut().insert(
+                "KALSHI-ACCESS-TIMESTAMP",
+                HeaderValue::from_str(&headers.timestamp_ms)
+                    .map_err(|e| KalshiError::Header(e.to_string()))?,
+            );
+        }
+
+        let host = req.uri().host().unwrap_or_default().to_string();
+        let secure = req.uri().scheme_str() == Some("wss");
+        let ws_stream = match proxy.resolve(secure, &host)? {
+            Some(proxy_url) => {
+                let port = req
+                    .uri()
+                    .port_u16()
+                    .unwrap_or(if secure { 443 } else { 80 });
+                let host = host.trim_start_matches('[').trim_end_matches(']');
+                let stream = proxy::tunnel(&proxy_url, host, port).await?;
+                client_async_tls_with_config(req, stream, None, None)
+                    .await
+                    .map_err(|e| KalshiError::Ws(e.to_string()))?
+                    .0
+            }
+            None => {
+                connect_async(req)
+                    .await
+                    .map_err(|e| KalshiError::Ws(e.to_string()))?
+                    .0
+            }
+        };
+
         let (write, read) = ws_stream.split();
         Ok(Self {
             write,
             read,
             next_id: 1,
-            authenticated: true,
+            authenticated,
         })
     }
 
@@ -361,9 +1023,7 @@ impl KalshiWsLowLevelClient {
                     self.send_raw(Message::Pong(payload)).await?;
                 }
                 Message::Pong(_) => {}
-                Message::Close(_) => {
-                    return Err(KalshiError::Ws("websocket closed".to_string()));
-                }
+                Message::Close(frame) => return Err(close_error(frame)),
                 _ => {}
             }
         }
@@ -386,6 +1046,7 @@ impl KalshiWsLowLevelClient {
         }
 
         validate_subscription(&params)?;
+        let params = params.normalized();
 
         let id = self.next_id;
         self.next_id += 1;
@@ -501,6 +1162,21 @@ impl KalshiWsLowLevelClient {
     pub async fn close(&mut self) -> Result<(), KalshiError> {
         self.send_raw(Message::Close(None)).await
     }
+
+    /// Send a Close frame, read until the server answers with its own (or
+    /// the stream ends or `limit` passes), then shut the socket down.
+    async fn close_handshake(&mut self, limit: Duration) {
+        let _ = self.send_raw(Message::Close(None)).await;
+        let _ = tokio_timeout(limit, async {
+            while let Some(Ok(msg)) = self.read.next().await {
+                if matches!(msg, Message::Close(_)) {
+                    break;
+                }
+            }
+        })
+        .await;
+        let _ = self.write.close().await;
+    }
 }
 
 /// High-level WebSocket client with automatic reconnection and resubscription.
@@ -543,15 +1219,32 @@ impl KalshiWsLowLevelClient {
 /// ```
 pub struct KalshiWsClient {
     env: KalshiEnvironment,
-    auth: Option<KalshiAuth>,
+    /// Shared with the reader task so [`refresh_auth`](Self::refresh_auth)
+    /// reaches its reconnects.
+    auth: SharedAuth,
+    proxy: WsProxy,
     client: Option<KalshiWsLowLevelClient>,
     config: WsReconnectConfig,
     tracker: Arc<Mutex<SubscriptionTracker>>,
     reader: Option<WsEventReceiver>,
     outgoing: Option<mpsc::Sender<Message>>,
-    shutdown: Option<watch::Sender<bool>>,
+    /// Carries the Close-handshake limit once shutdown is requested.
+    shutdown: Option<watch::Sender<Option<Duration>>>,
     reader_task: Option<JoinHandle<()>>,
     reader_shutdown_timeout: Duration,
+    book_manager: Option<BookManager>,
+    fill_deduper: Option<FillDeduper>,
+    on_disconnect: DisconnectAction,
+    detect_gaps: bool,
+    health: Arc<ConnectionHealth>,
+    /// Events read while waiting for a command reply without a reader.
+    backlog: VecDeque<WsEvent>,
+    /// A message held back to follow the [`WsEvent::SequenceGap`] just returned.
+    deferred: Option<WsEvent>,
+    /// The reconnect attempt announced by the last [`WsEvent::Reconnecting`].
+    pending_reconnect: Option<PendingReconnect>,
+    /// The error that dropped the connection, while reconnecting.
+    reconnect_cause: Option<KalshiError>,
     next_id: u64,
 }
 
@@ -565,20 +1258,7 @@ impl KalshiWsClient {
         env: KalshiEnvironment,
         config: WsReconnectConfig,
     ) -> Result<Self, KalshiError> {
-        let client = KalshiWsLowLevelClient::connect(env.clone()).await?;
-        Ok(Self {
-            env,
-            auth: None,
-            client: Some(client),
-            config,
-            tracker: Arc::new(Mutex::new(SubscriptionTracker::default())),
-            reader: None,
-            outgoing: None,
-            shutdown: None,
-            reader_task: None,
-            reader_shutdown_timeout: Duration::from_secs(5),
-            next_id: 1,
-        })
+        Self::connect_via(env, None, config, WsProxy::Direct).await
     }
 
     /// Connect with auth headers for private channels.
@@ -589,11 +1269,24 @@ impl KalshiWsClient {
         auth: KalshiAuth,
         config: WsReconnectConfig,
     ) -> Result<Self, KalshiError> {
-        let client =
-            KalshiWsLowLevelClient::connect_authenticated(env.clone(), auth.clone()).await?;
+        Self::connect_via(env, Some(auth), config, WsProxy::Direct).await
+    }
+
+    /// Connect through `proxy`, with auth headers when `auth` is given.
+    ///
+    /// Reconnects go through the same proxy. Use [`WsProxy::FromEnv`] to
+    /// follow `HTTPS_PROXY`/`ALL_PROXY` the way reqwest does for REST.
+    pub async fn connect_via(
+        env: KalshiEnvironment,
+        auth: Option<KalshiAuth>,
+        config: WsReconnectConfig,
+        proxy: WsProxy,
+    ) -> Result<Self, KalshiError> {
+        let client = KalshiWsLowLevelClient::connect_via(env.clone(), auth.clone(), &proxy).await?;
         Ok(Self {
             env,
-            auth: Some(auth),
+            auth: Arc::new(std::sync::Mutex::new(auth)),
+            proxy,
             client: Some(client),
             config,
             tracker: Arc::new(Mutex::new(SubscriptionTracker::default())),
@@ -602,6 +1295,15 @@ impl KalshiWsClient {
             shutdown: None,
             reader_task: None,
             reader_shutdown_timeout: Duration::from_secs(5),
+            book_manager: None,
+            fill_deduper: None,
+            on_disconnect: DisconnectAction::Nothing,
+            detect_gaps: false,
+            health: ConnectionHealth::connected(),
+            backlog: VecDeque::new(),
+            deferred: None,
+            pending_reconnect: None,
+            reconnect_cause: None,
             next_id: 1,
         })
     }
@@ -629,16 +1331,23 @@ impl KalshiWsClient {
     /// Subscribe to one or more channels. Returns the command `id`.
     ///
     /// The subscription is tracked internally so it can be resubscribed
-    /// automatically after a reconnect.
+    /// automatically after a reconnect. With a reader running, a subscribe
+    /// issued while the socket is down is sent once, by that resubscribe.
     pub async fn subscribe(&mut self, params: WsSubscriptionParams) -> Result<u64, KalshiError> {
-        let needs_auth = params.channels.iter().any(|c| c.is_private());
-        if needs_auth && self.auth.is_none() {
-            return Err(KalshiError::AuthRequired(
-                "WebSocket private channel subscription",
-            ));
-        }
+        self.subscribe_with_persistence(params, true).await
+    }
 
-        validate_subscription(&params)?;
+    /// Like [`subscribe`](Self::subscribe), but with `persistent: false` the
+    /// subscription is dropped on reconnect instead of being restored, even
+    /// when [`WsReconnectConfig::resubscribe`] is set. Use it for one-shot
+    /// subscriptions such as a snapshot.
+    pub async fn subscribe_with_persistence(
+        &mut self,
+        params: WsSubscriptionParams,
+        persistent: bool,
+    ) -> Result<u64, KalshiError> {
+        self.check_subscription(&params)?;
+        let params = params.normalized();
 
         let id = self.next_id;
         self.next_id = self.next_id.saturating_add(1);
@@ -646,6 +1355,9 @@ impl KalshiWsClient {
         {
             let mut tracker = self.tracker.lock().await;
             tracker.record_subscribe_cmd(id, params.clone());
+            if !persistent {
+                tracker.transient_pending.insert(id);
+            }
         }
 
         let cmd = WsSubscribeCmd {
@@ -659,12 +1371,68 @@ impl KalshiWsClient {
         Ok(id)
     }
 
-    /// Unsubscribe from one or more subscriptions by SID. Returns the command `id`.
-    pub async fn unsubscribe(&mut self, params: WsUnsubscribeParams) -> Result<u64, KalshiError> {
-        if params.sids.is_empty() {
-            return Err(KalshiError::InvalidParams(
-                "unsubscribe: at least one sid is required".to_string(),
-            ));
+    /// Subscribe to several parameter sets, one command each. Returns the
+    /// command ids in the order of `params`.
+    ///
+    /// Every set is validated before anything is sent; the first invalid one
+    /// fails the call with its index in the message. A send error stops the
+    /// batch, leaving the earlier sets subscribed and tracked.
+    pub async fn subscribe_many(
+        &mut self,
+        params: Vec<WsSubscriptionParams>,
+    ) -> Result<Vec<u64>, KalshiError> {
+        for (index, set) in params.iter().enumerate() {
+            self.check_subscription(set).map_err(|err| match err {
+                KalshiError::InvalidParams(msg) => {
+                    KalshiError::InvalidParams(format!("subscribe_many: params[{index}]: {msg}"))
+                }
+                other => other,
+            })?;
+        }
+        let mut ids = Vec::with_capacity(params.len());
+        for set in params {
+            ids.push(self.subscribe(set).await?);
+        }
+        Ok(ids)
+    }
+
+    fn check_subscription(&self, params: &WsSubscriptionParams) -> Result<(), KalshiError> {
+        let needs_auth = params.channels.iter().any(|c| c.is_private());
+        if needs_auth && current_auth(&self.auth).is_none() {
+            return Err(KalshiError::AuthRequired(
+                "WebSocket private channel subscription",
+            ));
+        }
+        validate_subscription(params)
+    }
+
+    /// Subscribe `channels` for every market in the given events. Returns the command `id`.
+    ///
+    /// Only channels where [`WsChannel::supports_event_tickers`] is `true` are accepted.
+    pub async fn subscribe_event_ticker(
+        &mut self,
+        event_tickers: &[impl AsRef<str>],
+        channels: &[WsChannel],
+    ) -> Result<u64, KalshiError> {
+        self.subscribe(WsSubscriptionParams {
+            channels: channels.to_vec(),
+            event_tickers: Some(
+                event_tickers
+                    .iter()
+                    .map(|t| t.as_ref().to_string())
+                    .collect(),
+            ),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Unsubscribe from one or more subscriptions by SID. Returns the command `id`.
+    pub async fn unsubscribe(&mut self, params: WsUnsubscribeParams) -> Result<u64, KalshiError> {
+        if params.sids.is_empty() {
+            return Err(KalshiError::InvalidParams(
+                "unsubscribe: at least one sid is required".to_string(),
+            ));
         }
 
         let id = self.next_id;
@@ -687,6 +1455,37 @@ impl KalshiWsClient {
         Ok(id)
     }
 
+    /// Unsubscribe every active subscription to `channel`, or only those
+    /// covering `market_ticker` if given, and return their sids.
+    ///
+    /// A matching subscription is removed whole, including any other
+    /// markets it covers; use [`update_subscription`](Self::update_subscription)
+    /// with `delete_markets` to drop a single market instead. Fails with
+    /// [`KalshiError::InvalidParams`] if nothing matches.
+    pub async fn unsubscribe_channel(
+        &mut self,
+        channel: WsChannel,
+        market_ticker: Option<&str>,
+    ) -> Result<Vec<u64>, KalshiError> {
+        let sids = self
+            .tracker
+            .lock()
+            .await
+            .matching_sids(channel, market_ticker);
+        if sids.is_empty() {
+            return Err(KalshiError::InvalidParams(format!(
+                "unsubscribe_channel: no active {} subscription{}",
+                channel.as_str(),
+                market_ticker
+                    .map(|ticker| format!(" for {ticker}"))
+                    .unwrap_or_default()
+            )));
+        }
+        self.unsubscribe(WsUnsubscribeParams { sids: sids.clone() })
+            .await?;
+        Ok(sids)
+    }
+
     /// Update an existing subscription (e.g. change market tickers). Returns the command `id`.
     pub async fn update_subscription(
         &mut self,
@@ -694,14 +1493,85 @@ impl KalshiWsClient {
     ) -> Result<u64, KalshiError> {
         validate_update(&params)?;
 
-        let id = self.next_id;
-        self.next_id = self.next_id.saturating_add(1);
-
         {
             let mut tracker = self.tracker.lock().await;
             tracker.apply_update(&params);
         }
 
+        self.send_update(params).await
+    }
+
+    /// Replace the market tickers of subscription `sid` with `tickers`.
+    ///
+    /// The server only accepts `add_markets`/`delete_markets` updates, so
+    /// this sends a `delete_markets` for tickers no longer wanted and an
+    /// `add_markets` for new ones, skipping either if empty. The tracked
+    /// subscription is set to exactly `tickers`, so removed markets stay
+    /// dropped after a reconnect. Returns the ids of the commands sent.
+    ///
+    /// Fails with [`KalshiError::InvalidParams`] if `tickers` is empty or
+    /// `sid` is not an active subscription.
+    pub async fn replace_subscription_markets(
+        &mut self,
+        sid: u64,
+        tickers: &[impl AsRef<str>],
+    ) -> Result<Vec<u64>, KalshiError> {
+        let mut wanted: Vec<String> = Vec::with_capacity(tickers.len());
+        for ticker in tickers {
+            let ticker = ticker.as_ref().to_string();
+            if !wanted.contains(&ticker) {
+                wanted.push(ticker);
+            }
+        }
+        if wanted.is_empty() {
+            return Err(KalshiError::InvalidParams(
+                "replace_subscription_markets: tickers must be non-empty".to_string(),
+            ));
+        }
+
+        let (removed, added) = self
+            .tracker
+            .lock()
+            .await
+            .replace_markets(sid, wanted)
+            .ok_or_else(|| {
+                KalshiError::InvalidParams(format!(
+                    "replace_subscription_markets: unknown sid {sid}"
+                ))
+            })?;
+
+        let mut ids = Vec::new();
+        for (action, tickers) in [
+            (WsUpdateAction::DeleteMarkets, removed),
+            (WsUpdateAction::AddMarkets, added),
+        ] {
+            if tickers.is_empty() {
+                continue;
+            }
+            ids.push(
+                self.send_update(WsUpdateSubscriptionParams {
+                    action,
+                    sid: Some(sid),
+                    sids: None,
+                    market_ticker: None,
+                    market_tickers: Some(tickers),
+                    market_id: None,
+                    market_ids: None,
+                    send_initial_snapshot: None,
+                })
+                .await?,
+            );
+        }
+        Ok(ids)
+    }
+
+    async fn send_update(
+        &mut self,
+        params: WsUpdateSubscriptionParams,
+    ) -> Result<u64, KalshiError> {
+        let id = self.next_id;
+        self.next_id = self.next_id.saturating_add(1);
+
         let cmd = WsUpdateSubscriptionCmd {
             id,
             cmd: "update_subscription",
@@ -726,9 +1596,240 @@ impl KalshiWsClient {
         Ok(id)
     }
 
+    /// Subscribe and wait for the server's reply, returning the new `sid`.
+    ///
+    /// A rejection comes back as an error instead of a [`WsMessage::Error`]
+    /// on the event stream: [`KalshiError::AuthRequired`] when the server
+    /// refuses a private channel for auth reasons (e.g. a revoked or rotated
+    /// key), otherwise [`KalshiError::Ws`] with the server's code and message.
+    /// Rejected subscriptions are not resubscribed after a reconnect.
+    ///
+    /// Events read while waiting are handled as in
+    /// [`list_subscriptions_await`](Self::list_subscriptions_await); wrap in a
+    /// timeout to bound the wait.
+    pub async fn subscribe_await(
+        &mut self,
+        params: WsSubscriptionParams,
+    ) -> Result<u64, KalshiError> {
+        let private = params.channels.iter().any(|c| c.is_private());
+        let (reply_tx, mut reply_rx) = oneshot::channel();
+        let id = self.next_id;
+        self.tracker
+            .lock()
+            .await
+            .pending_subscribes
+            .insert(id, reply_tx);
+        if let Err(err) = self.subscribe(params).await {
+            self.tracker.lock().await.pending_subscribes.remove(&id);
+            return Err(err);
+        }
+
+        let lost = || KalshiError::Ws("connection lost before subscribe reply".to_string());
+        let reply = if self.reader.is_some() {
+            reply_rx.await.map_err(|_| lost())?
+        } else {
+            loop {
+                if let Ok(reply) = reply_rx.try_recv() {
+                    break reply;
+                }
+                let event = self.read_event().await?;
+                let disconnected = matches!(
+                    event,
+                    WsEvent::Reconnecting { .. }
+                        | WsEvent::Reconnected { .. }
+                        | WsEvent::Disconnected { .. }
+                );
+                self.backlog.push_back(event);
+                if disconnected {
+                    self.tracker.lock().await.pending_subscribes.remove(&id);
+                    return Err(lost());
+                }
+            }
+        };
+
+        reply.map_err(|error| {
+            if private && error.is_auth_rejection() {
+                KalshiError::AuthRequired(
+                    "WebSocket private channel subscription (rejected by server)",
+                )
+            } else {
+                KalshiError::Ws(format!(
+                    "subscribe rejected (code {:?}): {}",
+                    error.code,
+                    error.message.as_deref().unwrap_or("no message")
+                ))
+            }
+        })
+    }
+
+    /// Send `list_subscriptions` and wait for the reply with the matching id.
+    ///
+    /// With a reader running, the reply is also delivered on the event
+    /// stream. Without one, events read while waiting are queued and returned
+    /// by later [`next_event`](Self::next_event) calls. Fails if the
+    /// connection drops before the reply arrives; wrap in a timeout to bound
+    /// the wait.
+    pub async fn list_subscriptions_await(
+        &mut self,
+    ) -> Result<Vec<WsSubscriptionInfo>, KalshiError> {
+        let (reply_tx, mut reply_rx) = oneshot::channel();
+        let id = self.next_id;
+        self.tracker.lock().await.pending_lists.insert(id, reply_tx);
+        if let Err(err) = self.list_subscriptions().await {
+            self.tracker.lock().await.pending_lists.remove(&id);
+            return Err(err);
+        }
+
+        let lost =
+            || KalshiError::Ws("connection lost before list_subscriptions reply".to_string());
+        if self.reader.is_some() {
+            return reply_rx.await.map_err(|_| lost());
+        }
+
+        loop {
+            if let Ok(subscriptions) = reply_rx.try_recv() {
+                return Ok(subscriptions);
+            }
+            let event = self.read_event().await?;
+            let disconnected = matches!(
+                event,
+                WsEvent::Reconnecting { .. }
+                    | WsEvent::Reconnected { .. }
+                    | WsEvent::Disconnected { .. }
+            );
+            self.backlog.push_back(event);
+            if disconnected {
+                self.tracker.lock().await.pending_lists.remove(&id);
+                return Err(lost());
+            }
+        }
+    }
+
+    /// Compare the server's subscriptions (via
+    /// [`list_subscriptions_await`](Self::list_subscriptions_await)) with the
+    /// ones tracked for resubscription. The tracker is not modified.
+    pub async fn reconcile_subscriptions(&mut self) -> Result<WsSubscriptionDrift, KalshiError> {
+        let server = self.list_subscriptions_await().await?;
+        Ok(self.tracker.lock().await.drift(server))
+    }
+
+    /// The subscriptions the client currently tracks as active, by sid.
+    ///
+    /// A local snapshot, with no server round trip. It reflects
+    /// acknowledged subscribes, unsubscribes, and updates, and is what a
+    /// reconnect restores (apart from subscriptions made with
+    /// [`subscribe_with_persistence`](Self::subscribe_with_persistence)`(_, false)`).
+    /// Use [`reconcile_subscriptions`](Self::reconcile_subscriptions) to
+    /// compare it with the server.
+    pub async fn active_subscriptions(&self) -> Vec<(u64, WsSubscriptionParams)> {
+        self.tracker.lock().await.active_snapshot()
+    }
+
+    /// Subscribe `orderbook_delta` for `tickers` with an initial snapshot and
+    /// wait up to `wait` for a snapshot of each, returning the seeded books.
+    ///
+    /// Deltas that arrive while other snapshots are outstanding are applied
+    /// to the returned books; book messages for `tickers` read here are not
+    /// replayed by [`next_event`](Self::next_event), but every other event
+    /// is queued for it. Fails on timeout, on a reconnect or disconnect, and
+    /// while a reader is running (use [`attach_book_manager`](Self::attach_book_manager)
+    /// there instead).
+    ///
+    /// **Requires auth.**
+    pub async fn subscribe_orderbook_ready(
+        &mut self,
+        tickers: &[impl AsRef<str>],
+        wait: Duration,
+    ) -> Result<HashMap<String, OrderBook>, KalshiError> {
+        if self.reader.is_some() {
+            return Err(KalshiError::InvalidParams(
+                "subscribe_orderbook_ready: not available while a reader is running".to_string(),
+            ));
+        }
+        let tickers: Vec<String> = tickers.iter().map(|t| t.as_ref().to_string()).collect();
+        self.subscribe(WsSubscriptionParams {
+            channels: vec![WsChannel::OrderbookDelta],
+            market_tickers: Some(tickers.clone()),
+            send_initial_snapshot: Some(true),
+            ..Default::default()
+        })
+        .await?;
+
+        let deadline = Instant::now() + wait;
+        let mut books = BookManager::new();
+        while tickers.iter().any(|t| books.book(t).is_none()) {
+            let event = tokio_timeout(
+                deadline.saturating_duration_since(Instant::now()),
+                self.read_event(),
+            )
+            .await
+            .map_err(|_| {
+                KalshiError::Ws(format!(
+                    "subscribe_orderbook_ready: timed out after {wait:?} waiting for snapshots"
+                ))
+            })??;
+
+            let ticker = match &event {
+                WsEvent::Message(WsMessage::Data(WsDataMessage::OrderbookSnapshot {
+                    msg, ..
+                })) => Some(&msg.market_ticker),
+                WsEvent::Message(WsMessage::Data(WsDataMessage::OrderbookDelta {
+                    msg, ..
+                })) => Some(&msg.market_ticker),
+                _ => None,
+            };
+            if ticker.is_some_and(|t| tickers.contains(t)) {
+                if let WsEvent::Message(msg) = &event {
+                    books.apply(msg);
+                }
+                continue;
+            }
+
+            let disconnected = matches!(
+                event,
+                WsEvent::Reconnecting { .. }
+                    | WsEvent::Reconnected { .. }
+                    | WsEvent::Disconnected { .. }
+            );
+            self.backlog.push_back(event);
+            if disconnected {
+                return Err(KalshiError::Ws(
+                    "subscribe_orderbook_ready: connection lost before all snapshots arrived"
+                        .to_string(),
+                ));
+            }
+        }
+
+        Ok(tickers
+            .into_iter()
+            .filter_map(|t| books.book(&t).cloned().map(|book| (t, book)))
+            .collect())
+    }
+
     pub async fn start_reader(
         &mut self,
         config: WsReaderConfig,
+    ) -> Result<WsEventReceiver, KalshiError> {
+        self.spawn_reader(config, None)
+    }
+
+    /// Like [`start_reader`](Self::start_reader), but spawns the reader task on
+    /// `handle` instead of the ambient runtime.
+    ///
+    /// Use this to pin the reader to a dedicated runtime, or to start it from
+    /// outside any runtime.
+    pub fn start_reader_on(
+        &mut self,
+        handle: Handle,
+        config: WsReaderConfig,
+    ) -> Result<WsEventReceiver, KalshiError> {
+        self.spawn_reader(config, Some(handle))
+    }
+
+    fn spawn_reader(
+        &mut self,
+        config: WsReaderConfig,
+        handle: Option<Handle>,
     ) -> Result<WsEventReceiver, KalshiError> {
         if self.reader.is_some() {
             return Err(KalshiError::InvalidParams(
@@ -740,38 +1841,95 @@ impl KalshiWsClient {
                 "websocket reader buffer_size must be > 0".to_string(),
             ));
         }
+        if config.max_buffered_bytes == Some(0) {
+            return Err(KalshiError::InvalidParams(
+                "websocket reader max_buffered_bytes must be > 0".to_string(),
+            ));
+        }
+        if config.ping_interval.is_some_and(|d| d.is_zero()) || config.pong_timeout.is_zero() {
+            return Err(KalshiError::InvalidParams(
+                "websocket reader ping_interval and pong_timeout must be > 0".to_string(),
+            ));
+        }
+        if config.tap.as_ref().is_some_and(|tap| tap.capacity == 0) {
+            return Err(KalshiError::InvalidParams(
+                "websocket frame tap capacity must be > 0".to_string(),
+            ));
+        }
+        if self.client.is_none() {
+            return Err(KalshiError::Ws(
+                "websocket client not connected".to_string(),
+            ));
+        }
+        let tap = config
+            .tap
+            .as_ref()
+            .map(|tap| tap.start(handle.as_ref()))
+            .transpose()?;
 
         let client = self
             .client
             .take()
             .ok_or_else(|| KalshiError::Ws("websocket client not connected".to_string()))?;
 
-        let (event_tx, event_rx) = mpsc::channel(config.buffer_size);
+        let (tx, event_rx) = mpsc::channel(config.buffer_size);
+        let budget = config
+            .max_buffered_bytes
+            .map(|max| Arc::new(ByteBudget::new(max)));
+        let event_tx = EventSender {
+            tx,
+            budget: budget.clone(),
+        };
         let (outgoing_tx, outgoing_rx) = mpsc::channel(config.buffer_size);
-        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let (shutdown_tx, shutdown_rx) = watch::channel(None);
 
         let tracker = self.tracker.clone();
         let env = self.env.clone();
         let auth = self.auth.clone();
+        let proxy = self.proxy.clone();
         let reconnect_cfg = self.config.clone();
         let mode = config.mode;
-
-        let task = tokio::spawn(async move {
+        let keepalive = config.ping_interval.map(|interval| Keepalive {
+            interval,
+            pong_timeout: config.pong_timeout,
+        });
+        let book_manager = self.book_manager.take();
+        let fill_deduper = self.fill_deduper.take();
+        let on_disconnect = self.on_disconnect.clone();
+        let detect_gaps = self.detect_gaps;
+        let health = self.health.clone();
+        let metrics = config.metrics.clone();
+
+        let reader = async move {
             reader_loop(
                 client,
                 env,
                 auth,
+                proxy,
                 reconnect_cfg,
                 tracker,
                 event_tx,
                 outgoing_rx,
                 shutdown_rx,
                 mode,
+                detect_gaps,
+                keepalive,
+                tap,
+                metrics,
+                book_manager,
+                fill_deduper,
+                on_disconnect,
+                health.clone(),
             )
             .await;
-        });
+            health.set_connected(false);
+        };
+        let task = match handle {
+            Some(handle) => handle.spawn(reader),
+            None => tokio::spawn(reader),
+        };
 
-        let receiver = WsEventReceiver::new(event_rx);
+        let receiver = WsEventReceiver::new(event_rx, budget);
         self.reader = Some(receiver.clone());
         self.outgoing = Some(outgoing_tx);
         self.shutdown = Some(shutdown_tx);
@@ -780,25 +1938,123 @@ impl KalshiWsClient {
         Ok(receiver)
     }
 
-    /// Configure how long [`close`](Self::close) waits for the reader task.
+    /// Maintain local order books from `orderbook_delta` messages.
+    ///
+    /// Call before [`start_reader`](Self::start_reader); the manager is moved
+    /// into the reader task. Books are cleared on reconnect and rebuilt from
+    /// the fresh snapshots sent after resubscribing.
+    pub fn attach_book_manager(&mut self, manager: BookManager) -> &mut Self {
+        self.book_manager = Some(manager);
+        self
+    }
+
+    /// The attached [`BookManager`], unless it has been moved into the reader task.
+    pub fn book_manager(&self) -> Option<&BookManager> {
+        self.book_manager.as_ref()
+    }
+
+    /// Drop fills already seen on the `fill` channel, e.g. ones the server
+    /// resends after a reconnect. See [`FillDeduper`].
+    ///
+    /// Call before [`start_reader`](Self::start_reader); the deduper is moved
+    /// into the reader task. It is kept across reconnects.
+    pub fn attach_fill_deduper(&mut self, deduper: FillDeduper) -> &mut Self {
+        self.fill_deduper = Some(deduper);
+        self
+    }
+
+    /// Run `action` each time the connection drops, before reconnecting.
+    /// See [`DisconnectAction`].
+    ///
+    /// Call before [`start_reader`](Self::start_reader) for it to apply to
+    /// the reader task.
+    pub fn on_disconnect(&mut self, action: DisconnectAction) -> &mut Self {
+        self.on_disconnect = action;
+        self
+    }
+
+    /// Emit [`WsEvent::SequenceGap`] when a data message's `seq` skips ahead
+    /// of the last one on its sid (default off).
+    ///
+    /// Tracking restarts after every reconnect, since the server assigns new
+    /// sids. Call before [`start_reader`](Self::start_reader) for it to apply
+    /// to the reader task.
+    pub fn detect_sequence_gaps(&mut self, enabled: bool) -> &mut Self {
+        self.detect_gaps = enabled;
+        self
+    }
+
+    /// Replace the credentials used to sign the handshake on the next
+    /// reconnect, e.g. to rotate keys without rebuilding the client.
+    ///
+    /// The live socket is not touched: it stays authenticated with the
+    /// headers sent at connect time until it drops. Applies to a running
+    /// reader task as well.
+    pub fn refresh_auth(&mut self, auth: KalshiAuth) -> &mut Self {
+        *self.auth.lock().expect("auth lock") = Some(auth);
+        self
+    }
+
+    /// The attached [`FillDeduper`], unless it has been moved into the reader task.
+    pub fn fill_deduper(&self) -> Option<&FillDeduper> {
+        self.fill_deduper.as_ref()
+    }
+
+    /// Whether the socket is currently up: `false` while reconnecting, after
+    /// [`WsEvent::Disconnected`], and after [`close`](Self::close).
+    pub fn is_connected(&self) -> bool {
+        self.health.connected.load(Ordering::Acquire)
+    }
+
+    /// When the last frame was received from the server. With a background
+    /// reader this includes pings; otherwise it is the last message returned
+    /// by [`next_event`](Self::next_event).
+    pub fn last_event_at(&self) -> Option<Instant> {
+        self.health.last_frame_at()
+    }
+
+    /// `true` if connected and a frame arrived (or the connection was
+    /// established) within `max_idle`.
+    pub fn healthy(&self, max_idle: Duration) -> bool {
+        if !self.is_connected() {
+            return false;
+        }
+        let last_activity = match (self.health.connected_at(), self.health.last_frame_at()) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        last_activity.is_some_and(|at| at.elapsed() <= max_idle)
+    }
+
+    /// Configure how long [`close`](Self::close) waits for the server's Close
+    /// reply, and again for the reader task to exit (default 5 seconds).
     pub fn shutdown_timeout(&mut self, timeout: Duration) -> &mut Self {
         self.reader_shutdown_timeout = timeout;
         self
     }
 
     /// Gracefully close the WebSocket and stop background tasks.
+    ///
+    /// Sends a Close frame and waits up to
+    /// [`shutdown_timeout`](Self::shutdown_timeout) for the server's reply,
+    /// then up to `shutdown_timeout` more for the reader task to exit,
+    /// aborting it if that passes. Dropping the client without calling this
+    /// aborts the reader.
     pub async fn close(&mut self) -> Result<(), KalshiError> {
-        if let Some(sender) = &self.outgoing {
-            let _ = sender.send(Message::Close(None)).await;
-        } else if let Some(client) = &mut self.client {
-            let _ = client.close().await;
+        if self.reader_task.is_none()
+            && let Some(client) = &mut self.client
+        {
+            client.close_handshake(self.reader_shutdown_timeout).await;
         }
 
         self.signal_shutdown();
         self.outgoing = None;
+        self.health.set_connected(false);
 
         if let Some(mut task) = self.reader_task.take() {
-            match tokio_timeout(self.reader_shutdown_timeout, &mut task).await {
+            // The reader spends up to one timeout on the handshake first.
+            let limit = self.reader_shutdown_timeout.saturating_mul(2);
+            match tokio_timeout(limit, &mut task).await {
                 Ok(joined) => {
                     if let Err(err) = joined
                         && !err.is_cancelled()
@@ -811,8 +2067,7 @@ impl KalshiWsClient {
                 Err(_) => {
                     task.abort();
                     return Err(KalshiError::Ws(format!(
-                        "websocket reader shutdown timed out after {:?}",
-                        self.reader_shutdown_timeout
+                        "websocket reader shutdown timed out after {limit:?}",
                     )));
                 }
             }
@@ -833,9 +2088,23 @@ impl KalshiWsClient {
     ///
     /// This is the primary event-loop driver. On connection loss it
     /// automatically attempts reconnection per [`WsReconnectConfig`],
-    /// returning [`WsEvent::Reconnected`] on success or
-    /// [`WsEvent::Disconnected`] when retries are exhausted.
+    /// returning [`WsEvent::Reconnecting`] before each attempt, then
+    /// [`WsEvent::Reconnected`] on success or [`WsEvent::Disconnected`] when
+    /// retries are exhausted.
     pub async fn next_event(&mut self) -> Result<WsEvent, KalshiError> {
+        if let Some(event) = self.backlog.pop_front() {
+            return Ok(event);
+        }
+        self.read_event().await
+    }
+
+    async fn read_event(&mut self) -> Result<WsEvent, KalshiError> {
+        if let Some(event) = self.deferred.take() {
+            return Ok(event);
+        }
+        if let Some(pending) = self.pending_reconnect {
+            return self.resume_reconnect(pending).await;
+        }
         if let Some(reader) = &self.reader {
             return reader
                 .next()
@@ -843,54 +2112,116 @@ impl KalshiWsClient {
                 .ok_or_else(|| KalshiError::Ws("websocket reader closed".to_string()));
         }
 
-        let client = self
-            .client
-            .as_mut()
-            .ok_or_else(|| KalshiError::Ws("websocket client not connected".to_string()))?;
-
-        match client.next_message().await {
-            Ok(msg) => {
-                let mut tracker = self.tracker.lock().await;
-                tracker.handle_message(&msg);
-                Ok(WsEvent::Message(msg))
+        loop {
+            let client = self
+                .client
+                .as_mut()
+                .ok_or_else(|| KalshiError::Ws("websocket client not connected".to_string()))?;
+
+            match client.next_message().await {
+                Ok(msg) => {
+                    self.health.touch();
+                    let mut tracker = self.tracker.lock().await;
+                    tracker.handle_message(&msg);
+                    let gap = if self.detect_gaps {
+                        message_gap(&mut tracker, &msg)
+                    } else {
+                        None
+                    };
+                    if let Some(books) = &mut self.book_manager {
+                        books.apply(&msg);
+                    }
+                    let event = WsEvent::Message(msg);
+                    let event = match &mut self.fill_deduper {
+                        Some(deduper) => deduper.screen(event, None),
+                        None => Some(event),
+                    };
+                    match (gap, event) {
+                        (Some(gap), event) => {
+                            self.deferred = event;
+                            return Ok(gap);
+                        }
+                        (None, Some(event)) => return Ok(event),
+                        (None, None) => continue,
+                    }
+                }
+                Err(err) => {
+                    self.health.set_connected(false);
+                    {
+                        let mut tracker = self.tracker.lock().await;
+                        tracker.fail_pending_commands();
+                        tracker.reset_sequences();
+                    }
+                    if let Some(books) = &mut self.book_manager {
+                        books.clear();
+                    }
+                    self.on_disconnect.run().await;
+                    return self.reconnect_loop(err).await;
+                }
             }
-            Err(err) => self.reconnect_loop(err).await,
         }
     }
 
-    async fn reconnect_loop(&mut self, mut err: KalshiError) -> Result<WsEvent, KalshiError> {
-        let mut attempt: u32 = 0;
-        loop {
-            attempt = attempt.saturating_add(1);
-            if let Some(max) = self.config.max_retries
-                && attempt > max
-            {
-                return Ok(WsEvent::Disconnected { error: err });
-            }
+    async fn reconnect_loop(&mut self, err: KalshiError) -> Result<WsEvent, KalshiError> {
+        if is_final_close(&err) {
+            return Ok(WsEvent::Disconnected {
+                error: err,
+                cause: None,
+            });
+        }
+        Ok(self.schedule_reconnect(1, err).await)
+    }
 
-            let delay = self.config.backoff_delay(attempt);
-            if !delay.is_zero() {
-                sleep(delay).await;
-            }
+    /// Announce reconnect attempt `attempt`, or give up once retries run out.
+    ///
+    /// `err` is the error that dropped the connection for attempt 1 and the
+    /// previous attempt's failure after that.
+    async fn schedule_reconnect(&mut self, attempt: u32, err: KalshiError) -> WsEvent {
+        if let Some(max) = self.config.max_retries
+            && attempt > max
+        {
+            return WsEvent::Disconnected {
+                error: err,
+                cause: self.reconnect_cause.take(),
+            };
+        }
+        if attempt == 1 {
+            self.reconnect_cause = Some(err);
+        }
+        let delay = self.config.backoff_delay(attempt);
+        self.pending_reconnect = Some(PendingReconnect { attempt, delay });
+        WsEvent::Reconnecting { attempt, delay }
+    }
 
-            match self.reconnect().await {
-                Ok(()) => return Ok(WsEvent::Reconnected { attempt }),
-                Err(e) => {
-                    err = e;
-                    continue;
-                }
+    /// Run the attempt announced by the last [`WsEvent::Reconnecting`].
+    async fn resume_reconnect(
+        &mut self,
+        PendingReconnect { attempt, delay }: PendingReconnect,
+    ) -> Result<WsEvent, KalshiError> {
+        // Cleared only after the wait, so a cancelled call resumes it.
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+        self.pending_reconnect = None;
+        match self.reconnect().await {
+            Ok(()) => {
+                self.health.set_connected(true);
+                self.reconnect_cause = None;
+                Ok(WsEvent::Reconnected { attempt })
             }
+            Err(err) => Ok(self
+                .schedule_reconnect(attempt.saturating_add(1), err)
+                .await),
         }
     }
 
     async fn reconnect(&mut self) -> Result<(), KalshiError> {
-        let new_client = match &self.auth {
-            Some(auth) => {
-                KalshiWsLowLevelClient::connect_authenticated(self.env.clone(), auth.clone())
-                    .await?
-            }
-            None => KalshiWsLowLevelClient::connect(self.env.clone()).await?,
-        };
+        let new_client = KalshiWsLowLevelClient::connect_via(
+            self.env.clone(),
+            current_auth(&self.auth),
+            &self.proxy,
+        )
+        .await?;
         self.client = Some(new_client);
 
         if self.config.resubscribe {
@@ -923,7 +2254,7 @@ impl KalshiWsClient {
 
     fn signal_shutdown(&mut self) {
         if let Some(tx) = &self.shutdown {
-            let _ = tx.send(true);
+            let _ = tx.send(Some(self.reader_shutdown_timeout));
         }
     }
 }
@@ -937,20 +2268,27 @@ impl Drop for KalshiWsClient {
     }
 }
 
+/// The envelope fields raw mode reads from every frame, parsed once without
+/// building the payload.
 #[derive(Debug, Deserialize)]
-#[serde(tag = "type")]
-enum WsControlMessage {
-    #[serde(rename = "subscribed")]
-    Subscribed {
-        id: Option<u64>,
-        sid: Option<u64>,
-        #[serde(default)]
-        msg: Option<WsControlSubscribedMsg>,
-    },
-    #[serde(rename = "unsubscribed")]
-    Unsubscribed { sid: Option<u64> },
-    #[serde(other)]
-    Other,
+struct WsRawEnvelope<'a> {
+    #[serde(rename = "type")]
+    msg_type: WsMsgType,
+    #[serde(default)]
+    id: Option<u64>,
+    #[serde(default)]
+    sid: Option<u64>,
+    #[serde(default)]
+    seq: Option<u64>,
+    #[serde(default, borrow)]
+    msg: Option<&'a RawValue>,
+}
+
+fn message_gap(tracker: &mut SubscriptionTracker, msg: &WsMessage) -> Option<WsEvent> {
+    let WsMessage::Data(data) = msg else {
+        return None;
+    };
+    tracker.observe_seq(data.sid()?, data.seq()?)
 }
 
 #[derive(Debug, Deserialize)]
@@ -961,32 +2299,74 @@ struct WsControlSubscribedMsg {
     sid: Option<u64>,
 }
 
+async fn sleep_until_keepalive(
+    keepalive: Option<Keepalive>,
+    last_frame: Instant,
+    ping_sent: Option<Instant>,
+) {
+    match keepalive {
+        Some(keepalive) => {
+            tokio::time::sleep_until(keepalive.deadline(last_frame, ping_sent)).await
+        }
+        None => std::future::pending().await,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingReconnect {
+    attempt: u32,
+    delay: Duration,
+}
+
 async fn reader_loop(
     mut client: KalshiWsLowLevelClient,
     env: KalshiEnvironment,
-    auth: Option<KalshiAuth>,
+    auth: SharedAuth,
+    proxy: WsProxy,
     config: WsReconnectConfig,
     tracker: Arc<Mutex<SubscriptionTracker>>,
-    event_tx: mpsc::Sender<WsEvent>,
+    event_tx: EventSender,
     mut outgoing_rx: mpsc::Receiver<Message>,
-    mut shutdown_rx: watch::Receiver<bool>,
+    mut shutdown_rx: watch::Receiver<Option<Duration>>,
     mode: WsReaderMode,
+    detect_gaps: bool,
+    keepalive: Option<Keepalive>,
+    mut tap: Option<TapSender>,
+    metrics: Option<Arc<dyn WsMetrics>>,
+    mut book_manager: Option<BookManager>,
+    mut fill_deduper: Option<FillDeduper>,
+    on_disconnect: DisconnectAction,
+    health: Arc<ConnectionHealth>,
 ) {
     let mut outgoing_closed = false;
+    // Only read by the keepalive timer, so only refreshed when it is on.
+    let mut last_frame = Instant::now();
+    let mut ping_sent: Option<Instant> = None;
 
     loop {
-        if *shutdown_rx.borrow() {
+        let requested = *shutdown_rx.borrow();
+        if let Some(limit) = requested {
+            client.close_handshake(limit).await;
             return;
         }
 
         let result: Result<(), KalshiError> = tokio::select! {
             shutdown = shutdown_rx.changed() => {
                 let _ = shutdown;
+                let limit = shutdown_rx.borrow().unwrap_or_default();
+                client.close_handshake(limit).await;
                 return;
             }
             frame = client.next_frame() => {
                 match frame {
-                    Ok(msg) => handle_incoming_message(msg, &mut client, &tracker, &event_tx, mode).await,
+                    Ok(msg) => {
+                        health.touch();
+                        if keepalive.is_some() {
+                            last_frame = Instant::now();
+                            ping_sent = None;
+                        }
+                        handle_incoming_message(msg, &mut client, &tracker, &event_tx, mode, detect_gaps, &mut tap, metrics.as_deref(), &mut book_manager, &mut fill_deduper).await
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -999,26 +2379,67 @@ async fn reader_loop(
                     }
                 }
             }
+            _ = sleep_until_keepalive(keepalive, last_frame, ping_sent), if keepalive.is_some() => {
+                match ping_sent {
+                    Some(at) => Err(KalshiError::Ws(format!(
+                        "no frame within {:?} of keepalive ping",
+                        at.elapsed()
+                    ))),
+                    None => {
+                        ping_sent = Some(Instant::now());
+                        client.send_raw(Message::Ping(Vec::new())).await
+                    }
+                }
+            }
         };
 
-        if let Err(_err) = result {
+        if let Err(err) = result {
+            health.set_connected(false);
+            {
+                let mut tracker = tracker.lock().await;
+                tracker.fail_pending_commands();
+                tracker.reset_sequences();
+            }
+            if let Some(books) = &mut book_manager {
+                books.clear();
+            }
+            on_disconnect.run().await;
+            if is_final_close(&err) {
+                let _ = event_tx
+                    .send(WsEvent::Disconnected {
+                        error: err,
+                        cause: None,
+                    })
+                    .await;
+                return;
+            }
             match handle_reconnect(
+                err,
                 &mut client,
                 &env,
                 &auth,
+                &proxy,
                 &config,
                 &tracker,
                 &event_tx,
+                &mut outgoing_rx,
                 &mut shutdown_rx,
+                &health,
+                metrics.as_deref(),
             )
             .await
             {
-                Ok(()) => {}
-                Err(err) => {
-                    if *shutdown_rx.borrow() {
+                Ok(()) => {
+                    if keepalive.is_some() {
+                        last_frame = Instant::now();
+                        ping_sent = None;
+                    }
+                }
+                Err(disconnected) => {
+                    if shutdown_rx.borrow().is_some() {
                         return;
                     }
-                    let _ = event_tx.send(WsEvent::Disconnected { error: err }).await;
+                    let _ = event_tx.send(disconnected).await;
                     return;
                 }
             }
@@ -1026,12 +2447,36 @@ async fn reader_loop(
     }
 }
 
+/// A server close that reconnecting won't fix, e.g. policy (1008) or an
+/// auth-rejected close.
+fn is_final_close(err: &KalshiError) -> bool {
+    matches!(err, KalshiError::WsClosed { .. }) && !err.is_retryable()
+}
+
+fn close_error(frame: Option<CloseFrame<'_>>) -> KalshiError {
+    match frame {
+        Some(frame) => KalshiError::WsClosed {
+            code: Some(u16::from(frame.code)),
+            reason: frame.reason.into_owned(),
+        },
+        None => KalshiError::WsClosed {
+            code: None,
+            reason: String::new(),
+        },
+    }
+}
+
 async fn handle_incoming_message(
     msg: Message,
     client: &mut KalshiWsLowLevelClient,
     tracker: &Arc<Mutex<SubscriptionTracker>>,
-    event_tx: &mpsc::Sender<WsEvent>,
+    event_tx: &EventSender,
     mode: WsReaderMode,
+    detect_gaps: bool,
+    tap: &mut Option<TapSender>,
+    metrics: Option<&dyn WsMetrics>,
+    book_manager: &mut Option<BookManager>,
+    fill_deduper: &mut Option<FillDeduper>,
 ) -> Result<(), KalshiError> {
     match msg {
         Message::Ping(payload) => {
@@ -1039,70 +2484,194 @@ async fn handle_incoming_message(
             Ok(())
         }
         Message::Pong(_) => Ok(()),
-        Message::Close(_) => Err(KalshiError::Ws("websocket closed".to_string())),
-        Message::Text(text) => handle_payload(Bytes::from(text), tracker, event_tx, mode).await,
-        Message::Binary(data) => handle_payload(Bytes::from(data), tracker, event_tx, mode).await,
-        _ => Ok(()),
-    }
+        Message::Close(frame) => Err(close_error(frame)),
+        Message::Text(text) => {
+            handle_payload(
+                Bytes::from(text),
+                tracker,
+                event_tx,
+                mode,
+                detect_gaps,
+                tap,
+                metrics,
+                book_manager,
+                fill_deduper,
+            )
+            .await
+        }
+        Message::Binary(data) => {
+            handle_payload(
+                Bytes::from(data),
+                tracker,
+                event_tx,
+                mode,
+                detect_gaps,
+                tap,
+                metrics,
+                book_manager,
+                fill_deduper,
+            )
+            .await
+        }
+        _ => Ok(()),
+    }
 }
 
 async fn handle_payload(
     bytes: Bytes,
     tracker: &Arc<Mutex<SubscriptionTracker>>,
-    event_tx: &mpsc::Sender<WsEvent>,
+    event_tx: &EventSender,
     mode: WsReaderMode,
+    detect_gaps: bool,
+    tap: &mut Option<TapSender>,
+    metrics: Option<&dyn WsMetrics>,
+    book_manager: &mut Option<BookManager>,
+    fill_deduper: &mut Option<FillDeduper>,
 ) -> Result<(), KalshiError> {
-    match mode {
+    let frame_len = bytes.len();
+    if let Some(warning) = tap.as_mut().and_then(|tap| tap.offer(bytes.clone())) {
+        event_tx.send(warning).await?;
+    }
+    let mut gap = None;
+    let (event, raw_type) = match mode {
         WsReaderMode::Owned => {
-            let msg = WsMessage::from_bytes(&bytes)?;
+            let msg = WsMessage::from_bytes(&bytes).inspect_err(|_| {
+                if let Some(metrics) = metrics {
+                    metrics.on_parse_error();
+                }
+            })?;
+            if let Some(metrics) = metrics {
+                metrics.on_message(&msg.msg_type());
+            }
             {
                 let mut tracker = tracker.lock().await;
                 tracker.handle_message(&msg);
+                if detect_gaps {
+                    gap = message_gap(&mut tracker, &msg);
+                }
             }
-            event_tx
-                .send(WsEvent::Message(msg))
-                .await
-                .map_err(|_| KalshiError::Ws("websocket reader closed".to_string()))?;
+            if let Some(books) = book_manager {
+                books.apply(&msg);
+            }
+            (WsEvent::Message(msg), None)
         }
         WsReaderMode::Raw => {
-            if let Ok(control) = serde_json::from_slice::<WsControlMessage>(&bytes) {
+            let envelope = serde_json::from_slice::<WsRawEnvelope>(&bytes).ok();
+            if let Some(metrics) = metrics {
+                match &envelope {
+                    Some(envelope) => metrics.on_message(&envelope.msg_type),
+                    None => metrics.on_parse_error(),
+                }
+            }
+            // Only control frames and sequenced frames (when tracking gaps)
+            // touch the tracker, so data frames skip the lock.
+            let seq = envelope
+                .as_ref()
+                .filter(|_| detect_gaps)
+                .and_then(|envelope| envelope.sid.zip(envelope.seq));
+            let control = envelope.as_ref().is_some_and(|envelope| {
+                matches!(
+                    envelope.msg_type,
+                    WsMsgType::Subscribed
+                        | WsMsgType::Unsubscribed
+                        | WsMsgType::Ok
+                        | WsMsgType::ListSubscriptions
+                        | WsMsgType::Error
+                )
+            });
+            if let Some(envelope) = envelope.as_ref().filter(|_| control || seq.is_some()) {
                 let mut tracker = tracker.lock().await;
-                match control {
-                    WsControlMessage::Subscribed { id, sid, msg } => {
-                        tracker
-                            .handle_subscribed(id, sid.or_else(|| msg.and_then(|value| value.sid)));
+                match envelope.msg_type {
+                    WsMsgType::Subscribed => {
+                        let sid = envelope.sid.or_else(|| {
+                            serde_json::from_str::<WsControlSubscribedMsg>(envelope.msg?.get())
+                                .ok()?
+                                .sid
+                        });
+                        tracker.handle_subscribed(envelope.id, sid);
+                    }
+                    WsMsgType::Unsubscribed => tracker.handle_unsubscribed(envelope.sid),
+                    // Every error reply to a command must reach the tracker so a
+                    // rejected subscribe is not replayed, even with no waiter.
+                    WsMsgType::Error if envelope.id.is_some() => {
+                        if let Ok(msg) = WsMessage::from_bytes(&bytes) {
+                            tracker.handle_message(&msg);
+                        }
                     }
-                    WsControlMessage::Unsubscribed { sid } => {
-                        tracker.handle_unsubscribed(sid);
+                    WsMsgType::Ok | WsMsgType::ListSubscriptions | WsMsgType::Error
+                        if !(tracker.pending_lists.is_empty()
+                            && tracker.pending_subscribes.is_empty()) =>
+                    {
+                        if let Ok(msg) = WsMessage::from_bytes(&bytes) {
+                            tracker.handle_message(&msg);
+                        }
                     }
-                    WsControlMessage::Other => {}
+                    _ => {}
+                }
+                if let Some((sid, seq)) = seq {
+                    gap = tracker.observe_seq(sid, seq);
                 }
             }
+            let raw_type = envelope.map(|envelope| envelope.msg_type);
+
+            if let Some(books) = book_manager
+                && matches!(
+                    raw_type,
+                    Some(WsMsgType::OrderbookSnapshot | WsMsgType::OrderbookDelta)
+                )
+                && let Ok(msg) = WsMessage::from_bytes(&bytes)
+            {
+                books.apply(&msg);
+            }
 
-            event_tx
-                .send(WsEvent::Raw(WsRawEvent::new(bytes)))
-                .await
-                .map_err(|_| KalshiError::Ws("websocket reader closed".to_string()))?;
+            (WsEvent::Raw(WsRawEvent::new(bytes)), raw_type)
         }
+    };
+
+    if let Some(gap) = gap {
+        event_tx.send_sized(gap, 0).await?;
+    }
+    let event = match fill_deduper {
+        Some(deduper) => deduper.screen(event, raw_type.as_ref()),
+        None => Some(event),
+    };
+    if let Some(event) = event {
+        event_tx.send_sized(event, frame_len).await?;
     }
 
     Ok(())
 }
 
 async fn handle_reconnect(
+    cause: KalshiError,
     client: &mut KalshiWsLowLevelClient,
     env: &KalshiEnvironment,
-    auth: &Option<KalshiAuth>,
+    auth: &SharedAuth,
+    proxy: &WsProxy,
     config: &WsReconnectConfig,
     tracker: &Arc<Mutex<SubscriptionTracker>>,
-    event_tx: &mpsc::Sender<WsEvent>,
-    shutdown_rx: &mut watch::Receiver<bool>,
-) -> Result<(), KalshiError> {
+    event_tx: &EventSender,
+    outgoing_rx: &mut mpsc::Receiver<Message>,
+    shutdown_rx: &mut watch::Receiver<Option<Duration>>,
+    health: &ConnectionHealth,
+    metrics: Option<&dyn WsMetrics>,
+) -> Result<(), WsEvent> {
     let mut attempt: u32 = 0;
-    let mut last_err = KalshiError::Ws("websocket disconnected".to_string());
+    let mut last_err: Option<KalshiError> = None;
+    // The `Disconnected` event to emit on giving up.
+    let disconnected = |cause: KalshiError, last_err: Option<KalshiError>| match last_err {
+        Some(error) => WsEvent::Disconnected {
+            error,
+            cause: Some(cause),
+        },
+        None => WsEvent::Disconnected {
+            error: cause,
+            cause: None,
+        },
+    };
 
     loop {
-        if *shutdown_rx.borrow() {
+        if shutdown_rx.borrow().is_some() {
             return Ok(());
         }
 
@@ -1110,10 +2679,23 @@ async fn handle_reconnect(
         if let Some(max) = config.max_retries
             && attempt > max
         {
-            return Err(last_err);
+            return Err(disconnected(cause, last_err));
         }
 
         let delay = config.backoff_delay(attempt);
+        // The consumer may be slow or gone; don't let announcing the attempt
+        // hold up shutdown, and stop reconnecting once nobody is listening.
+        tokio::select! {
+            sent = event_tx.send(WsEvent::Reconnecting { attempt, delay }) => {
+                if let Err(err) = sent {
+                    return Err(disconnected(cause, Some(err)));
+                }
+            }
+            changed = shutdown_rx.changed() => {
+                let _ = changed;
+                return Ok(());
+            }
+        }
         if !delay.is_zero() {
             tokio::select! {
                 _ = sleep(delay) => {}
@@ -1124,14 +2706,11 @@ async fn handle_reconnect(
             }
         }
 
-        let reconnect_future = async {
-            match auth {
-                Some(auth) => {
-                    KalshiWsLowLevelClient::connect_authenticated(env.clone(), auth.clone()).await
-                }
-                None => KalshiWsLowLevelClient::connect(env.clone()).await,
-            }
-        };
+        if let Some(metrics) = metrics {
+            metrics.on_reconnect(attempt);
+        }
+        let reconnect_future =
+            KalshiWsLowLevelClient::connect_via(env.clone(), current_auth(auth), proxy);
         let reconnect = tokio::select! {
             result = reconnect_future => result,
             changed = shutdown_rx.changed() => {
@@ -1146,6 +2725,11 @@ async fn handle_reconnect(
                 if config.resubscribe {
                     let params = {
                         let mut tracker = tracker.lock().await;
+                        // Commands queued for the old socket are already
+                        // reflected in the tracker; replay them from there
+                        // rather than sending them again afterwards.
+                        while outgoing_rx.try_recv().is_ok() {}
+                        tracker.fail_pending_commands();
                         tracker.prepare_resubscribe()
                     };
                     let mut resubscribe_err: Option<KalshiError> = None;
@@ -1162,19 +2746,20 @@ async fn handle_reconnect(
                         }
                     }
                     if let Some(err) = resubscribe_err {
-                        last_err = err;
+                        last_err = Some(err);
                         continue;
                     }
                 }
 
-                if *shutdown_rx.borrow() {
+                if shutdown_rx.borrow().is_some() {
                     return Ok(());
                 }
+                health.set_connected(true);
                 let _ = event_tx.send(WsEvent::Reconnected { attempt }).await;
                 return Ok(());
             }
             Err(err) => {
-                last_err = err;
+                last_err = Some(err);
                 continue;
             }
         }
@@ -1185,7 +2770,6 @@ async fn handle_reconnect(
 mod tests {
     use super::*;
     use crate::KalshiEnvironment;
-    use crate::ws::types::WsChannel;
     use serde_json::{Value, json};
     use tokio::net::TcpListener;
     use tokio::time::{Duration, Instant, timeout};
@@ -1244,6 +2828,87 @@ mod tests {
         assert!(tracker.active.is_empty());
     }
 
+    #[test]
+    fn subscription_tracker_prepare_resubscribe_skips_transient() {
+        let mut tracker = SubscriptionTracker::default();
+        let ticker = WsSubscriptionParams {
+            channels: vec![WsChannel::Ticker],
+            ..Default::default()
+        };
+        let snapshot = WsSubscriptionParams {
+            channels: vec![WsChannel::OrderbookDelta],
+            market_tickers: Some(vec!["MKT-1".to_string()]),
+            ..Default::default()
+        };
+        tracker.record_subscribe_cmd(1, ticker.clone());
+        tracker.record_subscribe_cmd(2, snapshot.clone());
+        tracker.transient_pending.insert(2);
+        tracker.record_subscribe_cmd(3, snapshot.clone());
+        tracker.transient_pending.insert(3);
+        tracker.handle_message(&WsMessage::Subscribed {
+            id: Some(1),
+            sid: Some(10),
+        });
+        tracker.handle_message(&WsMessage::Subscribed {
+            id: Some(2),
+            sid: Some(11),
+        });
+        assert!(tracker.transient_active.contains(&11));
+
+        assert_eq!(tracker.prepare_resubscribe(), [ticker]);
+        assert!(tracker.transient_pending.is_empty());
+        assert!(tracker.transient_active.is_empty());
+    }
+
+    #[test]
+    fn subscription_tracker_matches_sids_by_channel_and_market() {
+        let mut tracker = SubscriptionTracker::default();
+        let subs = [
+            (10, WsChannel::Ticker, None, None),
+            (11, WsChannel::Ticker, Some("MKT-1"), None),
+            (
+                12,
+                WsChannel::OrderbookDelta,
+                None,
+                Some(vec!["MKT-1", "MKT-2"]),
+            ),
+            (13, WsChannel::Trade, None, Some(vec!["MKT-2"])),
+        ];
+        for (sid, channel, market_ticker, market_tickers) in subs {
+            tracker.active.insert(
+                sid,
+                WsSubscriptionParams {
+                    channels: vec![channel],
+                    market_ticker: market_ticker.map(str::to_string),
+                    market_tickers: market_tickers
+                        .map(|tickers| tickers.into_iter().map(str::to_string).collect()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        assert_eq!(tracker.matching_sids(WsChannel::Ticker, None), [10, 11]);
+        assert_eq!(
+            tracker.matching_sids(WsChannel::Ticker, Some("MKT-1")),
+            [11]
+        );
+        assert_eq!(
+            tracker.matching_sids(WsChannel::OrderbookDelta, Some("MKT-2")),
+            [12]
+        );
+        assert!(
+            tracker
+                .matching_sids(WsChannel::Trade, Some("MKT-1"))
+                .is_empty()
+        );
+        assert!(tracker.matching_sids(WsChannel::Fill, None).is_empty());
+
+        let snapshot = tracker.active_snapshot();
+        let sids: Vec<u64> = snapshot.iter().map(|(sid, _)| *sid).collect();
+        assert_eq!(sids, [10, 11, 12, 13]);
+        assert_eq!(snapshot[1].1.market_ticker.as_deref(), Some("MKT-1"));
+    }
+
     #[test]
     fn subscription_tracker_apply_update_changes_fields() {
         use crate::ws::types::WsUpdateAction;
@@ -1316,6 +2981,7 @@ mod tests {
             .start_reader(WsReaderConfig {
                 buffer_size: 1,
                 mode: WsReaderMode::Owned,
+                ..Default::default()
             })
             .await
             .expect("start reader");
@@ -1336,142 +3002,1910 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn reader_reconnect_emits_reconnected_event() {
+    async fn reader_byte_budget_holds_back_frames_until_drained() {
         let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
         let addr = listener.local_addr().expect("addr");
+        let frame = |sid: u64| {
+            format!(
+                r#"{{"type":"ticker","sid":{sid},"seq":{sid},"msg":{{"market_ticker":"A","market_id":"1","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}}}"#
+            )
+        };
+        let frame_len = frame(1).len();
 
+        let (done_tx, done_rx) = oneshot::channel::<()>();
         let server = tokio::spawn(async move {
-            let (stream, _) = listener.accept().await.expect("accept 1");
-            let mut ws = accept_async(stream).await.expect("accept ws 1");
-            let msg1 = r#"{"type":"ticker","sid":1,"seq":1,"msg":{"market_ticker":"A","market_id":"1","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}"#;
-            ws.send(Message::Text(msg1.to_string()))
-                .await
-                .expect("send 1");
-            ws.close(None).await.expect("close 1");
-
-            let (stream, _) = listener.accept().await.expect("accept 2");
-            let mut ws = accept_async(stream).await.expect("accept ws 2");
-            let msg2 = r#"{"type":"ticker","sid":2,"seq":2,"msg":{"market_ticker":"B","market_id":"2","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}"#;
-            ws.send(Message::Text(msg2.to_string()))
-                .await
-                .expect("send 2");
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            for sid in 1..=3 {
+                ws.send(Message::Text(frame(sid))).await.expect("send");
+            }
+            let _ = done_rx.await;
         });
 
         let env = KalshiEnvironment {
             rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
             ws_url: format!("ws://{}", addr),
         };
-        let config = WsReconnectConfig {
-            max_retries: Some(3),
-            base_delay: Duration::from_millis(10),
-            max_delay: Duration::from_millis(50),
-            jitter: 0.0,
-            resubscribe: false,
-        };
-        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        let mut client = KalshiWsClient::connect(env, WsReconnectConfig::default())
+            .await
+            .expect("connect");
 
         let receiver = client
             .start_reader(WsReaderConfig {
-                buffer_size: 4,
-                mode: WsReaderMode::Owned,
+                buffer_size: 16,
+                max_buffered_bytes: Some(frame_len + 1),
+                ..Default::default()
             })
             .await
             .expect("start reader");
 
-        let first = timeout(Duration::from_secs(2), receiver.next())
-            .await
-            .expect("timeout 1")
-            .expect("event 1");
-        assert!(matches!(first, WsEvent::Message(_)));
-
-        let reconnect = timeout(Duration::from_secs(2), receiver.next())
-            .await
-            .expect("timeout reconnect")
-            .expect("event reconnect");
-        assert!(matches!(reconnect, WsEvent::Reconnected { .. }));
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(receiver.buffered_bytes(), Some(frame_len));
 
-        let second = timeout(Duration::from_secs(2), receiver.next())
-            .await
-            .expect("timeout 2")
-            .expect("event 2");
-        assert!(matches!(second, WsEvent::Message(_)));
+        for _ in 0..3 {
+            let event = timeout(Duration::from_secs(2), receiver.next())
+                .await
+                .expect("timeout")
+                .expect("event");
+            assert!(matches!(event, WsEvent::Message(_)));
+        }
+        assert_eq!(receiver.buffered_bytes(), Some(0));
 
+        let _ = done_tx.send(());
         server.await.expect("server");
     }
 
     #[tokio::test]
-    async fn low_level_unsubscribe_sends_sids_array() {
+    async fn reader_runs_on_provided_runtime_handle() {
         let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
         let addr = listener.local_addr().expect("addr");
 
         let server = tokio::spawn(async move {
             let (stream, _) = listener.accept().await.expect("accept");
             let mut ws = accept_async(stream).await.expect("accept ws");
-
-            let frame = ws.next().await.expect("frame").expect("ok frame");
-            let text = match frame {
-                Message::Text(text) => text,
-                other => panic!("expected text frame, got {other:?}"),
-            };
-
-            let payload: Value = serde_json::from_str(&text).expect("valid json");
-            assert_eq!(payload["cmd"], json!("unsubscribe"));
-            assert_eq!(payload["params"]["sids"], json!([7, 9]));
+            let msg = r#"{"type":"ticker","sid":1,"seq":1,"msg":{"market_ticker":"A","market_id":"1","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}"#;
+            ws.send(Message::Text(msg.to_string())).await.expect("send");
         });
 
         let env = KalshiEnvironment {
             rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
             ws_url: format!("ws://{}", addr),
         };
-        let mut client = KalshiWsLowLevelClient::connect(env).await.expect("connect");
-        client
-            .unsubscribe(WsUnsubscribeParams { sids: vec![7, 9] })
+        let mut client = KalshiWsClient::connect(env, WsReconnectConfig::default())
             .await
-            .expect("unsubscribe");
+            .expect("connect");
+
+        let dedicated = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("runtime");
+        let receiver = client
+            .start_reader_on(dedicated.handle().clone(), WsReaderConfig::default())
+            .expect("start reader");
+
+        let event = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Message(_)));
 
         server.await.expect("server");
+        drop(client);
+        dedicated.shutdown_background();
     }
 
     #[tokio::test]
-    async fn close_stops_reader_without_waiting_for_reconnect_backoff() {
+    async fn disconnect_action_runs_once_per_drop() {
         let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
         let addr = listener.local_addr().expect("addr");
-
         let server = tokio::spawn(async move {
             let (stream, _) = listener.accept().await.expect("accept");
             let mut ws = accept_async(stream).await.expect("accept ws");
-            let _ = ws.close(None).await;
+            ws.close(None).await.expect("close");
         });
 
+        let mock = crate::rest::MockTransport::new();
+        mock.push_response(reqwest::StatusCode::OK, r#"{"orders":[],"cursor":""}"#);
+        let rest = KalshiRestClient::builder(KalshiEnvironment {
+            rest_origin: Url::parse("http://mock/").expect("url"),
+            ws_url: String::new(),
+        })
+        .with_auth(crate::auth::tests::load_test_auth())
+        .with_transport(mock.clone())
+        .build()
+        .expect("rest");
+
         let env = KalshiEnvironment {
             rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
             ws_url: format!("ws://{}", addr),
         };
         let config = WsReconnectConfig {
             max_retries: None,
-            base_delay: Duration::from_secs(5),
-            max_delay: Duration::from_secs(5),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
             jitter: 0.0,
             resubscribe: false,
+            ..Default::default()
         };
         let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client.on_disconnect(DisconnectAction::CancelAllOrders(rest));
+        server.await.expect("server");
 
-        client
-            .start_reader(WsReaderConfig {
-                buffer_size: 4,
-                mode: WsReaderMode::Owned,
-            })
+        let mut attempts = Vec::new();
+        while attempts.len() < 3 {
+            match timeout(Duration::from_secs(5), client.next_event())
+                .await
+                .expect("event in time")
+                .expect("event")
+            {
+                WsEvent::Reconnecting { attempt, .. } => attempts.push(attempt),
+                other => panic!("expected reconnecting, got {other:?}"),
+            }
+        }
+        client.close().await.expect("close");
+
+        assert_eq!(attempts, [1, 2, 3]);
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url.path(), "/trade-api/v2/portfolio/orders");
+    }
+
+    #[test]
+    fn close_error_keeps_code_and_reason() {
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+        let err = close_error(Some(CloseFrame {
+            code: CloseCode::Policy,
+            reason: "auth expired".into(),
+        }));
+        assert_eq!(err.ws_close_code(), Some(1008));
+        assert!(err.to_string().contains("auth expired"));
+        assert!(!err.is_retryable());
+
+        let err = close_error(Some(CloseFrame {
+            code: CloseCode::Away,
+            reason: "restarting".into(),
+        }));
+        assert!(err.is_retryable());
+
+        let err = close_error(None);
+        assert!(matches!(err, KalshiError::WsClosed { code: None, .. }));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn disconnect_action_cancels_orders_before_disconnected() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let ws_listener = TcpListener::bind("127.0.0.1:0").await.expect("bind ws");
+        let ws_addr = ws_listener.local_addr().expect("ws addr");
+        let ws_server = tokio::spawn(async move {
+            let (stream, _) = ws_listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            ws.close(None).await.expect("close");
+        });
+
+        let http_listener = TcpListener::bind("127.0.0.1:0").await.expect("bind http");
+        let http_addr = http_listener.local_addr().expect("http addr");
+        let http_server = tokio::spawn(async move {
+            let (mut stream, _) = http_listener.accept().await.expect("accept http");
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.expect("read");
+                head.extend_from_slice(&buf[..n]);
+            }
+            let body = r#"{"orders":[],"cursor":""}"#;
+            let reply = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(reply.as_bytes()).await.expect("write");
+            String::from_utf8_lossy(&head).to_string()
+        });
+
+        let rest = KalshiRestClient::builder(KalshiEnvironment {
+            rest_origin: Url::parse(&format!("http://{http_addr}")).expect("url"),
+            ws_url: String::new(),
+        })
+        .with_auth(crate::auth::tests::load_test_auth())
+        .build()
+        .expect("rest");
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", ws_addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(0),
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client.on_disconnect(DisconnectAction::CancelAllOrders(rest));
+
+        let event = timeout(Duration::from_secs(5), client.next_event())
             .await
-            .expect("start reader");
+            .expect("timeout")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Disconnected { .. }));
 
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        client.shutdown_timeout(Duration::from_secs(1));
-        let start = Instant::now();
-        client.close().await.expect("close");
+        let head = http_server.await.expect("http server");
+        assert!(head.starts_with("GET /trade-api/v2/portfolio/orders?status=resting"));
+        ws_server.await.expect("ws server");
+    }
 
-        assert!(start.elapsed() < Duration::from_secs(1));
-        assert!(client.reader_task.is_none());
+    async fn policy_close_server() -> (std::net::SocketAddr, tokio::task::JoinHandle<()>) {
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            ws.close(Some(CloseFrame {
+                code: CloseCode::Policy,
+                reason: "auth expired".into(),
+            }))
+            .await
+            .expect("close");
+            // A reconnect would be accepted here; it must not be attempted.
+            assert!(
+                timeout(Duration::from_millis(200), listener.accept())
+                    .await
+                    .is_err(),
+                "client reconnected after a policy close"
+            );
+        });
+        (addr, server)
+    }
+
+    fn policy_close_env(addr: std::net::SocketAddr) -> KalshiEnvironment {
+        KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        }
+    }
+
+    #[tokio::test]
+    async fn policy_close_disconnects_without_reconnecting() {
+        let (addr, server) = policy_close_server().await;
+        let config = WsReconnectConfig {
+            max_retries: None,
+            base_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(policy_close_env(addr), config)
+            .await
+            .expect("connect");
+
+        let event = timeout(Duration::from_secs(5), client.next_event())
+            .await
+            .expect("event in time")
+            .expect("event");
+        match event {
+            WsEvent::Disconnected { error, cause } => {
+                assert_eq!(error.ws_close_code(), Some(1008));
+                assert!(error.to_string().contains("auth expired"));
+                assert!(cause.is_none());
+            }
+            other => panic!("expected disconnected, got {other:?}"),
+        }
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn reader_policy_close_disconnects_without_reconnecting() {
+        let (addr, server) = policy_close_server().await;
+        let config = WsReconnectConfig {
+            max_retries: None,
+            base_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(policy_close_env(addr), config)
+            .await
+            .expect("connect");
+        let receiver = client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
 
+        let event = timeout(Duration::from_secs(5), receiver.next())
+            .await
+            .expect("event in time")
+            .expect("event");
+        match event {
+            WsEvent::Disconnected { error, cause } => {
+                assert_eq!(error.ws_close_code(), Some(1008));
+                assert!(cause.is_none());
+            }
+            other => panic!("expected disconnected, got {other:?}"),
+        }
         server.await.expect("server");
     }
+
+    #[tokio::test]
+    async fn exhausted_reconnects_keep_the_close_as_cause() {
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            ws.close(Some(CloseFrame {
+                code: CloseCode::Away,
+                reason: "restarting".into(),
+            }))
+            .await
+            .expect("close");
+            // Refuse the reconnect by dropping the listener.
+        });
+
+        let config = WsReconnectConfig {
+            max_retries: Some(1),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(policy_close_env(addr), config)
+            .await
+            .expect("connect");
+        server.await.expect("server");
+        let receiver = client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+
+        loop {
+            let event = timeout(Duration::from_secs(5), receiver.next())
+                .await
+                .expect("event in time")
+                .expect("event");
+            match event {
+                WsEvent::Reconnecting { .. } => {}
+                WsEvent::Disconnected { error, cause } => {
+                    assert!(!matches!(error, KalshiError::WsClosed { .. }));
+                    let cause = cause.expect("cause");
+                    assert_eq!(cause.ws_close_code(), Some(1001));
+                    break;
+                }
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribe_while_down_is_replayed_once_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let (down_tx, down_rx) = oneshot::channel::<()>();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept 1");
+            let mut ws = accept_async(stream).await.expect("accept ws 1");
+            ws.close(None).await.expect("close 1");
+            drop(ws);
+            let _ = down_rx.await;
+
+            let (stream, _) = listener.accept().await.expect("accept 2");
+            let mut ws = accept_async(stream).await.expect("accept ws 2");
+            let mut subscribes = 0;
+            while let Ok(Some(Ok(Message::Text(text)))) =
+                timeout(Duration::from_millis(300), ws.next()).await
+            {
+                let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+                if cmd["cmd"] == "subscribe" {
+                    subscribes += 1;
+                    let reply = json!({"id": cmd["id"], "type": "subscribed", "msg": {"sid": 7}});
+                    ws.send(Message::Text(reply.to_string()))
+                        .await
+                        .expect("reply");
+                }
+            }
+            subscribes
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(5),
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_millis(200),
+            jitter: 0.0,
+            resubscribe: true,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        let receiver = client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while client.is_connected() {
+            assert!(Instant::now() < deadline, "socket never dropped");
+            sleep(Duration::from_millis(5)).await;
+        }
+        client
+            .subscribe(WsSubscriptionParams {
+                channels: vec![WsChannel::Ticker],
+                market_tickers: Some(vec!["A".to_string()]),
+                ..Default::default()
+            })
+            .await
+            .expect("subscribe while down");
+        let _ = down_tx.send(());
+
+        let mut reconnected = false;
+        while let Ok(Some(event)) = timeout(Duration::from_secs(2), receiver.next()).await {
+            match event {
+                WsEvent::Reconnected { .. } => reconnected = true,
+                WsEvent::Message(WsMessage::Subscribed { .. }) => break,
+                _ => {}
+            }
+        }
+        assert!(reconnected);
+        assert_eq!(server.await.expect("server"), 1);
+        assert!(client.tracker.lock().await.active.contains_key(&7));
+    }
+
+    #[tokio::test]
+    async fn replace_subscription_markets_drops_removed_tickers_on_resubscribe() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept 1");
+            let mut ws = accept_async(stream).await.expect("accept ws 1");
+            let mut updates = Vec::new();
+            for _ in 0..2 {
+                let Some(Ok(Message::Text(text))) = ws.next().await else {
+                    panic!("expected update");
+                };
+                let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+                assert_eq!(cmd["cmd"], "update_subscription");
+                updates.push(cmd["params"].clone());
+            }
+            ws.close(None).await.expect("close 1");
+            drop(ws);
+
+            let (stream, _) = listener.accept().await.expect("accept 2");
+            let mut ws = accept_async(stream).await.expect("accept ws 2");
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                panic!("expected resubscribe");
+            };
+            let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+            (updates, cmd["params"]["market_tickers"].clone())
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            resubscribe: true,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client.tracker.lock().await.active.insert(
+            3,
+            WsSubscriptionParams {
+                channels: vec![WsChannel::Ticker],
+                market_tickers: Some(vec!["A".to_string(), "B".to_string()]),
+                ..Default::default()
+            },
+        );
+
+        let err = client
+            .replace_subscription_markets(9, &["A"])
+            .await
+            .expect_err("unknown sid");
+        assert!(matches!(err, KalshiError::InvalidParams(_)));
+
+        let ids = client
+            .replace_subscription_markets(3, &["B", "C"])
+            .await
+            .expect("replace");
+        assert_eq!(ids.len(), 2);
+
+        let event = timeout(Duration::from_secs(2), client.next_event())
+            .await
+            .expect("timeout")
+            .expect("event");
+
+        assert!(
+            matches!(event, WsEvent::Reconnecting { attempt: 1, .. }),
+            "{event:?}"
+        );
+
+        let event = timeout(Duration::from_secs(2), client.next_event())
+            .await
+            .expect("timeout")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Reconnected { .. }));
+
+        let (updates, resubscribed) = server.await.expect("server");
+        assert_eq!(updates[0]["action"], "delete_markets");
+        assert_eq!(updates[0]["market_tickers"], json!(["A"]));
+        assert_eq!(updates[1]["action"], "add_markets");
+        assert_eq!(updates[1]["market_tickers"], json!(["C"]));
+        assert_eq!(resubscribed, json!(["B", "C"]));
+    }
+
+    #[tokio::test]
+    async fn subscribe_many_validates_every_set_before_sending() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let mut cmds = Vec::new();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                cmds.push(serde_json::from_str::<Value>(&text).expect("cmd json"));
+            }
+            cmds
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsClient::connect(env, WsReconnectConfig::default())
+            .await
+            .expect("connect");
+        let ticker = WsSubscriptionParams {
+            channels: vec![WsChannel::Ticker],
+            market_tickers: Some(vec!["A".to_string()]),
+            ..Default::default()
+        };
+        let trades = WsSubscriptionParams {
+            channels: vec![WsChannel::Trade],
+            market_tickers: Some(vec!["B".to_string()]),
+            ..Default::default()
+        };
+
+        let err = client
+            .subscribe_many(vec![ticker.clone(), WsSubscriptionParams::default()])
+            .await
+            .expect_err("second set is invalid");
+        assert!(err.to_string().contains("params[1]"), "{err}");
+        assert!(client.tracker.lock().await.pending.is_empty());
+
+        let ids = client
+            .subscribe_many(vec![ticker, trades])
+            .await
+            .expect("subscribe many");
+        assert_eq!(ids.len(), 2);
+        assert!(ids[0] < ids[1]);
+        assert_eq!(client.tracker.lock().await.pending.len(), 2);
+
+        client.close().await.expect("close");
+        let cmds = server.await.expect("server");
+        let sent: Vec<_> = cmds
+            .iter()
+            .map(|cmd| {
+                (
+                    cmd["id"].as_u64().expect("id"),
+                    cmd["params"]["channels"][0].as_str().expect("channel"),
+                )
+            })
+            .collect();
+        assert_eq!(sent, [(ids[0], "ticker"), (ids[1], "trade")], "{cmds:?}");
+    }
+
+    #[tokio::test]
+    async fn raw_reader_rejected_subscribe_is_not_replayed_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept 1");
+            let mut ws = accept_async(stream).await.expect("accept ws 1");
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                panic!("expected subscribe");
+            };
+            let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+            let reply = json!({
+                "id": cmd["id"],
+                "type": "error",
+                "msg": {"code": 8, "msg": "Unknown channel name"},
+            });
+            ws.send(Message::Text(reply.to_string()))
+                .await
+                .expect("reply");
+            ws.close(None).await.expect("close 1");
+            drop(ws);
+
+            let (stream, _) = listener.accept().await.expect("accept 2");
+            let mut ws = accept_async(stream).await.expect("accept ws 2");
+            let mut subscribes = 0;
+            while let Ok(Some(Ok(Message::Text(text)))) =
+                timeout(Duration::from_millis(300), ws.next()).await
+            {
+                let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+                if cmd["cmd"] == "subscribe" {
+                    subscribes += 1;
+                }
+            }
+            subscribes
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(5),
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(50),
+            jitter: 0.0,
+            resubscribe: true,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client
+            .start_reader(WsReaderConfig {
+                mode: WsReaderMode::Raw,
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+        client
+            .subscribe(WsSubscriptionParams {
+                channels: vec![WsChannel::Ticker],
+                ..Default::default()
+            })
+            .await
+            .expect("subscribe");
+
+        let mut reconnected = false;
+        while let Ok(Ok(event)) = timeout(Duration::from_secs(2), client.next_event()).await {
+            if matches!(event, WsEvent::Reconnected { .. }) {
+                reconnected = true;
+                break;
+            }
+        }
+        assert!(reconnected);
+        assert!(client.tracker.lock().await.pending.is_empty());
+
+        let subscribes = server.await.expect("server");
+        assert_eq!(subscribes, 0);
+        client.close().await.expect("close");
+    }
+
+    #[tokio::test]
+    async fn subscribe_await_maps_auth_rejection_and_returns_sid() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let replies = [
+                json!({"type": "error", "msg": {"code": 9, "msg": "Authentication required"}}),
+                json!({"type": "error", "msg": {"code": 8, "msg": "Unknown channel name"}}),
+                json!({"type": "subscribed", "msg": {"channel": "ticker", "sid": 4}}),
+            ];
+            for mut reply in replies {
+                let Some(Ok(Message::Text(text))) = ws.next().await else {
+                    panic!("expected subscribe");
+                };
+                let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+                reply["id"] = cmd["id"].clone();
+                ws.send(Message::Text(reply.to_string()))
+                    .await
+                    .expect("reply");
+            }
+            let _ = ws.next().await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsClient::connect_authenticated(
+            env,
+            crate::auth::tests::load_test_auth(),
+            WsReconnectConfig::default(),
+        )
+        .await
+        .expect("connect");
+        let params = |channel| WsSubscriptionParams {
+            channels: vec![channel],
+            ..Default::default()
+        };
+
+        let err = timeout(
+            Duration::from_secs(2),
+            client.subscribe_await(params(WsChannel::Fill)),
+        )
+        .await
+        .expect("timeout")
+        .expect_err("auth rejection");
+        assert!(matches!(err, KalshiError::AuthRequired(_)));
+        assert!(client.tracker.lock().await.pending.is_empty());
+
+        let err = timeout(
+            Duration::from_secs(2),
+            client.subscribe_await(params(WsChannel::Ticker)),
+        )
+        .await
+        .expect("timeout")
+        .expect_err("rejection");
+        assert!(err.to_string().contains("Unknown channel name"));
+
+        let sid = timeout(
+            Duration::from_secs(2),
+            client.subscribe_await(params(WsChannel::Ticker)),
+        )
+        .await
+        .expect("timeout")
+        .expect("subscribed");
+        assert_eq!(sid, 4);
+        assert!(client.tracker.lock().await.active.contains_key(&4));
+
+        client.close().await.expect("close");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn subscribe_orderbook_ready_seeds_books_and_applies_early_deltas() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                panic!("expected subscribe");
+            };
+            let cmd: Value = serde_json::from_str(&text).expect("cmd json");
+            assert_eq!(cmd["params"]["send_initial_snapshot"], true);
+
+            let frames = [
+                json!({"id": cmd["id"], "type": "subscribed", "msg": {"sid": 1}}).to_string(),
+                r#"{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{"market_ticker":"A","market_id":"m","yes":[[40,10]],"no":[[50,7]]}}"#.to_string(),
+                r#"{"type":"orderbook_delta","sid":1,"seq":2,"msg":{"market_ticker":"A","market_id":"m","price":41,"price_dollars":"0.41","delta":3,"delta_fp":"3","side":"yes"}}"#.to_string(),
+                r#"{"type":"orderbook_snapshot","sid":1,"seq":3,"msg":{"market_ticker":"B","market_id":"m","yes":[[30,1]],"no":[]}}"#.to_string(),
+            ];
+            for frame in frames {
+                ws.send(Message::Text(frame)).await.expect("send");
+            }
+            // Stay connected through the subscribe for "C" until the client closes.
+            while let Some(Ok(msg)) = ws.next().await {
+                if msg.is_close() {
+                    break;
+                }
+            }
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsClient::connect_authenticated(
+            env,
+            crate::auth::tests::load_test_auth(),
+            WsReconnectConfig::default(),
+        )
+        .await
+        .expect("connect");
+
+        let books = client
+            .subscribe_orderbook_ready(&["A", "B"], Duration::from_secs(2))
+            .await
+            .expect("books");
+        assert_eq!(books.len(), 2);
+        assert_eq!(books["A"].best_yes_bid().map(|(p, _)| p), Some(41));
+        assert_eq!(books["A"].seq, Some(2));
+        assert_eq!(books["B"].best_yes_bid().map(|(p, _)| p), Some(30));
+
+        let queued = client.next_event().await.expect("queued ack");
+        assert!(matches!(
+            queued,
+            WsEvent::Message(WsMessage::Subscribed { .. })
+        ));
+
+        let err = client
+            .subscribe_orderbook_ready(&["C"], Duration::from_millis(50))
+            .await
+            .expect_err("no snapshot for C");
+        assert!(err.to_string().contains("timed out"));
+
+        client.close().await.expect("close");
+        server.await.expect("server");
+    }
+
+    #[test]
+    fn reconnect_backoff_uses_custom_strategy() {
+        let config = WsReconnectConfig {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: 0.0,
+            ..Default::default()
+        };
+        let delays: Vec<_> = (1..=4).map(|n| config.backoff_delay(n)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 300, 300].map(Duration::from_millis).to_vec()
+        );
+
+        #[derive(Debug)]
+        struct Fixed(Duration);
+        impl BackoffStrategy for Fixed {
+            fn delay(&self, _attempt: u32) -> Duration {
+                self.0
+            }
+        }
+        let config = config.with_backoff(Fixed(Duration::from_secs(5)));
+        assert_eq!(config.clone().backoff_delay(1), Duration::from_secs(5));
+        assert_eq!(config.backoff_delay(9), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn reader_reconnect_emits_reconnected_event() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept 1");
+            let mut ws = accept_async(stream).await.expect("accept ws 1");
+            let msg1 = r#"{"type":"ticker","sid":1,"seq":1,"msg":{"market_ticker":"A","market_id":"1","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}"#;
+            ws.send(Message::Text(msg1.to_string()))
+                .await
+                .expect("send 1");
+            ws.close(None).await.expect("close 1");
+
+            let (stream, _) = listener.accept().await.expect("accept 2");
+            let mut ws = accept_async(stream).await.expect("accept ws 2");
+            let msg2 = r#"{"type":"ticker","sid":2,"seq":2,"msg":{"market_ticker":"B","market_id":"2","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}"#;
+            ws.send(Message::Text(msg2.to_string()))
+                .await
+                .expect("send 2");
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        assert!(client.is_connected());
+        assert!(client.last_event_at().is_none());
+        assert!(client.healthy(Duration::from_secs(5)));
+
+        let receiver = client
+            .start_reader(WsReaderConfig {
+                buffer_size: 4,
+                mode: WsReaderMode::Owned,
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+
+        let first = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout 1")
+            .expect("event 1");
+        assert!(matches!(first, WsEvent::Message(_)));
+        assert!(client.last_event_at().is_some());
+
+        let reconnect = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout reconnect")
+            .expect("event reconnect");
+
+        assert!(
+            matches!(reconnect, WsEvent::Reconnecting { attempt: 1, .. }),
+            "{reconnect:?}"
+        );
+
+        let reconnect = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout reconnect")
+            .expect("event reconnect");
+        assert!(matches!(reconnect, WsEvent::Reconnected { .. }));
+
+        let second = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout 2")
+            .expect("event 2");
+        assert!(matches!(second, WsEvent::Message(_)));
+
+        server.await.expect("server");
+        client.close().await.expect("close");
+        assert!(!client.is_connected());
+        assert!(!client.healthy(Duration::from_secs(5)));
+    }
+
+    #[tokio::test]
+    async fn refresh_auth_applies_on_next_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let mut keys = Vec::new();
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().await.expect("accept");
+                let mut key = None;
+                let mut ws = tokio_tungstenite::accept_hdr_async(
+                    stream,
+                    |req: &tokio_tungstenite::tungstenite::handshake::server::Request, resp| {
+                        key = req
+                            .headers()
+                            .get("KALSHI-ACCESS-KEY")
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string);
+                        Ok(resp)
+                    },
+                )
+                .await
+                .expect("accept ws");
+                keys.push(key.expect("key header"));
+                if keys.len() == 1 {
+                    ws.close(None).await.expect("close");
+                } else {
+                    let _ = ws.next().await;
+                }
+            }
+            keys
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let auth = crate::auth::tests::load_test_auth();
+        let mut rotated = auth.clone();
+        rotated.key_id = "rotated-key-id".to_string();
+
+        let mut client = KalshiWsClient::connect_authenticated(env, auth.clone(), config)
+            .await
+            .expect("connect");
+        let receiver = client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+        client.refresh_auth(rotated);
+
+        let event = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout")
+            .expect("event");
+
+        assert!(
+            matches!(event, WsEvent::Reconnecting { attempt: 1, .. }),
+            "{event:?}"
+        );
+
+        let event = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Reconnected { .. }));
+
+        client.close().await.expect("close");
+        let keys = server.await.expect("server");
+        assert_eq!(keys, [auth.key_id.as_str(), "rotated-key-id"]);
+    }
+
+    #[tokio::test]
+    async fn connect_via_http_proxy_tunnels_connect_and_reconnects() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let ws_listener = TcpListener::bind("127.0.0.1:0").await.expect("bind ws");
+        let ws_addr = ws_listener.local_addr().expect("ws addr");
+        let ws_server = tokio::spawn(async move {
+            for attempt in 0..2 {
+                let (stream, _) = ws_listener.accept().await.expect("accept");
+                let mut ws = accept_async(stream).await.expect("accept ws");
+                if attempt == 0 {
+                    ws.close(None).await.expect("close");
+                } else {
+                    let _ = ws.next().await;
+                }
+            }
+        });
+
+        let proxy_listener = TcpListener::bind("127.0.0.1:0").await.expect("bind proxy");
+        let proxy_addr = proxy_listener.local_addr().expect("proxy addr");
+        let proxy_server = tokio::spawn(async move {
+            let mut request_lines = Vec::new();
+            for _ in 0..2 {
+                let (mut inbound, _) = proxy_listener.accept().await.expect("accept");
+                let mut head = Vec::new();
+                while !head.ends_with(b"\r\n\r\n") {
+                    head.push(inbound.read_u8().await.expect("read head"));
+                }
+                let head = String::from_utf8(head).expect("utf8");
+                request_lines.push(head.lines().next().expect("line").to_string());
+                let target = head.split_whitespace().nth(1).expect("target").to_string();
+                let mut outbound = tokio::net::TcpStream::connect(target)
+                    .await
+                    .expect("dial target");
+                inbound
+                    .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                    .await
+                    .expect("write");
+                tokio::spawn(async move {
+                    let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+                });
+            }
+            request_lines
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", ws_addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let proxy = WsProxy::Url(Url::parse(&format!("http://{proxy_addr}")).expect("proxy url"));
+        let mut client = KalshiWsClient::connect_via(env, None, config, proxy)
+            .await
+            .expect("connect");
+        let receiver = client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+
+        let event = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout")
+            .expect("event");
+
+        assert!(
+            matches!(event, WsEvent::Reconnecting { attempt: 1, .. }),
+            "{event:?}"
+        );
+
+        let event = timeout(Duration::from_secs(2), receiver.next())
+            .await
+            .expect("timeout")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Reconnected { .. }));
+
+        client.close().await.expect("close");
+        let lines = proxy_server.await.expect("proxy");
+        let expected = format!("CONNECT {ws_addr} HTTP/1.1");
+        assert_eq!(lines, [expected.as_str(), expected.as_str()]);
+        ws_server.await.expect("ws server");
+    }
+
+    #[tokio::test]
+    async fn events_keep_frame_order_across_reader_modes_and_reconnects() {
+        fn ticker(market: &str) -> String {
+            format!(
+                r#"{{"type":"ticker","sid":1,"msg":{{"market_ticker":"{market}","market_id":"1","price":1,"yes_bid":1,"yes_ask":2,"price_dollars":"0.01","yes_bid_dollars":"0.01","yes_ask_dollars":"0.02","volume":0,"volume_fp":"0","open_interest":0,"open_interest_fp":"0","dollar_volume":0,"dollar_open_interest":0,"ts":0}}}}"#
+            )
+        }
+        fn label(event: &WsEvent) -> String {
+            let frame: Value = match event {
+                WsEvent::Message(msg) => {
+                    serde_json::from_str(&msg.to_wire_json().expect("encode")).expect("json")
+                }
+                WsEvent::Raw(raw) => serde_json::from_slice(raw.bytes()).expect("json"),
+                WsEvent::Reconnecting { attempt, .. } => return format!("reconnecting:{attempt}"),
+                WsEvent::Reconnected { .. } => return "reconnected".to_string(),
+                other => panic!("unexpected event {other:?}"),
+            };
+            match frame["msg"]["market_ticker"].as_str() {
+                Some(market) => format!("{}:{market}", frame["type"].as_str().unwrap_or("?")),
+                None => frame["type"].as_str().unwrap_or("?").to_string(),
+            }
+        }
+
+        for mode in [WsReaderMode::Owned, WsReaderMode::Raw] {
+            let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+            let addr = listener.local_addr().expect("addr");
+            let server = tokio::spawn(async move {
+                let (stream, _) = listener.accept().await.expect("accept 1");
+                let mut ws = accept_async(stream).await.expect("accept ws 1");
+                let subscribed =
+                    json!({"id": 1, "type": "subscribed", "msg": {"channel": "ticker", "sid": 1}});
+                for frame in [
+                    ticker("A"),
+                    subscribed.to_string(),
+                    ticker("B"),
+                    ticker("C"),
+                ] {
+                    ws.send(Message::Text(frame)).await.expect("send 1");
+                }
+                ws.close(None).await.expect("close 1");
+                drop(ws);
+
+                let (stream, _) = listener.accept().await.expect("accept 2");
+                let mut ws = accept_async(stream).await.expect("accept ws 2");
+                ws.send(Message::Text(ticker("D"))).await.expect("send 2");
+                let _ = ws.next().await;
+            });
+
+            let env = KalshiEnvironment {
+                rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+                ws_url: format!("ws://{}", addr),
+            };
+            let config = WsReconnectConfig {
+                max_retries: Some(3),
+                base_delay: Duration::from_millis(10),
+                max_delay: Duration::from_millis(10),
+                jitter: 0.0,
+                resubscribe: false,
+                ..Default::default()
+            };
+            let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+            let receiver = client
+                .start_reader(WsReaderConfig {
+                    buffer_size: 1,
+                    mode,
+                    ..Default::default()
+                })
+                .await
+                .expect("start reader");
+
+            let mut labels = Vec::new();
+            while labels.len() < 7 {
+                let event = timeout(Duration::from_secs(2), receiver.next())
+                    .await
+                    .expect("timeout")
+                    .expect("event");
+                labels.push(label(&event));
+                // Let the reader fill the one-slot buffer before draining.
+                sleep(Duration::from_millis(5)).await;
+            }
+            assert_eq!(
+                labels,
+                [
+                    "ticker:A",
+                    "subscribed",
+                    "ticker:B",
+                    "ticker:C",
+                    "reconnecting:1",
+                    "reconnected",
+                    "ticker:D"
+                ],
+                "{mode:?}"
+            );
+
+            client.close().await.expect("close");
+            server.await.expect("server");
+        }
+    }
+
+    #[tokio::test]
+    async fn sequence_gaps_are_reported_and_reset_on_reconnect() {
+        fn delta(seq: u64) -> String {
+            format!(
+                r#"{{"type":"orderbook_delta","sid":5,"seq":{seq},"msg":{{"market_ticker":"A","market_id":"m","price":40,"price_dollars":"0.40","delta":1,"delta_fp":"1","side":"yes"}}}}"#
+            )
+        }
+        fn label(event: &WsEvent) -> String {
+            let frame: Value = match event {
+                WsEvent::Message(msg) => {
+                    serde_json::from_str(&msg.to_wire_json().expect("encode")).expect("json")
+                }
+                WsEvent::Raw(raw) => serde_json::from_slice(raw.bytes()).expect("json"),
+                WsEvent::Reconnecting { attempt, .. } => return format!("reconnecting:{attempt}"),
+                WsEvent::Reconnected { .. } => return "reconnected".to_string(),
+                WsEvent::SequenceGap { sid, expected, got } => {
+                    return format!("gap:{sid}:{expected}->{got}");
+                }
+                other => panic!("unexpected event {other:?}"),
+            };
+            format!("seq:{}", frame["seq"])
+        }
+
+        for mode in [None, Some(WsReaderMode::Owned), Some(WsReaderMode::Raw)] {
+            let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+            let addr = listener.local_addr().expect("addr");
+            let server = tokio::spawn(async move {
+                let (stream, _) = listener.accept().await.expect("accept 1");
+                let mut ws = accept_async(stream).await.expect("accept ws 1");
+                for seq in [1, 2, 4] {
+                    ws.send(Message::Text(delta(seq))).await.expect("send 1");
+                }
+                ws.close(None).await.expect("close 1");
+                drop(ws);
+
+                let (stream, _) = listener.accept().await.expect("accept 2");
+                let mut ws = accept_async(stream).await.expect("accept ws 2");
+                for seq in [9, 10] {
+                    ws.send(Message::Text(delta(seq))).await.expect("send 2");
+                }
+                let _ = ws.next().await;
+            });
+
+            let env = KalshiEnvironment {
+                rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+                ws_url: format!("ws://{}", addr),
+            };
+            let config = WsReconnectConfig {
+                max_retries: Some(3),
+                base_delay: Duration::from_millis(10),
+                max_delay: Duration::from_millis(10),
+                jitter: 0.0,
+                resubscribe: false,
+                ..Default::default()
+            };
+            let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+            client.detect_sequence_gaps(true);
+            let receiver = match mode {
+                Some(mode) => Some(
+                    client
+                        .start_reader(WsReaderConfig {
+                            mode,
+                            ..Default::default()
+                        })
+                        .await
+                        .expect("start reader"),
+                ),
+                None => None,
+            };
+
+            let mut labels = Vec::new();
+            while labels.len() < 8 {
+                let event = match &receiver {
+                    Some(receiver) => timeout(Duration::from_secs(2), receiver.next())
+                        .await
+                        .expect("timeout")
+                        .expect("event"),
+                    None => timeout(Duration::from_secs(2), client.next_event())
+                        .await
+                        .expect("timeout")
+                        .expect("event"),
+                };
+                labels.push(label(&event));
+            }
+            assert_eq!(
+                labels,
+                [
+                    "seq:1",
+                    "seq:2",
+                    "gap:5:3->4",
+                    "seq:4",
+                    "reconnecting:1",
+                    "reconnected",
+                    "seq:9",
+                    "seq:10"
+                ],
+                "{mode:?}"
+            );
+
+            client.close().await.expect("close");
+            server.await.expect("server");
+        }
+    }
+
+    #[tokio::test]
+    async fn reconcile_subscriptions_correlates_reply_and_queues_other_events() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+
+            let _subscribe = ws.next().await.expect("frame").expect("ok frame");
+            ws.send(Message::Text(
+                r#"{"id":1,"type":"subscribed","msg":{"channel":"trade","sid":1}}"#.to_string(),
+            ))
+            .await
+            .expect("send subscribed");
+
+            let frame = ws.next().await.expect("frame").expect("ok frame");
+            let payload: Value = match frame {
+                Message::Text(text) => serde_json::from_str(&text).expect("valid json"),
+                other => panic!("expected text frame, got {other:?}"),
+            };
+            assert_eq!(payload["cmd"], json!("list_subscriptions"));
+            assert_eq!(payload["id"], json!(2));
+
+            let trade = r#"{"type":"trade","sid":1,"msg":{"trade_id":"t1","ticker":"A"}}"#;
+            ws.send(Message::Text(trade.to_string()))
+                .await
+                .expect("send trade");
+            let reply = r#"{"id":2,"type":"ok","msg":[{"channel":"trade","sid":1},{"channel":"ticker","sid":5}]}"#;
+            ws.send(Message::Text(reply.to_string()))
+                .await
+                .expect("send reply");
+            let _ = ws.next().await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsClient::connect(env, WsReconnectConfig::default())
+            .await
+            .expect("connect");
+        client
+            .subscribe(WsSubscriptionParams {
+                channels: vec![WsChannel::Trade],
+                ..Default::default()
+            })
+            .await
+            .expect("subscribe");
+        let subscribed = client.next_event().await.expect("subscribed");
+        assert!(matches!(
+            subscribed,
+            WsEvent::Message(WsMessage::Subscribed { .. })
+        ));
+
+        let drift = timeout(Duration::from_secs(2), client.reconcile_subscriptions())
+            .await
+            .expect("timeout")
+            .expect("reconcile");
+        assert_eq!(drift.server.len(), 2);
+        assert!(drift.missing_on_server.is_empty());
+        assert_eq!(drift.unknown_to_client, vec![5]);
+
+        let queued = client.next_event().await.expect("queued trade");
+        assert!(matches!(
+            queued,
+            WsEvent::Message(WsMessage::Data(
+                crate::ws::types::WsDataMessage::Trade { .. }
+            ))
+        ));
+        let reply = client.next_event().await.expect("queued reply");
+        assert!(matches!(
+            reply,
+            WsEvent::Message(WsMessage::ListSubscriptions { id: Some(2), .. })
+        ));
+
+        client.close().await.expect("close");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn connect_with_request_keeps_caller_headers_and_signs() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let mut seen = Vec::new();
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().await.expect("accept");
+                let mut headers = None;
+                let mut ws = tokio_tungstenite::accept_hdr_async(
+                    stream,
+                    |req: &tokio_tungstenite::tungstenite::handshake::server::Request, resp| {
+                        let header = |name| {
+                            req.headers()
+                                .get(name)
+                                .and_then(|v| v.to_str().ok())
+                                .map(str::to_string)
+                        };
+                        headers = Some((header("user-agent"), header("KALSHI-ACCESS-KEY")));
+                        Ok(resp)
+                    },
+                )
+                .await
+                .expect("accept ws");
+                seen.push(headers.expect("handshake headers"));
+                let _ = ws.next().await;
+            }
+            seen
+        });
+
+        let request = || {
+            let mut req = format!("ws://{addr}{WS_PATH}")
+                .into_client_request()
+                .expect("request");
+            req.headers_mut()
+                .insert("user-agent", HeaderValue::from_static("my-bot/1.0"));
+            req
+        };
+        let client = KalshiWsLowLevelClient::connect_with_request(request())
+            .await
+            .expect("connect");
+        assert!(!client.authenticated);
+        drop(client);
+
+        let auth = crate::auth::tests::load_test_auth();
+        let client =
+            KalshiWsLowLevelClient::connect_with_request_authenticated(request(), auth.clone())
+                .await
+                .expect("connect authenticated");
+        assert!(client.authenticated);
+        drop(client);
+
+        let seen = server.await.expect("server");
+        let agent = Some("my-bot/1.0".to_string());
+        assert_eq!(
+            seen,
+            [(agent.clone(), None), (agent, Some(auth.key_id.clone()))]
+        );
+    }
+
+    #[tokio::test]
+    async fn low_level_unsubscribe_sends_sids_array() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+
+            let frame = ws.next().await.expect("frame").expect("ok frame");
+            let text = match frame {
+                Message::Text(text) => text,
+                other => panic!("expected text frame, got {other:?}"),
+            };
+
+            let payload: Value = serde_json::from_str(&text).expect("valid json");
+            assert_eq!(payload["cmd"], json!("unsubscribe"));
+            assert_eq!(payload["params"]["sids"], json!([7, 9]));
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsLowLevelClient::connect(env).await.expect("connect");
+        client
+            .unsubscribe(WsUnsubscribeParams { sids: vec![7, 9] })
+            .await
+            .expect("unsubscribe");
+
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn keepalive_pings_idle_connections_and_reconnects_without_pong() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            // First connection: take the Ping but never read again, so no Pong goes out.
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut silent = accept_async(stream).await.expect("accept ws");
+            let first = silent.next().await.expect("frame").expect("frame ok");
+            assert!(matches!(first, Message::Ping(_)), "{first:?}");
+
+            // Second connection: keep reading (tungstenite answers Pings) and count them.
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let mut pings = 0;
+            while pings < 2 {
+                if let Message::Ping(_) = ws.next().await.expect("frame").expect("frame ok") {
+                    pings += 1;
+                }
+            }
+            drop(silent);
+            ws
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(1),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        assert!(matches!(
+            client
+                .start_reader(WsReaderConfig {
+                    ping_interval: Some(Duration::ZERO),
+                    ..Default::default()
+                })
+                .await,
+            Err(KalshiError::InvalidParams(_))
+        ));
+        client
+            .start_reader(WsReaderConfig {
+                ping_interval: Some(Duration::from_millis(100)),
+                pong_timeout: Duration::from_millis(200),
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+
+        let event = timeout(Duration::from_secs(3), client.next_event())
+            .await
+            .expect("reconnect in time")
+            .expect("event");
+
+        assert!(
+            matches!(event, WsEvent::Reconnecting { attempt: 1, .. }),
+            "{event:?}"
+        );
+
+        let event = timeout(Duration::from_secs(3), client.next_event())
+            .await
+            .expect("reconnect in time")
+            .expect("event");
+        assert!(matches!(event, WsEvent::Reconnected { .. }), "{event:?}");
+
+        // Dropping the server side lets close() finish without waiting out the handshake.
+        let ws = timeout(Duration::from_secs(3), server)
+            .await
+            .expect("second connection pinged twice")
+            .expect("server");
+        drop(ws);
+        client.shutdown_timeout(Duration::from_secs(2));
+        client.close().await.expect("close");
+    }
+
+    #[tokio::test]
+    async fn close_completes_the_close_handshake() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let mut saw_close = Vec::new();
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().await.expect("accept");
+                let mut ws = accept_async(stream).await.expect("accept ws");
+                let mut closed = false;
+                while let Some(Ok(msg)) = ws.next().await {
+                    closed |= matches!(msg, Message::Close(_));
+                }
+                saw_close.push(closed);
+            }
+            saw_close
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(0),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+
+        // Without a reader the caller's task runs the handshake.
+        let mut client = KalshiWsClient::connect(env.clone(), config.clone())
+            .await
+            .expect("connect");
+        let start = Instant::now();
+        client.close().await.expect("close");
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        // With a reader, the reader task runs it before exiting.
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+        let start = Instant::now();
+        client.close().await.expect("close");
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert!(client.reader_task.is_none());
+
+        assert_eq!(server.await.expect("server"), vec![true, true]);
+    }
+
+    #[tokio::test]
+    async fn reader_close_handshake_waits_for_shutdown_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        // The server never reads, so the Close reply never comes.
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let ws = accept_async(stream).await.expect("accept ws");
+            let _ = release_rx.await;
+            drop(ws);
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(0),
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client
+            .start_reader(WsReaderConfig::default())
+            .await
+            .expect("start reader");
+
+        client.shutdown_timeout(Duration::from_millis(300));
+        let start = Instant::now();
+        client.close().await.expect("close");
+        let elapsed = start.elapsed();
+
+        assert!(elapsed >= Duration::from_millis(250), "{elapsed:?}");
+        assert!(elapsed < Duration::from_millis(900), "{elapsed:?}");
+        assert!(client.reader_task.is_none());
+
+        let _ = release_tx.send(());
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn close_stops_reader_without_waiting_for_reconnect_backoff() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let _ = ws.close(None).await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: None,
+            base_delay: Duration::from_secs(5),
+            max_delay: Duration::from_secs(5),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+
+        client
+            .start_reader(WsReaderConfig {
+                buffer_size: 4,
+                mode: WsReaderMode::Owned,
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.shutdown_timeout(Duration::from_secs(1));
+        let start = Instant::now();
+        client.close().await.expect("close");
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(client.reader_task.is_none());
+
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn close_is_not_blocked_by_a_full_event_buffer_while_reconnecting() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let trade = r#"{"type":"trade","sid":1,"msg":{"trade_id":"t1","ticker":"A"}}"#;
+            ws.send(Message::Text(trade.to_string()))
+                .await
+                .expect("send");
+            let _ = ws.close(None).await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: None,
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        client
+            .start_reader(WsReaderConfig {
+                buffer_size: 1,
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+
+        // The unread trade fills the buffer, so `Reconnecting` can't be queued.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.shutdown_timeout(Duration::from_secs(5));
+        let start = Instant::now();
+        client.close().await.expect("close");
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(client.reader_task.is_none());
+
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn metrics_count_messages_parse_errors_and_reconnects() {
+        #[derive(Debug, Default)]
+        struct Counts {
+            messages: std::sync::Mutex<Vec<WsMsgType>>,
+            parse_errors: AtomicUsize,
+            reconnects: std::sync::Mutex<Vec<u32>>,
+        }
+        impl WsMetrics for Counts {
+            fn on_message(&self, msg_type: &WsMsgType) {
+                self.messages.lock().unwrap().push(msg_type.clone());
+            }
+            fn on_parse_error(&self) {
+                self.parse_errors.fetch_add(1, Ordering::Relaxed);
+            }
+            fn on_reconnect(&self, attempt: u32) {
+                self.reconnects.lock().unwrap().push(attempt);
+            }
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            let trade = r#"{"type":"trade","sid":1,"msg":{"trade_id":"t1","ticker":"A"}}"#;
+            ws.send(Message::Text(trade.to_string()))
+                .await
+                .expect("send");
+            ws.send(Message::Text("not json".to_string()))
+                .await
+                .expect("send");
+            let (stream, _) = listener.accept().await.expect("accept again");
+            let mut ws = accept_async(stream).await.expect("accept ws again");
+            let _ = ws.next().await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let config = WsReconnectConfig {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            jitter: 0.0,
+            resubscribe: false,
+            ..Default::default()
+        };
+        let mut client = KalshiWsClient::connect(env, config).await.expect("connect");
+        let counts = Arc::new(Counts::default());
+        client
+            .start_reader(WsReaderConfig {
+                metrics: Some(counts.clone()),
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+        for expected in ["message", "reconnecting", "reconnected"] {
+            let event = timeout(Duration::from_secs(2), client.next_event())
+                .await
+                .expect("event in time")
+                .expect("event");
+            match (expected, &event) {
+                ("message", WsEvent::Message(_))
+                | ("reconnecting", WsEvent::Reconnecting { attempt: 1, .. })
+                | ("reconnected", WsEvent::Reconnected { .. }) => {}
+                _ => panic!("expected {expected}, got {event:?}"),
+            }
+        }
+        client.close().await.expect("close");
+        server.await.expect("server");
+
+        assert_eq!(*counts.messages.lock().unwrap(), [WsMsgType::Trade]);
+        assert_eq!(counts.parse_errors.load(Ordering::Relaxed), 1);
+        assert_eq!(*counts.reconnects.lock().unwrap(), [1]);
+    }
+
+    #[tokio::test]
+    async fn frame_tap_writes_ndjson_and_reports_drops() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let frames = [
+            r#"{"type":"ticker","sid":1}"#,
+            r#"{"type":"ticker","sid":2}"#,
+        ];
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("accept ws");
+            for frame in frames {
+                ws.send(Message::Text(frame.to_string()))
+                    .await
+                    .expect("send");
+            }
+            let _ = ws.next().await;
+        });
+
+        let env = KalshiEnvironment {
+            rest_origin: Url::parse("http://127.0.0.1/").expect("url"),
+            ws_url: format!("ws://{}", addr),
+        };
+        let mut client = KalshiWsClient::connect(env, WsReconnectConfig::default())
+            .await
+            .expect("connect");
+        let (writer, mut file) = tokio::io::duplex(4096);
+        let tap = WsFrameTap::new(writer, 8);
+        client
+            .start_reader(WsReaderConfig {
+                mode: WsReaderMode::Raw,
+                tap: Some(tap.clone()),
+                ..Default::default()
+            })
+            .await
+            .expect("start reader");
+        for _ in frames {
+            let event = timeout(Duration::from_secs(2), client.next_event())
+                .await
+                .expect("event in time")
+                .expect("event");
+            assert!(matches!(event, WsEvent::Raw(_)), "{event:?}");
+        }
+        client.close().await.expect("close");
+        server.await.expect("server");
+
+        let mut written = String::new();
+        timeout(Duration::from_secs(2), file.read_to_string(&mut written))
+            .await
+            .expect("tap shut down")
+            .expect("read tap");
+        assert_eq!(written, format!("{}\n{}\n", frames[0], frames[1]));
+        assert_eq!(tap.dropped(), 0);
+
+        // The tap serves one reader.
+        assert!(matches!(
+            tap.start(None),
+            Err(KalshiError::InvalidParams(_))
+        ));
+
+        // A full queue drops frames, warning once per stall.
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut sender = TapSender {
+            tx,
+            dropped: Arc::new(AtomicU64::new(0)),
+            stalled: false,
+        };
+        assert!(sender.offer(Bytes::from_static(b"a")).is_none());
+        assert!(matches!(
+            sender.offer(Bytes::from_static(b"b")),
+            Some(WsEvent::TapDropped { dropped: 1 })
+        ));
+        assert!(sender.offer(Bytes::from_static(b"c")).is_none());
+        rx.recv().await.expect("queued frame");
+        assert!(sender.offer(Bytes::from_static(b"d")).is_none());
+        assert!(matches!(
+            sender.offer(Bytes::from_static(b"e")),
+            Some(WsEvent::TapDropped { dropped: 3 })
+        ));
+    }
 }
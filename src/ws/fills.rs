@@ -0,0 +1,469 @@
+use crate::error::KalshiError;
+use crate::rest::KalshiRestClient;
+use crate::rest::types::{
+    Fill, GetFillsParams, format_signed_price_units, parse_signed_price_units,
+};
+use crate::ws::client::{KalshiWsClient, WsEvent};
+use crate::ws::types::{
+    WsChannel, WsDataMessage, WsDataMessageRef, WsFill, WsMessage, WsMessageRef, WsMsgType,
+    WsSubscriptionParams,
+};
+
+use futures::{Stream, StreamExt, stream};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::pin::Pin;
+
+/// A fill from either the REST backfill or the live `fill` channel.
+#[derive(Debug, Clone)]
+pub enum FillUpdate {
+    /// Historical fill returned by `GET /portfolio/fills`.
+    Backfill(Fill),
+    /// Fill pushed on the WebSocket `fill` channel.
+    Live(WsFill),
+}
+
+impl FillUpdate {
+    pub fn fill_id(&self) -> &str {
+        match self {
+            FillUpdate::Backfill(fill) => &fill.fill_id,
+            FillUpdate::Live(fill) => &fill.fill_id,
+        }
+    }
+
+    pub fn order_id(&self) -> &str {
+        match self {
+            FillUpdate::Backfill(fill) => &fill.order_id,
+            FillUpdate::Live(fill) => &fill.order_id,
+        }
+    }
+}
+
+/// Drops fills the server resends after a reconnect.
+///
+/// Remembers the most recent `capacity` `fill_id`s seen on the `fill`
+/// channel, forgetting the oldest first. Attach with
+/// [`KalshiWsClient::attach_fill_deduper`]; repeats are dropped, or replaced
+/// by [`WsEvent::DuplicateFill`] when
+/// [`report_duplicates`](Self::report_duplicates) is set.
+#[derive(Debug, Clone)]
+pub struct FillDeduper {
+    capacity: usize,
+    order: VecDeque<String>,
+    seen: HashSet<String>,
+    report_duplicates: bool,
+}
+
+impl FillDeduper {
+    /// Remember up to `capacity` fill ids (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+            report_duplicates: false,
+        }
+    }
+
+    /// Emit [`WsEvent::DuplicateFill`] for repeats instead of dropping them silently.
+    pub fn report_duplicates(mut self, report: bool) -> Self {
+        self.report_duplicates = report;
+        self
+    }
+
+    /// Record `fill_id`. Returns `false` if it was seen recently.
+    pub fn insert(&mut self, fill_id: &str) -> bool {
+        if self.seen.contains(fill_id) {
+            return false;
+        }
+        self.seen.insert(fill_id.to_string());
+        self.order.push_back(fill_id.to_string());
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        true
+    }
+
+    /// Number of fill ids currently remembered.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Pass `event` through unless it repeats a recent fill.
+    ///
+    /// `raw_type` is the `type` the reader already read from a raw frame;
+    /// raw frames are only parsed when it is `fill`.
+    pub(crate) fn screen(
+        &mut self,
+        event: WsEvent,
+        raw_type: Option<&WsMsgType>,
+    ) -> Option<WsEvent> {
+        let fresh = match &event {
+            WsEvent::Message(WsMessage::Data(WsDataMessage::Fill { msg, .. })) => {
+                self.insert(&msg.fill_id)
+            }
+            WsEvent::Raw(raw) if raw_type == Some(&WsMsgType::Fill) => match raw.parse_borrowed() {
+                Ok(WsMessageRef::Data(WsDataMessageRef::Fill { msg, .. })) => {
+                    self.insert(&msg.fill_id)
+                }
+                _ => true,
+            },
+            _ => true,
+        };
+        if fresh {
+            return Some(event);
+        }
+        if !self.report_duplicates {
+            return None;
+        }
+        let fill_id = match event {
+            WsEvent::Message(WsMessage::Data(WsDataMessage::Fill { msg, .. })) => msg.fill_id,
+            WsEvent::Raw(raw) => match raw.parse_borrowed() {
+                Ok(WsMessageRef::Data(WsDataMessageRef::Fill { msg, .. })) => {
+                    msg.fill_id.into_owned()
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(WsEvent::DuplicateFill { fill_id })
+    }
+}
+
+/// Running total of `fee_cost` across fills, overall and per market ticker.
+///
+/// Fees are summed in micro-dollars (`1_000_000` = $1), so totals are exact
+/// however many fills are added. A fill whose `fee_cost` can't be parsed is
+/// left out and counted in [`skipped`](Self::skipped); a backfilled fill
+/// without one counts as zero. Repeated fills are counted again, so feed it
+/// from a stream screened by [`FillDeduper`] or [`KalshiWsClient::fills_since`].
+#[derive(Debug, Clone, Default)]
+pub struct FeeAccumulator {
+    total: i64,
+    by_ticker: BTreeMap<String, i64>,
+    skipped: usize,
+}
+
+impl FeeAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a live fill. Returns `false` if its fee was unparsable.
+    pub fn add_ws_fill(&mut self, fill: &WsFill) -> bool {
+        self.add_fee(&fill.ticker, &fill.fee_cost)
+    }
+
+    /// Add a backfilled fill. Returns `false` if its fee was unparsable.
+    pub fn add_fill(&mut self, fill: &Fill) -> bool {
+        self.add_fee(&fill.ticker, fill.fee_cost.as_deref().unwrap_or("0"))
+    }
+
+    /// Add either kind of fill. Returns `false` if its fee was unparsable.
+    pub fn add(&mut self, update: &FillUpdate) -> bool {
+        match update {
+            FillUpdate::Backfill(fill) => self.add_fill(fill),
+            FillUpdate::Live(fill) => self.add_ws_fill(fill),
+        }
+    }
+
+    fn add_fee(&mut self, ticker: &str, fee_cost: &str) -> bool {
+        let Some(fee) = parse_signed_price_units(fee_cost) else {
+            self.skipped += 1;
+            return false;
+        };
+        self.total += fee;
+        match self.by_ticker.get_mut(ticker) {
+            Some(total) => *total += fee,
+            None => {
+                self.by_ticker.insert(ticker.to_string(), fee);
+            }
+        }
+        true
+    }
+
+    /// Total fees in micro-dollars.
+    pub fn total_fees(&self) -> i64 {
+        self.total
+    }
+
+    /// [`total_fees`](Self::total_fees) as a dollar string with 4 decimal places, e.g. `"1.2500"`.
+    pub fn total_fees_dollars(&self) -> String {
+        format_signed_price_units(self.total, 4)
+    }
+
+    /// Fees in micro-dollars, keyed by market ticker.
+    pub fn by_ticker(&self) -> &BTreeMap<String, i64> {
+        &self.by_ticker
+    }
+
+    /// Fees for one market as a dollar string, or `None` if it has no fills.
+    pub fn ticker_fees_dollars(&self, ticker: &str) -> Option<String> {
+        self.by_ticker
+            .get(ticker)
+            .map(|fees| format_signed_price_units(*fees, 4))
+    }
+
+    /// Fills left out because their `fee_cost` was unparsable.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Clear all totals.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+type BoxedStream<'a, T> = Pin<Box<dyn Stream<Item = Result<T, KalshiError>> + Send + 'a>>;
+
+struct MergeState<'a> {
+    backfill: Option<BoxedStream<'a, Fill>>,
+    live: BoxedStream<'a, WsEvent>,
+    seen: HashSet<String>,
+    done: bool,
+}
+
+/// Yield every backfilled fill, then live fills whose `fill_id` was not
+/// already backfilled. Ends after the first error or a disconnect.
+fn merge_fills<'a>(
+    backfill: BoxedStream<'a, Fill>,
+    live: BoxedStream<'a, WsEvent>,
+) -> impl Stream<Item = Result<FillUpdate, KalshiError>> + Send + 'a {
+    let state = MergeState {
+        backfill: Some(backfill),
+        live,
+        seen: HashSet::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+
+        if let Some(backfill) = state.backfill.as_mut() {
+            match backfill.next().await {
+                Some(Ok(fill)) => {
+                    state.seen.insert(fill.fill_id.clone());
+                    return Some((Ok(FillUpdate::Backfill(fill)), state));
+                }
+                Some(Err(err)) => {
+                    state.done = true;
+                    return Some((Err(err), state));
+                }
+                None => state.backfill = None,
+            }
+        }
+
+        loop {
+            let msg = match state.live.next().await {
+                Some(Ok(WsEvent::Message(WsMessage::Data(WsDataMessage::Fill {
+                    msg, ..
+                })))) => msg,
+                Some(Ok(WsEvent::Raw(raw))) if raw.peek_type() == Some(WsMsgType::Fill) => {
+                    match raw.parse_owned() {
+                        Ok(WsMessage::Data(WsDataMessage::Fill { msg, .. })) => msg,
+                        Ok(_) => continue,
+                        Err(err) => {
+                            state.done = true;
+                            return Some((Err(err), state));
+                        }
+                    }
+                }
+                Some(Ok(WsEvent::Disconnected { error, .. })) | Some(Err(error)) => {
+                    state.done = true;
+                    return Some((Err(error), state));
+                }
+                Some(Ok(_)) => continue,
+                None => return None,
+            };
+            if state.seen.remove(&msg.fill_id) {
+                continue;
+            }
+            return Some((Ok(FillUpdate::Live(msg)), state));
+        }
+    })
+}
+
+impl KalshiWsClient {
+    /// Stream every fill since `since_ts` (unix seconds), then keep streaming
+    /// new fills as they arrive.
+    ///
+    /// Subscribes to [`WsChannel::Fill`] before paging the REST backfill, so
+    /// fills that land during setup are seen on one side or the other and
+    /// deduplicated by `fill_id`. Backfilled fills are yielded newest first,
+    /// as returned by the API.
+    ///
+    /// The stream drives [`KalshiWsClient::next_event`] and drops every
+    /// non-fill event; it ends with an error on [`WsEvent::Disconnected`].
+    /// Live fills are read from [`WsEvent::Raw`] frames too, so it works with
+    /// a reader in [`WsReaderMode::Raw`](crate::ws::WsReaderMode::Raw).
+    /// Fills that occur while a reconnect is in progress are not backfilled.
+    ///
+    /// **Requires auth.**
+    pub async fn fills_since<'a>(
+        &'a mut self,
+        rest: &'a KalshiRestClient,
+        since_ts: i64,
+    ) -> Result<impl Stream<Item = Result<FillUpdate, KalshiError>> + Send + 'a, KalshiError> {
+        self.subscribe(WsSubscriptionParams {
+            channels: vec![WsChannel::Fill],
+            ..Default::default()
+        })
+        .await?;
+
+        let backfill = rest.stream_fills(
+            GetFillsParams {
+                min_ts: Some(since_ts),
+                ..Default::default()
+            },
+            None,
+        );
+        let live = stream::unfold(self, |ws| async move {
+            let event = ws.next_event().await;
+            Some((event, ws))
+        });
+
+        Ok(merge_fills(Box::pin(backfill), Box::pin(live)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rest_fill(id: &str) -> Fill {
+        serde_json::from_value(serde_json::json!({
+            "fill_id": id,
+            "order_id": "o1",
+            "trade_id": "t1",
+            "ticker": "MKT-1",
+        }))
+        .expect("fill")
+    }
+
+    fn ws_fill_json(id: &str) -> String {
+        format!(
+            r#"{{"type":"fill","sid":1,"msg":{{"fill_id":"{id}","trade_id":"t1","order_id":"o1","ticker":"MKT-1","market_ticker":"MKT-1","side":"yes","action":"buy","count":1,"count_fp":"1.00","yes_price":55,"no_price":45,"yes_price_fixed":"0.55","no_price_fixed":"0.45","is_taker":true,"fee_cost":"0.01"}}}}"#
+        )
+    }
+
+    fn ws_fill(id: &str) -> WsEvent {
+        WsEvent::Message(WsMessage::from_bytes(ws_fill_json(id).as_bytes()).expect("fill"))
+    }
+
+    #[tokio::test]
+    async fn merge_fills_dedupes_across_the_boundary() {
+        let backfill = stream::iter(vec![Ok(rest_fill("f2")), Ok(rest_fill("f1"))]);
+        let live = stream::iter(vec![
+            Ok(ws_fill("f2")),
+            Ok(WsEvent::Reconnected { attempt: 1 }),
+            Ok(ws_fill("f3")),
+            Ok(WsEvent::Disconnected {
+                error: KalshiError::Ws("closed".to_string()),
+                cause: None,
+            }),
+            Ok(ws_fill("f4")),
+        ]);
+
+        let items: Vec<_> = merge_fills(Box::pin(backfill), Box::pin(live))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 4);
+        let ids: Vec<_> = items[..3]
+            .iter()
+            .map(|item| item.as_ref().expect("fill").fill_id().to_string())
+            .collect();
+        assert_eq!(ids, ["f2", "f1", "f3"]);
+        assert!(matches!(items[0], Ok(FillUpdate::Backfill(_))));
+        assert!(matches!(items[2], Ok(FillUpdate::Live(_))));
+        assert!(items[3].is_err());
+    }
+
+    #[tokio::test]
+    async fn merge_fills_reads_raw_frames() {
+        let raw = |id: &str| {
+            Ok(WsEvent::Raw(crate::ws::types::WsRawEvent::new(
+                ws_fill_json(id).into(),
+            )))
+        };
+        let backfill = stream::iter(vec![Ok(rest_fill("f1"))]);
+        let live = stream::iter(vec![raw("f1"), raw("f2")]);
+
+        let items: Vec<_> = merge_fills(Box::pin(backfill), Box::pin(live))
+            .collect()
+            .await;
+
+        let ids: Vec<_> = items
+            .iter()
+            .map(|item| item.as_ref().expect("fill").fill_id().to_string())
+            .collect();
+        assert_eq!(ids, ["f1", "f2"]);
+        assert!(matches!(items[1], Ok(FillUpdate::Live(_))));
+    }
+
+    #[test]
+    fn fill_deduper_drops_or_reports_recent_repeats() {
+        let mut deduper = FillDeduper::new(2);
+        assert!(deduper.screen(ws_fill("f1"), None).is_some());
+        assert!(deduper.screen(ws_fill("f1"), None).is_none());
+        assert!(
+            deduper
+                .screen(WsEvent::Reconnected { attempt: 1 }, None)
+                .is_some()
+        );
+
+        let mut deduper = deduper.report_duplicates(true);
+        let raw = || WsEvent::Raw(crate::ws::types::WsRawEvent::new(ws_fill_json("f1").into()));
+        assert!(deduper.screen(raw(), None).is_some());
+        assert!(matches!(
+            deduper.screen(raw(), Some(&WsMsgType::Fill)),
+            Some(WsEvent::DuplicateFill { fill_id }) if fill_id == "f1"
+        ));
+
+        assert!(deduper.insert("f2"));
+        assert!(deduper.insert("f3"));
+        assert_eq!(deduper.len(), 2);
+        assert!(deduper.insert("f1"));
+    }
+
+    #[test]
+    fn fee_accumulator_sums_exactly_per_ticker() {
+        let WsEvent::Message(WsMessage::Data(WsDataMessage::Fill { msg: mut live, .. })) =
+            ws_fill("f1")
+        else {
+            panic!("expected fill");
+        };
+        live.fee_cost = "0.0001".to_string();
+
+        let mut fees = FeeAccumulator::new();
+        for _ in 0..10_000 {
+            assert!(fees.add_ws_fill(&live));
+        }
+        let mut backfill = rest_fill("f2");
+        backfill.ticker = "MKT-2".to_string();
+        backfill.fee_cost = Some("0.07".to_string());
+        assert!(fees.add(&FillUpdate::Backfill(backfill)));
+        assert!(fees.add_fill(&rest_fill("f3")));
+        live.fee_cost = "n/a".to_string();
+        assert!(!fees.add_ws_fill(&live));
+
+        assert_eq!(fees.total_fees(), 1_070_000);
+        assert_eq!(fees.total_fees_dollars(), "1.0700");
+        assert_eq!(fees.ticker_fees_dollars("MKT-1").as_deref(), Some("1.0000"));
+        assert_eq!(fees.ticker_fees_dollars("MKT-2").as_deref(), Some("0.0700"));
+        assert_eq!(fees.ticker_fees_dollars("MKT-3"), None);
+        assert_eq!(fees.skipped(), 1);
+
+        fees.reset();
+        assert_eq!(fees.total_fees_dollars(), "0.0000");
+        assert!(fees.by_ticker().is_empty());
+    }
+}
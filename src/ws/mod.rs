@@ -66,10 +66,7 @@
 //! };
 //!
 //! # async fn run() -> Result<(), kalshi_fast::KalshiError> {
-//! let auth = KalshiAuth::from_pem_file(
-//!     std::env::var("KALSHI_KEY_ID").unwrap(),
-//!     std::env::var("KALSHI_PRIVATE_KEY_PATH").unwrap(),
-//! )?;
+//! let auth = KalshiAuth::from_env()?;
 //!
 //! let mut ws = KalshiWsClient::connect_authenticated(
 //!     KalshiEnvironment::demo(),
@@ -106,25 +103,48 @@
 //!                 │    ├─ Subscribed / Unsubscribed / Ok
 //!                 │    ├─ Error { .. }
 //!                 │    └─ Unknown { .. }
+//!                 ├─ Reconnecting { attempt, delay }
 //!                 ├─ Reconnected { attempt }
-//!                 └─ Disconnected { error }
+//!                 └─ Disconnected { error, cause }
 //! ```
 //!
 //! # Reconnection
 //!
 //! [`KalshiWsClient`] reconnects automatically with exponential backoff when
-//! the underlying connection drops. On success it resubscribes to all active
-//! channels and emits [`WsEvent::Reconnected`]. If retries are exhausted it
-//! emits [`WsEvent::Disconnected`]. Configure via [`WsReconnectConfig`].
-//!
-//! **Note:** Sequence resync is not automatic; callers must handle any gaps
-//! using the `seq` field on [`WsDataMessage`] variants.
+//! the underlying connection drops, emitting [`WsEvent::Reconnecting`] before
+//! each attempt. On success it resubscribes to all active channels and emits
+//! [`WsEvent::Reconnected`]. If retries are exhausted, or the server closes
+//! with a non-retryable code such as policy (1008), it emits
+//! [`WsEvent::Disconnected`]. Configure via [`WsReconnectConfig`].
+//! [`KalshiWsClient::on_disconnect`] can cancel all resting orders as soon as
+//! the connection drops.
+//!
+//! # Proxies
+//!
+//! The WebSocket connection does not use reqwest, so REST proxy settings
+//! don't apply to it. Pass a [`WsProxy`] to [`KalshiWsClient::connect_via`]
+//! to tunnel through an HTTP `CONNECT` or SOCKS5 proxy, or
+//! [`WsProxy::FromEnv`] to honor `HTTPS_PROXY`/`ALL_PROXY`/`NO_PROXY`.
+//!
+//! **Note:** Sequence resync is not automatic. Enable
+//! [`KalshiWsClient::detect_sequence_gaps`] to be told about gaps via
+//! [`WsEvent::SequenceGap`], or check the `seq` field on [`WsDataMessage`]
+//! variants yourself.
 
+mod book;
 mod client;
+mod fills;
+mod proxy;
 pub mod types;
 
+pub use crate::types::Quantity;
+pub(crate) use book::best_level_price;
+pub use book::{BookManager, DeltaOutcome, OrderBook, SnapshotCache, TopOfBook};
 pub use client::{
-    KalshiWsClient, KalshiWsLowLevelClient, WsEvent, WsEventReceiver, WsReaderConfig, WsReaderMode,
-    WsReconnectConfig,
+    BackoffStrategy, DisconnectAction, ExponentialBackoff, KalshiWsClient, KalshiWsLowLevelClient,
+    WsEvent, WsEventReceiver, WsFrameTap, WsMetrics, WsReaderConfig, WsReaderMode,
+    WsReconnectConfig, WsSubscriptionDrift,
 };
+pub use fills::{FeeAccumulator, FillDeduper, FillUpdate};
+pub use proxy::WsProxy;
 pub use types::*;
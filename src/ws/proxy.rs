@@ -0,0 +1,587 @@
+use crate::error::KalshiError;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use percent_encoding::percent_decode_str;
+use std::net::{IpAddr, SocketAddr};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, lookup_host};
+use tokio::time::{Duration, timeout};
+use url::Url;
+
+/// Longest proxy `CONNECT` response head accepted.
+const MAX_CONNECT_RESPONSE: usize = 8 * 1024;
+
+/// How long connecting to the proxy and setting up the tunnel may take.
+const PROXY_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How the WebSocket connection reaches the server.
+///
+/// Pass to [`KalshiWsClient::connect_via`](crate::KalshiWsClient::connect_via)
+/// or [`KalshiWsLowLevelClient::connect_via`](crate::KalshiWsLowLevelClient::connect_via);
+/// reconnects use the same route. TLS to the Kalshi endpoint runs inside the
+/// tunnel, so the proxy only sees the target host and port.
+#[derive(Debug, Clone, Default)]
+pub enum WsProxy {
+    /// Connect straight to the server (default).
+    #[default]
+    Direct,
+    /// Tunnel through this proxy. `http://` uses an HTTP `CONNECT` request,
+    /// `socks5h://` a SOCKS5 `CONNECT` with the hostname resolved by the
+    /// proxy, and `socks5://` one with the hostname resolved locally.
+    /// Percent-encoded credentials in the URL are decoded and sent as basic
+    /// auth or SOCKS5 username/password. `https://` proxies (TLS to the proxy
+    /// itself) are not supported and fail to connect.
+    Url(Url),
+    /// Read the proxy from the environment as reqwest does: `HTTPS_PROXY`
+    /// (for `wss://`) or `HTTP_PROXY` (for `ws://`), then `ALL_PROXY`, each
+    /// also in lowercase. Hosts matched by `NO_PROXY` connect directly.
+    /// The same schemes as [`Url`](Self::Url) apply.
+    FromEnv,
+}
+
+impl WsProxy {
+    /// The proxy to use for `host`, if any.
+    pub(crate) fn resolve(&self, secure: bool, host: &str) -> Result<Option<Url>, KalshiError> {
+        match self {
+            WsProxy::Direct => Ok(None),
+            WsProxy::Url(url) => Ok(Some(url.clone())),
+            WsProxy::FromEnv => {
+                proxy_from_env_lookup(|name| std::env::var(name).ok(), secure, host)
+            }
+        }
+    }
+}
+
+fn proxy_from_env_lookup(
+    lookup: impl Fn(&str) -> Option<String>,
+    secure: bool,
+    host: &str,
+) -> Result<Option<Url>, KalshiError> {
+    let var = |name: &str| {
+        lookup(name)
+            .or_else(|| lookup(&name.to_ascii_lowercase()))
+            .filter(|value| !value.trim().is_empty())
+    };
+    let scheme_var = if secure { "HTTPS_PROXY" } else { "HTTP_PROXY" };
+    let Some(raw) = var(scheme_var).or_else(|| var("ALL_PROXY")) else {
+        return Ok(None);
+    };
+    if var("NO_PROXY").is_some_and(|no_proxy| no_proxy_matches(&no_proxy, host)) {
+        return Ok(None);
+    }
+    let raw = raw.trim();
+    let with_scheme = if raw.contains("://") {
+        raw.to_string()
+    } else {
+        format!("http://{raw}")
+    };
+    Url::parse(&with_scheme)
+        .map(Some)
+        .map_err(|e| KalshiError::InvalidParams(format!("invalid proxy URL {raw:?}: {e}")))
+}
+
+/// `NO_PROXY` entries match the host itself or any subdomain; `*` matches all.
+fn no_proxy_matches(no_proxy: &str, host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    no_proxy.split(',').map(str::trim).any(|entry| {
+        let entry = entry.trim_start_matches('.').to_ascii_lowercase();
+        entry == "*"
+            || (!entry.is_empty() && (host == entry || host.ends_with(&format!(".{entry}"))))
+    })
+}
+
+/// Open a TCP stream to `host:port` through `proxy`.
+pub(crate) async fn tunnel(proxy: &Url, host: &str, port: u16) -> Result<TcpStream, KalshiError> {
+    tunnel_within(proxy, host, port, PROXY_HANDSHAKE_TIMEOUT).await
+}
+
+async fn tunnel_within(
+    proxy: &Url,
+    host: &str,
+    port: u16,
+    limit: Duration,
+) -> Result<TcpStream, KalshiError> {
+    let proxy_err =
+        |reason: String| KalshiError::Ws(format!("proxy {}: {reason}", redacted(proxy)));
+    let proxy_host = proxy
+        .host_str()
+        .ok_or_else(|| proxy_err("missing host".to_string()))?;
+    // `Some(remote_dns)` for SOCKS5.
+    let socks = match proxy.scheme() {
+        "http" => None,
+        "socks5" => Some(false),
+        "socks5h" => Some(true),
+        "https" => {
+            return Err(proxy_err(
+                "https:// proxies are not supported; use http://".to_string(),
+            ));
+        }
+        other => return Err(proxy_err(format!("unsupported scheme {other:?}"))),
+    };
+    let proxy_port = proxy
+        .port()
+        .unwrap_or(if socks.is_some() { 1080 } else { 80 });
+    let username = percent_decode(proxy.username());
+    let password = proxy.password().map(percent_decode);
+    let credentials = (!username.is_empty()).then(|| (username, password.unwrap_or_default()));
+
+    let handshake = async {
+        let mut stream = TcpStream::connect((proxy_host, proxy_port))
+            .await
+            .map_err(|e| e.to_string())?;
+        match socks {
+            Some(remote_dns) => {
+                let target = socks5_target(host, port, remote_dns).await?;
+                socks5_connect(&mut stream, &target, credentials.as_ref()).await?;
+            }
+            None => http_connect(&mut stream, host, port, credentials.as_ref()).await?,
+        }
+        Ok::<_, String>(stream)
+    };
+    match timeout(limit, handshake).await {
+        Ok(result) => result.map_err(proxy_err),
+        Err(_) => Err(proxy_err(format!("handshake timed out after {limit:?}"))),
+    }
+}
+
+async fn http_connect(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+    credentials: Option<&(String, String)>,
+) -> Result<(), String> {
+    let authority = if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((user, pass)) = credentials {
+        let token = BASE64.encode(format!("{user}:{pass}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Read byte by byte so nothing past the response head is consumed.
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_CONNECT_RESPONSE {
+            return Err("CONNECT response too large".to_string());
+        }
+        let byte = stream.read_u8().await.map_err(|e| e.to_string())?;
+        head.push(byte);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let status_line = head.lines().next().unwrap_or_default();
+    let status = status_line.split_whitespace().nth(1).unwrap_or_default();
+    if status != "200" {
+        return Err(format!("CONNECT rejected: {status_line}"));
+    }
+    Ok(())
+}
+
+/// The SOCKS5 `DST.ADDR` and `DST.PORT` fields for `host:port`: an IP
+/// literal as is, otherwise the hostname for the proxy to resolve when
+/// `remote_dns`, or the first locally resolved address.
+async fn socks5_target(host: &str, port: u16, remote_dns: bool) -> Result<Vec<u8>, String> {
+    let literal = host.trim_start_matches('[').trim_end_matches(']');
+    let ip = match literal.parse::<IpAddr>() {
+        Ok(ip) => ip,
+        Err(_) if remote_dns => {
+            if host.len() > 255 {
+                return Err("SOCKS5 target host longer than 255 bytes".to_string());
+            }
+            let mut target = vec![0x03, host.len() as u8];
+            target.extend_from_slice(host.as_bytes());
+            target.extend_from_slice(&port.to_be_bytes());
+            return Ok(target);
+        }
+        Err(_) => lookup_host((host, port))
+            .await
+            .map_err(|e| format!("resolve {host}: {e}"))?
+            .next()
+            .map(|addr: SocketAddr| addr.ip())
+            .ok_or_else(|| format!("resolve {host}: no addresses"))?,
+    };
+    let mut target = match ip {
+        IpAddr::V4(ip) => [&[0x01][..], &ip.octets()].concat(),
+        IpAddr::V6(ip) => [&[0x04][..], &ip.octets()].concat(),
+    };
+    target.extend_from_slice(&port.to_be_bytes());
+    Ok(target)
+}
+
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    target: &[u8],
+    credentials: Option<&(String, String)>,
+) -> Result<(), String> {
+    let io = |e: std::io::Error| e.to_string();
+    let methods: &[u8] = if credentials.is_some() {
+        &[0x00, 0x02]
+    } else {
+        &[0x00]
+    };
+    let mut greeting = vec![0x05, methods.len() as u8];
+    greeting.extend_from_slice(methods);
+    stream.write_all(&greeting).await.map_err(io)?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await.map_err(io)?;
+    match (reply, credentials) {
+        ([0x05, 0x00], _) => {}
+        ([0x05, 0x02], Some((user, pass))) => {
+            if user.len() > 255 || pass.len() > 255 {
+                return Err("SOCKS5 credentials longer than 255 bytes".to_string());
+            }
+            let mut auth = vec![0x01, user.len() as u8];
+            auth.extend_from_slice(user.as_bytes());
+            auth.push(pass.len() as u8);
+            auth.extend_from_slice(pass.as_bytes());
+            stream.write_all(&auth).await.map_err(io)?;
+            stream.read_exact(&mut reply).await.map_err(io)?;
+            if reply[1] != 0x00 {
+                return Err("SOCKS5 authentication failed".to_string());
+            }
+        }
+        ([0x05, _], _) => {
+            return Err("SOCKS5 proxy offered no acceptable auth method".to_string());
+        }
+        _ => return Err(format!("not a SOCKS5 reply: {reply:02x?}")),
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    request.extend_from_slice(target);
+    stream.write_all(&request).await.map_err(io)?;
+
+    let mut head = [0u8; 4];
+    stream.read_exact(&mut head).await.map_err(io)?;
+    if head[0] != 0x05 {
+        return Err(format!("not a SOCKS5 reply: {head:02x?}"));
+    }
+    if head[1] != 0x00 {
+        return Err(format!("SOCKS5 CONNECT rejected (reply {})", head[1]));
+    }
+    let addr_len = match head[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await.map_err(io)? as usize,
+        other => return Err(format!("SOCKS5 reply has unknown address type {other}")),
+    };
+    let mut bound = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound).await.map_err(io)?;
+    Ok(())
+}
+
+/// The proxy URL without credentials, for error messages.
+fn redacted(proxy: &Url) -> String {
+    let mut url = proxy.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url.to_string()
+}
+
+fn percent_decode(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_proxy_prefers_scheme_var_and_honors_no_proxy() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let vars = env(&[
+            ("https_proxy", "proxy.corp:3128"),
+            ("ALL_PROXY", "socks5://socks.corp"),
+            ("NO_PROXY", "localhost, .internal.example"),
+        ]);
+
+        let url = proxy_from_env_lookup(vars, true, "api.elections.kalshi.com")
+            .unwrap()
+            .expect("proxy");
+        assert_eq!(url.as_str(), "http://proxy.corp:3128/");
+        let url = proxy_from_env_lookup(vars, false, "api.elections.kalshi.com")
+            .unwrap()
+            .expect("proxy");
+        assert_eq!(url.scheme(), "socks5");
+        assert!(
+            proxy_from_env_lookup(vars, true, "ws.internal.example")
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            proxy_from_env_lookup(vars, true, "localhost")
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            proxy_from_env_lookup(env(&[]), true, "host")
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(percent_decode("us%40er"), "us@er");
+        assert_eq!(percent_decode("p%3As%25s"), "p:s%s");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    /// Accept one connection on a local port and run `script` on it.
+    async fn scripted_proxy<F, Fut>(
+        script: F,
+    ) -> (std::net::SocketAddr, tokio::task::JoinHandle<()>)
+    where
+        F: FnOnce(TcpStream) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ()> + Send,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            script(stream).await;
+        });
+        (addr, server)
+    }
+
+    async fn tunnel_err(proxy: &str) -> String {
+        let proxy = Url::parse(proxy).expect("url");
+        tunnel_within(&proxy, "api.example.com", 443, Duration::from_secs(2))
+            .await
+            .expect_err("tunnel should fail")
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn socks5_resolves_locally_without_the_h_suffix() {
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut greeting = [0u8; 3];
+            stream.read_exact(&mut greeting).await.expect("greeting");
+            stream.write_all(&[0x05, 0x00]).await.expect("method");
+            let mut request = [0u8; 10];
+            stream.read_exact(&mut request).await.expect("request");
+            assert_eq!(request, [0x05, 0x01, 0x00, 0x01, 127, 0, 0, 1, 0x01, 0xbb]);
+            stream
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0x1f, 0x90])
+                .await
+                .expect("reply");
+        })
+        .await;
+
+        let proxy = Url::parse(&format!("socks5://{addr}")).expect("url");
+        tunnel_within(&proxy, "127.0.0.1", 443, Duration::from_secs(2))
+            .await
+            .expect("tunnel");
+        server.await.expect("server");
+
+        // `localhost` may resolve to either family, but never goes out as a name.
+        let target = socks5_target("localhost", 443, false)
+            .await
+            .expect("target");
+        assert!(matches!(target[0], 0x01 | 0x04), "{target:?}");
+        let target = socks5_target("api.example.com", 443, true)
+            .await
+            .expect("target");
+        assert_eq!(target[0], 0x03);
+        let target = socks5_target("[::1]", 443, true).await.expect("target");
+        assert_eq!(target[0], 0x04);
+        assert_eq!(target.len(), 1 + 16 + 2);
+    }
+
+    #[tokio::test]
+    async fn socks5_reports_auth_failure() {
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut greeting = [0u8; 4];
+            stream.read_exact(&mut greeting).await.expect("greeting");
+            stream.write_all(&[0x05, 0x02]).await.expect("method");
+            let mut auth = [0u8; 11];
+            stream.read_exact(&mut auth).await.expect("auth");
+            assert_eq!(&auth, b"\x01\x04user\x04p:ss");
+            stream.write_all(&[0x01, 0x01]).await.expect("auth reply");
+        })
+        .await;
+
+        let err = tunnel_err(&format!("socks5h://user:p%3Ass@{addr}")).await;
+        assert!(err.contains("authentication failed"), "{err}");
+        assert!(!err.contains("p:ss") && !err.contains("p%3Ass"), "{err}");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn socks5_rejects_malformed_and_short_replies() {
+        let cases: [(&[u8], &str); 5] = [
+            // Not SOCKS5 at all.
+            (b"HTTP/1.1 400 Bad Request\r\n\r\n", "not a socks5 reply"),
+            // No acceptable method.
+            (&[0x05, 0xff], "no acceptable auth method"),
+            // Method reply cut short.
+            (&[0x05], "eof"),
+            // CONNECT reply with an unknown address type.
+            (
+                &[0x05, 0x00, 0x05, 0x00, 0x00, 0x09],
+                "unknown address type",
+            ),
+            // CONNECT reply cut off inside the bound address.
+            (&[0x05, 0x00, 0x05, 0x00, 0x00, 0x01, 127, 0], "eof"),
+        ];
+        for (reply, expected) in cases {
+            let (addr, server) = scripted_proxy(move |mut stream| async move {
+                let mut greeting = [0u8; 3];
+                stream.read_exact(&mut greeting).await.expect("greeting");
+                let _ = stream.write_all(reply).await;
+                // Drain the CONNECT request, if one is sent, then hang up.
+                let mut buf = [0u8; 64];
+                let _ = timeout(Duration::from_millis(100), stream.read(&mut buf)).await;
+            })
+            .await;
+            let err = tunnel_err(&format!("socks5h://{addr}")).await;
+            assert!(
+                err.to_ascii_lowercase().contains(expected),
+                "{reply:?}: {err}"
+            );
+            server.await.expect("server");
+        }
+
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut greeting = [0u8; 3];
+            stream.read_exact(&mut greeting).await.expect("greeting");
+            stream.write_all(&[0x05, 0x00]).await.expect("method");
+            let mut request = [0u8; 22];
+            stream.read_exact(&mut request).await.expect("request");
+            stream
+                .write_all(&[0x05, 0x05, 0x00, 0x01])
+                .await
+                .expect("reply");
+        })
+        .await;
+        let err = tunnel_err(&format!("socks5h://{addr}")).await;
+        assert!(err.contains("CONNECT rejected (reply 5)"), "{err}");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn http_connect_sends_decoded_credentials_and_reports_rejections() {
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(stream.read_u8().await.expect("read"));
+            }
+            let head = String::from_utf8(head).expect("utf8");
+            let token = BASE64.encode("us@er:p:ss");
+            assert!(
+                head.contains(&format!("Proxy-Authorization: Basic {token}\r\n")),
+                "{head}"
+            );
+            stream
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .expect("reply");
+        })
+        .await;
+        let err = tunnel_err(&format!("http://us%40er:p%3Ass@{addr}")).await;
+        assert!(err.contains("CONNECT rejected: HTTP/1.1 407"), "{err}");
+        assert!(!err.contains("p%3Ass"), "{err}");
+        server.await.expect("server");
+
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut buf = [0u8; 256];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream.write_all(b"HTTP/1.1 200 Connection").await;
+        })
+        .await;
+        let err = tunnel_err(&format!("http://{addr}")).await;
+        assert!(err.contains("end of file"), "{err}");
+        server.await.expect("server");
+
+        let (addr, server) = scripted_proxy(|mut stream| async move {
+            let mut buf = [0u8; 256];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(&vec![b'x'; MAX_CONNECT_RESPONSE + 1])
+                .await;
+        })
+        .await;
+        let err = tunnel_err(&format!("http://{addr}")).await;
+        assert!(err.contains("too large"), "{err}");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn socks5_tunnel_authenticates_and_sends_the_hostname() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.expect("accept");
+            let mut greeting = [0u8; 4];
+            stream.read_exact(&mut greeting).await.expect("greeting");
+            assert_eq!(greeting, [0x05, 0x02, 0x00, 0x02]);
+            stream.write_all(&[0x05, 0x02]).await.expect("method");
+
+            let mut auth = [0u8; 12];
+            stream.read_exact(&mut auth).await.expect("auth");
+            assert_eq!(&auth, b"\x01\x04user\x05pa@ss");
+            stream.write_all(&[0x01, 0x00]).await.expect("auth reply");
+
+            let host = b"api.example.com";
+            let mut request = vec![0u8; 5 + host.len() + 2];
+            stream.read_exact(&mut request).await.expect("request");
+            assert_eq!(request[..5], [0x05, 0x01, 0x00, 0x03, host.len() as u8]);
+            assert_eq!(&request[5..5 + host.len()], host);
+            assert_eq!(request[5 + host.len()..], 443u16.to_be_bytes());
+            stream
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0x1f, 0x90])
+                .await
+                .expect("reply");
+            stream.write_all(b"tunnel").await.expect("payload");
+        });
+
+        let proxy = Url::parse(&format!("socks5h://user:pa%40ss@{addr}")).expect("url");
+        let mut stream = tunnel_within(&proxy, "api.example.com", 443, Duration::from_secs(2))
+            .await
+            .expect("tunnel");
+        let mut payload = [0u8; 6];
+        stream.read_exact(&mut payload).await.expect("payload");
+        assert_eq!(&payload, b"tunnel");
+        server.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn tunnel_times_out_a_silent_proxy_and_rejects_https() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            drop(stream);
+        });
+
+        let proxy = Url::parse(&format!("http://{addr}")).expect("url");
+        let err = tunnel_within(&proxy, "api.example.com", 443, Duration::from_millis(100))
+            .await
+            .expect_err("silent proxy");
+        assert!(err.to_string().contains("timed out"), "{err}");
+
+        let proxy = Url::parse(&format!("https://{addr}")).expect("url");
+        let err = tunnel(&proxy, "api.example.com", 443)
+            .await
+            .expect_err("https proxy");
+        assert!(err.to_string().contains("not supported"), "{err}");
+        server.abort();
+    }
+}
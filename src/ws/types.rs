@@ -2,7 +2,7 @@ use crate::error::KalshiError;
 use crate::rest::types::{EventPosition, MarketPosition};
 use crate::types::{
     BuySell, FixedPointCount, FixedPointDollars, OrderStatus, SelfTradePreventionType,
-    TradeTakerSide, YesNo,
+    TradeTakerSide, YesNo, deserialize_i64_or_string,
 };
 
 use bytes::Bytes;
@@ -33,6 +33,22 @@ pub enum WsChannel {
 }
 
 impl WsChannel {
+    /// Every channel, public first.
+    pub fn all() -> &'static [WsChannel] {
+        &[
+            WsChannel::Ticker,
+            WsChannel::Trade,
+            WsChannel::MarketLifecycleV2,
+            WsChannel::Multivariate,
+            WsChannel::OrderbookDelta,
+            WsChannel::Fill,
+            WsChannel::MarketPositions,
+            WsChannel::Communications,
+            WsChannel::OrderGroupUpdates,
+            WsChannel::UserOrders,
+        ]
+    }
+
     pub fn as_str(self) -> &'static str {
         match self {
             WsChannel::Ticker => "ticker",
@@ -59,6 +75,11 @@ impl WsChannel {
                 | WsChannel::UserOrders
         )
     }
+
+    /// Whether the channel accepts event-scoped subscriptions via `event_tickers`.
+    pub fn supports_event_tickers(self) -> bool {
+        matches!(self, WsChannel::Ticker | WsChannel::Trade)
+    }
 }
 
 impl fmt::Display for WsChannel {
@@ -67,6 +88,31 @@ impl fmt::Display for WsChannel {
     }
 }
 
+/// Error from parsing an unknown channel name into [`WsChannel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWsChannelError(pub String);
+
+impl fmt::Display for ParseWsChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown websocket channel `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseWsChannelError {}
+
+/// Parses the wire name returned by [`WsChannel::as_str`].
+impl std::str::FromStr for WsChannel {
+    type Err = ParseWsChannelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WsChannel::all()
+            .iter()
+            .copied()
+            .find(|channel| channel.as_str() == s)
+            .ok_or_else(|| ParseWsChannelError(s.to_string()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WsMsgType {
     Subscribed,
@@ -254,6 +300,28 @@ pub struct WsSubscriptionParams {
 }
 
 impl WsSubscriptionParams {
+    /// Return a copy with channels and ticker/id lists sorted and deduplicated.
+    ///
+    /// Duplicates would otherwise go over the wire and can produce duplicate
+    /// subscriptions.
+    pub fn normalized(&self) -> Self {
+        let mut out = self.clone();
+        out.channels.sort_by_key(|c| c.as_str());
+        out.channels.dedup();
+        for list in [
+            &mut out.market_tickers,
+            &mut out.market_ids,
+            &mut out.event_tickers,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            list.sort();
+            list.dedup();
+        }
+        out
+    }
+
     /// Collect all market tickers from both singular and plural fields.
     pub fn all_market_tickers(&self) -> Vec<&str> {
         let mut out = Vec::new();
@@ -279,7 +347,7 @@ impl WsSubscriptionParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsSubscriptionInfo {
     pub sid: u64,
     #[serde(default)]
@@ -301,7 +369,7 @@ pub struct WsSubscriptionInfo {
 }
 
 /// Ticker channel message (type: "ticker")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsTicker {
     pub market_ticker: String,
     pub market_id: String,
@@ -311,17 +379,21 @@ pub struct WsTicker {
     pub price_dollars: String,
     pub yes_bid_dollars: String,
     pub yes_ask_dollars: String,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub volume: i64,
     pub volume_fp: String,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub open_interest: i64,
     pub open_interest_fp: String,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub dollar_volume: i64,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub dollar_open_interest: i64,
     pub ts: i64,
 }
 
 /// Trade channel message (type: "trade")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsTrade {
     pub trade_id: String,
     pub ticker: String,
@@ -346,7 +418,7 @@ pub struct WsTrade {
 }
 
 /// Orderbook snapshot message (type: "orderbook_snapshot")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsOrderbookSnapshot {
     pub market_ticker: String,
     pub market_id: String,
@@ -371,7 +443,7 @@ pub struct WsOrderbookSnapshot {
 }
 
 /// Orderbook delta message (type: "orderbook_delta")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsOrderbookDelta {
     pub market_ticker: String,
     pub market_id: String,
@@ -389,7 +461,7 @@ pub struct WsOrderbookDelta {
 }
 
 /// Fill channel message (type: "fill")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsFill {
     pub fill_id: String,
     pub trade_id: String,
@@ -419,7 +491,7 @@ pub struct WsFill {
 }
 
 /// Market lifecycle message (type: "market_lifecycle_v2")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMarketLifecycleV2 {
     pub market_ticker: String,
     #[serde(default)]
@@ -432,7 +504,7 @@ pub struct WsMarketLifecycleV2 {
     pub additional_metadata: Option<WsMarketLifecycleAdditionalMetadata>,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum WsMarketLifecycleEventType {
     Created,
@@ -445,7 +517,7 @@ pub enum WsMarketLifecycleEventType {
     Unknown,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMarketLifecycleAdditionalMetadata {
     #[serde(default)]
     pub name: Option<String>,
@@ -476,7 +548,7 @@ pub struct WsMarketLifecycleAdditionalMetadata {
 }
 
 /// Event lifecycle message (type: "event_lifecycle")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsEventLifecycle {
     pub event_ticker: String,
     #[serde(default)]
@@ -491,7 +563,7 @@ pub struct WsEventLifecycle {
     pub additional_metadata: Option<WsEventLifecycleAdditionalMetadata>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsEventLifecycleAdditionalMetadata {
     #[serde(default)]
     pub custom_strike: Option<BTreeMap<String, String>>,
@@ -500,7 +572,7 @@ pub struct WsEventLifecycleAdditionalMetadata {
 }
 
 /// Market positions message (type: "market_positions")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMarketPositions {
     #[serde(default)]
     pub market_positions: Vec<MarketPosition>,
@@ -508,7 +580,7 @@ pub struct WsMarketPositions {
     pub event_positions: Vec<EventPosition>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMultivariateSelectedMarket {
     pub event_ticker: String,
     pub market_ticker: String,
@@ -516,7 +588,7 @@ pub struct WsMultivariateSelectedMarket {
 }
 
 /// Multivariate message payload (type: "multivariate_lookup")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMultivariate {
     pub collection_ticker: String,
     pub event_ticker: String,
@@ -524,7 +596,7 @@ pub struct WsMultivariate {
     pub selected_markets: Vec<WsMultivariateSelectedMarket>,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WsOrderGroupEventType {
     Created,
@@ -537,7 +609,7 @@ pub enum WsOrderGroupEventType {
 }
 
 /// Order group update message payload (type: "order_group_updates")
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsOrderGroupUpdate {
     pub event_type: WsOrderGroupEventType,
     pub order_group_id: String,
@@ -546,7 +618,7 @@ pub struct WsOrderGroupUpdate {
 }
 
 /// User order update payload (type: "user_order").
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsUserOrder {
     pub order_id: String,
     pub user_id: String,
@@ -587,7 +659,7 @@ pub struct WsUserOrder {
     pub subaccount_number: Option<u32>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsMveSelectedLeg {
     #[serde(default)]
     pub event_ticker: Option<String>,
@@ -599,7 +671,7 @@ pub struct WsMveSelectedLeg {
     pub yes_settlement_value_dollars: Option<FixedPointDollars>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsRfqCreated {
     pub id: String,
     pub creator_id: String,
@@ -621,7 +693,7 @@ pub struct WsRfqCreated {
     pub mve_selected_legs: Option<Vec<WsMveSelectedLeg>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsRfqDeleted {
     pub id: String,
     pub creator_id: String,
@@ -639,7 +711,7 @@ pub struct WsRfqDeleted {
     pub deleted_ts: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsQuoteCreated {
     pub quote_id: String,
     pub rfq_id: String,
@@ -666,7 +738,7 @@ pub struct WsQuoteCreated {
     pub created_ts: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsQuoteAccepted {
     pub quote_id: String,
     pub rfq_id: String,
@@ -698,7 +770,7 @@ pub struct WsQuoteAccepted {
     pub rfq_target_cost_dollars: Option<FixedPointDollars>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsQuoteExecuted {
     pub quote_id: String,
     pub rfq_id: String,
@@ -867,13 +939,17 @@ pub struct WsTickerRef<'a> {
     pub yes_bid_dollars: Cow<'a, str>,
     #[serde(borrow)]
     pub yes_ask_dollars: Cow<'a, str>,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub volume: i64,
     #[serde(borrow)]
     pub volume_fp: Cow<'a, str>,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub open_interest: i64,
     #[serde(borrow)]
     pub open_interest_fp: Cow<'a, str>,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub dollar_volume: i64,
+    #[serde(deserialize_with = "deserialize_i64_or_string")]
     pub dollar_open_interest: i64,
     pub ts: i64,
 }
@@ -1640,11 +1716,19 @@ impl<'a> WsListSubscriptionsRef<'a> {
 pub struct WsErrorRef<'a> {
     #[serde(default)]
     pub code: Option<i64>,
-    #[serde(default, borrow)]
+    #[serde(default, borrow, alias = "msg")]
     pub message: Option<Cow<'a, str>>,
 }
 
 impl<'a> WsErrorRef<'a> {
+    /// The typed [`code`](Self::code), if the server sent one.
+    ///
+    /// Named `error_code` rather than `code` because `code` is already the
+    /// raw field, and it matches [`KalshiError::error_code`] on the REST side.
+    pub fn error_code(&self) -> Option<WsErrorCode> {
+        self.code.map(WsErrorCode::from)
+    }
+
     pub fn into_owned(self) -> WsError {
         WsError {
             code: self.code,
@@ -1820,13 +1904,10 @@ impl WsEnvelope {
                 seq,
                 msg: parse_msg(&msg)?,
             })),
-            WsMsgType::Communications => Ok(WsMessage::Unknown {
-                msg_type: WsMsgType::Communications,
-                raw: msg,
-            }),
             other => Ok(WsMessage::Unknown {
                 msg_type: other,
                 raw: msg,
+                envelope: None,
             }),
         }
     }
@@ -2006,32 +2087,163 @@ impl<'a> WsEnvelopeRef<'a> {
                 seq,
                 msg: parse_borrowed_msg(msg)?,
             })),
-            WsMsgType::Communications => Ok(WsMessageRef::Unknown {
-                msg_type: WsMsgType::Communications,
-                raw: msg,
-            }),
             other => Ok(WsMessageRef::Unknown {
                 msg_type: other,
                 raw: msg,
+                envelope: None,
             }),
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsListSubscriptions {
     #[serde(default)]
     pub subscriptions: Vec<WsSubscriptionInfo>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsError {
     #[serde(default)]
     pub code: Option<i64>,
-    #[serde(default)]
+    #[serde(default, alias = "msg")]
     pub message: Option<String>,
 }
 
+impl WsError {
+    /// Whether the server rejected the command for missing or invalid auth.
+    pub fn is_auth_rejection(&self) -> bool {
+        self.error_code() == Some(WsErrorCode::NotAuthorized)
+    }
+
+    /// The typed [`code`](Self::code), if the server sent one.
+    ///
+    /// Named `error_code` rather than `code` because `code` is already the
+    /// raw field, and it matches [`KalshiError::error_code`] on the REST side.
+    pub fn error_code(&self) -> Option<WsErrorCode> {
+        self.code.map(WsErrorCode::from)
+    }
+}
+
+/// Error codes documented for Kalshi WebSocket `error` replies.
+///
+/// Codes this crate does not know yet map to [`Unknown`](Self::Unknown), so
+/// match with a fallback arm.
+///
+/// There is no `RateLimited` variant: Kalshi documents no WebSocket error
+/// code for rate limiting, so such a reply would surface as `Unknown(code)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WsErrorCode {
+    /// 1: the server could not process the message.
+    UnableToProcess,
+    /// 2: `params` missing.
+    ParamsRequired,
+    /// 3: `channels` missing.
+    ChannelsRequired,
+    /// 4: `sids` missing.
+    SidsRequired,
+    /// 5: unknown `cmd`.
+    UnknownCommand,
+    /// 6: already subscribed to this channel and market.
+    AlreadySubscribed,
+    /// 7: no subscription with this sid.
+    UnknownSid,
+    /// 8: unknown channel name.
+    InvalidChannel,
+    /// 9: the channel or command needs an authenticated connection.
+    NotAuthorized,
+    /// 10: the channel failed.
+    ChannelError,
+    /// 11: a parameter is invalid.
+    InvalidParameter,
+    /// 12: exactly one sid is required.
+    ExactlyOneSidRequired,
+    /// 13: unsupported update action.
+    UnsupportedAction,
+    /// 14: market ticker missing.
+    MarketTickerRequired,
+    /// 15: update `action` missing.
+    ActionRequired,
+    /// 16: no such market.
+    MarketNotFound,
+    /// 17: server-side failure.
+    InternalError,
+    /// 18: the command timed out.
+    CommandTimeout,
+    /// 19: `shard_factor` must be greater than zero.
+    InvalidShardFactor,
+    /// 20: `shard_key` was set without `shard_factor`.
+    ShardFactorRequired,
+    /// 21: `shard_key` must be at least zero and below `shard_factor`.
+    InvalidShardKey,
+    /// 22: `shard_factor` must be at most 100.
+    ShardFactorTooLarge,
+    /// A code this crate does not recognise, kept as sent.
+    Unknown(i64),
+}
+
+impl WsErrorCode {
+    /// The numeric code sent on the wire.
+    pub fn as_i64(self) -> i64 {
+        match self {
+            WsErrorCode::UnableToProcess => 1,
+            WsErrorCode::ParamsRequired => 2,
+            WsErrorCode::ChannelsRequired => 3,
+            WsErrorCode::SidsRequired => 4,
+            WsErrorCode::UnknownCommand => 5,
+            WsErrorCode::AlreadySubscribed => 6,
+            WsErrorCode::UnknownSid => 7,
+            WsErrorCode::InvalidChannel => 8,
+            WsErrorCode::NotAuthorized => 9,
+            WsErrorCode::ChannelError => 10,
+            WsErrorCode::InvalidParameter => 11,
+            WsErrorCode::ExactlyOneSidRequired => 12,
+            WsErrorCode::UnsupportedAction => 13,
+            WsErrorCode::MarketTickerRequired => 14,
+            WsErrorCode::ActionRequired => 15,
+            WsErrorCode::MarketNotFound => 16,
+            WsErrorCode::InternalError => 17,
+            WsErrorCode::CommandTimeout => 18,
+            WsErrorCode::InvalidShardFactor => 19,
+            WsErrorCode::ShardFactorRequired => 20,
+            WsErrorCode::InvalidShardKey => 21,
+            WsErrorCode::ShardFactorTooLarge => 22,
+            WsErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<i64> for WsErrorCode {
+    fn from(code: i64) -> Self {
+        match code {
+            1 => WsErrorCode::UnableToProcess,
+            2 => WsErrorCode::ParamsRequired,
+            3 => WsErrorCode::ChannelsRequired,
+            4 => WsErrorCode::SidsRequired,
+            5 => WsErrorCode::UnknownCommand,
+            6 => WsErrorCode::AlreadySubscribed,
+            7 => WsErrorCode::UnknownSid,
+            8 => WsErrorCode::InvalidChannel,
+            9 => WsErrorCode::NotAuthorized,
+            10 => WsErrorCode::ChannelError,
+            11 => WsErrorCode::InvalidParameter,
+            12 => WsErrorCode::ExactlyOneSidRequired,
+            13 => WsErrorCode::UnsupportedAction,
+            14 => WsErrorCode::MarketTickerRequired,
+            15 => WsErrorCode::ActionRequired,
+            16 => WsErrorCode::MarketNotFound,
+            17 => WsErrorCode::InternalError,
+            18 => WsErrorCode::CommandTimeout,
+            19 => WsErrorCode::InvalidShardFactor,
+            20 => WsErrorCode::ShardFactorRequired,
+            21 => WsErrorCode::InvalidShardKey,
+            22 => WsErrorCode::ShardFactorTooLarge,
+            other => WsErrorCode::Unknown(other),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum WsMessage {
     Subscribed {
@@ -2054,9 +2266,14 @@ pub enum WsMessage {
         error: WsError,
     },
     Data(WsDataMessage),
+    /// A message type this crate does not model yet.
     Unknown {
         msg_type: WsMsgType,
+        /// The `msg` payload, if present.
         raw: Option<Box<RawValue>>,
+        /// The full envelope (`type`, `id`, `sid`, `seq`, `msg`, ...), when
+        /// parsed via [`WsMessage::from_bytes`].
+        envelope: Option<Box<RawValue>>,
     },
 }
 
@@ -2285,9 +2502,13 @@ pub enum WsMessageRef<'a> {
         error: WsErrorRef<'a>,
     },
     Data(WsDataMessageRef<'a>),
+    /// A message type this crate does not model yet.
     Unknown {
         msg_type: WsMsgType,
+        /// The `msg` payload, if present.
         raw: Option<&'a RawValue>,
+        /// The full envelope, when parsed via [`WsMessageRef::from_bytes`].
+        envelope: Option<&'a RawValue>,
     },
 }
 
@@ -2309,14 +2530,20 @@ impl<'a> WsMessageRef<'a> {
                 error: error.into_owned(),
             },
             WsMessageRef::Data(data) => WsMessage::Data(data.into_owned()),
-            WsMessageRef::Unknown { msg_type, raw } => {
-                let raw_owned = match raw {
-                    Some(value) => Some(serde_json::from_str::<Box<RawValue>>(value.get())?),
-                    None => None,
+            WsMessageRef::Unknown {
+                msg_type,
+                raw,
+                envelope,
+            } => {
+                let to_owned = |value: Option<&RawValue>| -> Result<_, serde_json::Error> {
+                    value
+                        .map(|value| RawValue::from_string(value.get().to_owned()))
+                        .transpose()
                 };
                 WsMessage::Unknown {
                     msg_type,
-                    raw: raw_owned,
+                    raw: to_owned(raw)?,
+                    envelope: to_owned(envelope)?,
                 }
             }
         };
@@ -2346,6 +2573,24 @@ impl WsRawEvent {
         std::str::from_utf8(&self.bytes).ok()
     }
 
+    /// Frame size in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Read only the `"type"` field, skipping the payload without building it.
+    ///
+    /// Use to route or count frames before paying for
+    /// [`parse_borrowed`](Self::parse_borrowed). Returns `None` for invalid
+    /// JSON or a frame without a `type`.
+    pub fn peek_type(&self) -> Option<WsMsgType> {
+        peek_msg_type(&self.bytes)
+    }
+
     pub fn parse_owned(&self) -> Result<WsMessage, KalshiError> {
         WsMessage::from_bytes(&self.bytes)
     }
@@ -2355,6 +2600,18 @@ impl WsRawEvent {
     }
 }
 
+/// The `"type"` field of a frame, or `None` for invalid JSON or no `type`.
+pub(crate) fn peek_msg_type(bytes: &[u8]) -> Option<WsMsgType> {
+    #[derive(Deserialize)]
+    struct TypeOnly {
+        #[serde(rename = "type")]
+        msg_type: WsMsgType,
+    }
+    serde_json::from_slice::<TypeOnly>(bytes)
+        .ok()
+        .map(|frame| frame.msg_type)
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub(crate) struct WsSubscribeCmd {
     pub id: u64,
@@ -2463,6 +2720,30 @@ pub(crate) fn validate_subscription(params: &WsSubscriptionParams) -> Result<(),
     let has_any_market_tickers = has_market_ticker || has_market_tickers;
     let has_any_market_ids = has_market_id || has_market_ids;
 
+    if params.market_tickers.as_ref().is_some_and(Vec::is_empty) {
+        return Err(KalshiError::InvalidParams(
+            "subscribe: market_tickers must not be empty".to_string(),
+        ));
+    }
+    if params.market_ids.as_ref().is_some_and(Vec::is_empty) {
+        return Err(KalshiError::InvalidParams(
+            "subscribe: market_ids must not be empty".to_string(),
+        ));
+    }
+
+    if let Some(event_tickers) = &params.event_tickers {
+        if event_tickers.is_empty() {
+            return Err(KalshiError::InvalidParams(
+                "subscribe: event_tickers must not be empty".to_string(),
+            ));
+        }
+        if let Some(channel) = params.channels.iter().find(|c| !c.supports_event_tickers()) {
+            return Err(KalshiError::InvalidParams(format!(
+                "subscribe: {channel} does not support event_tickers"
+            )));
+        }
+    }
+
     if has_market_ticker && has_market_tickers {
         return Err(KalshiError::InvalidParams(
             "subscribe: provide at most one of market_ticker or market_tickers".to_string(),
@@ -3053,7 +3334,16 @@ impl WsMessage {
         match serde_json::from_slice::<WsWireMessage>(bytes) {
             Ok(wire) => Ok(wire.into_message()),
             Err(first_err) => match serde_json::from_slice::<WsEnvelope>(bytes) {
-                Ok(env) => env.into_message(),
+                Ok(env) => env.into_message().map(|msg| match msg {
+                    WsMessage::Unknown { msg_type, raw, .. } => WsMessage::Unknown {
+                        msg_type,
+                        raw,
+                        envelope: std::str::from_utf8(bytes)
+                            .ok()
+                            .and_then(|text| RawValue::from_string(text.trim().to_owned()).ok()),
+                    },
+                    other => other,
+                }),
                 Err(second_err) => Err(KalshiError::parse_reason(
                     "websocket message",
                     bytes,
@@ -3072,12 +3362,272 @@ impl WsMessage {
     }
 }
 
+impl WsMessage {
+    /// Look up a top-level envelope field (e.g. `"id"`, `"sid"`) of an
+    /// [`Unknown`](Self::Unknown) message without re-parsing the payload.
+    ///
+    /// Returns `None` for typed messages or when the field is absent.
+    pub fn envelope_field(&self, key: &str) -> Option<&RawValue> {
+        let WsMessage::Unknown {
+            envelope: Some(envelope),
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let fields: BTreeMap<&str, &RawValue> = serde_json::from_str(envelope.get()).ok()?;
+        fields.get(key).copied()
+    }
+
+    /// The message's `type`, as [`to_wire_json`](Self::to_wire_json) writes
+    /// it. Both `multivariate` and `multivariate_lookup` frames report as
+    /// [`WsMsgType::MultivariateLookup`], since they parse to the same variant.
+    pub fn msg_type(&self) -> WsMsgType {
+        match self {
+            WsMessage::Subscribed { .. } => WsMsgType::Subscribed,
+            WsMessage::Unsubscribed { .. } => WsMsgType::Unsubscribed,
+            WsMessage::ListSubscriptions { .. } => WsMsgType::ListSubscriptions,
+            WsMessage::Ok { .. } => WsMsgType::Ok,
+            WsMessage::Error { .. } => WsMsgType::Error,
+            WsMessage::Data(data) => data.msg_type(),
+            WsMessage::Unknown { msg_type, .. } => msg_type.clone(),
+        }
+    }
+
+    /// Re-encode as the `{type, id, sid, seq, msg}` envelope Kalshi sends.
+    ///
+    /// The output parses back into an equivalent message via
+    /// [`from_bytes`](Self::from_bytes); fields not modelled by the typed
+    /// payloads are not reproduced.
+    pub fn to_wire_json(&self) -> Result<String, KalshiError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+impl WsDataMessage {
+    /// Subscription id from the envelope, regardless of variant.
+    pub fn sid(&self) -> Option<u64> {
+        match self {
+            WsDataMessage::Ticker { sid, .. }
+            | WsDataMessage::Trade { sid, .. }
+            | WsDataMessage::OrderbookSnapshot { sid, .. }
+            | WsDataMessage::OrderbookDelta { sid, .. }
+            | WsDataMessage::Fill { sid, .. }
+            | WsDataMessage::MarketPositions { sid, .. }
+            | WsDataMessage::MarketLifecycleV2 { sid, .. }
+            | WsDataMessage::EventLifecycle { sid, .. }
+            | WsDataMessage::Multivariate { sid, .. }
+            | WsDataMessage::Communications { sid, .. }
+            | WsDataMessage::OrderGroupUpdates { sid, .. }
+            | WsDataMessage::UserOrder { sid, .. } => *sid,
+        }
+    }
+
+    /// Sequence number from the envelope, regardless of variant.
+    pub fn seq(&self) -> Option<u64> {
+        match self {
+            WsDataMessage::Ticker { seq, .. }
+            | WsDataMessage::Trade { seq, .. }
+            | WsDataMessage::OrderbookSnapshot { seq, .. }
+            | WsDataMessage::OrderbookDelta { seq, .. }
+            | WsDataMessage::Fill { seq, .. }
+            | WsDataMessage::MarketPositions { seq, .. }
+            | WsDataMessage::MarketLifecycleV2 { seq, .. }
+            | WsDataMessage::EventLifecycle { seq, .. }
+            | WsDataMessage::Multivariate { seq, .. }
+            | WsDataMessage::Communications { seq, .. }
+            | WsDataMessage::OrderGroupUpdates { seq, .. }
+            | WsDataMessage::UserOrder { seq, .. } => *seq,
+        }
+    }
+
+    /// The message's `type`; see [`WsMessage::msg_type`].
+    pub fn msg_type(&self) -> WsMsgType {
+        match self {
+            WsDataMessage::Ticker { .. } => WsMsgType::Ticker,
+            WsDataMessage::Trade { .. } => WsMsgType::Trade,
+            WsDataMessage::OrderbookSnapshot { .. } => WsMsgType::OrderbookSnapshot,
+            WsDataMessage::OrderbookDelta { .. } => WsMsgType::OrderbookDelta,
+            WsDataMessage::Fill { .. } => WsMsgType::Fill,
+            WsDataMessage::MarketPositions { .. } => WsMsgType::MarketPositions,
+            WsDataMessage::MarketLifecycleV2 { .. } => WsMsgType::MarketLifecycleV2,
+            WsDataMessage::EventLifecycle { .. } => WsMsgType::EventLifecycle,
+            WsDataMessage::Multivariate { .. } => WsMsgType::MultivariateLookup,
+            WsDataMessage::Communications { msg, .. } => match msg {
+                WsCommunications::RfqCreated(_) => WsMsgType::RfqCreated,
+                WsCommunications::RfqDeleted(_) => WsMsgType::RfqDeleted,
+                WsCommunications::QuoteCreated(_) => WsMsgType::QuoteCreated,
+                WsCommunications::QuoteAccepted(_) => WsMsgType::QuoteAccepted,
+                WsCommunications::QuoteExecuted(_) => WsMsgType::QuoteExecuted,
+            },
+            WsDataMessage::OrderGroupUpdates { .. } => WsMsgType::OrderGroupUpdates,
+            WsDataMessage::UserOrder { .. } => WsMsgType::UserOrder,
+        }
+    }
+
+    /// Re-encode as the `{type, sid, seq, msg}` envelope Kalshi sends.
+    pub fn to_wire_json(&self) -> Result<String, KalshiError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+fn serialize_envelope<S, T>(
+    serializer: S,
+    msg_type: &str,
+    id: Option<u64>,
+    sid: Option<u64>,
+    seq: Option<u64>,
+    msg: Option<&T>,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: Serialize + ?Sized,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(None)?;
+    map.serialize_entry("type", msg_type)?;
+    if let Some(id) = id {
+        map.serialize_entry("id", &id)?;
+    }
+    if let Some(sid) = sid {
+        map.serialize_entry("sid", &sid)?;
+    }
+    if let Some(seq) = seq {
+        map.serialize_entry("seq", &seq)?;
+    }
+    if let Some(msg) = msg {
+        map.serialize_entry("msg", msg)?;
+    }
+    map.end()
+}
+
+impl Serialize for WsMessage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            WsMessage::Subscribed { id, sid } => {
+                let msg = sid.map(|sid| serde_json::json!({ "sid": sid }));
+                serialize_envelope(serializer, "subscribed", *id, None, None, msg.as_ref())
+            }
+            WsMessage::Unsubscribed { id, sid } => {
+                serialize_envelope::<_, ()>(serializer, "unsubscribed", *id, *sid, None, None)
+            }
+            WsMessage::ListSubscriptions { id, subscriptions } => {
+                serialize_envelope(serializer, "ok", *id, None, None, Some(subscriptions))
+            }
+            WsMessage::Ok { id } => {
+                serialize_envelope::<_, ()>(serializer, "ok", *id, None, None, None)
+            }
+            WsMessage::Error { id, error } => {
+                serialize_envelope(serializer, "error", *id, None, None, Some(error))
+            }
+            WsMessage::Data(data) => data.serialize(serializer),
+            WsMessage::Unknown {
+                envelope: Some(envelope),
+                ..
+            } => envelope.serialize(serializer),
+            WsMessage::Unknown { msg_type, raw, .. } => serialize_envelope(
+                serializer,
+                msg_type.as_str(),
+                None,
+                None,
+                None,
+                raw.as_deref(),
+            ),
+        }
+    }
+}
+
+impl Serialize for WsDataMessage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            WsDataMessage::Ticker { sid, seq, msg } => {
+                serialize_envelope(serializer, "ticker", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::Trade { sid, seq, msg } => {
+                serialize_envelope(serializer, "trade", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::OrderbookSnapshot { sid, seq, msg } => serialize_envelope(
+                serializer,
+                "orderbook_snapshot",
+                None,
+                *sid,
+                *seq,
+                Some(msg),
+            ),
+            WsDataMessage::OrderbookDelta { sid, seq, msg } => {
+                serialize_envelope(serializer, "orderbook_delta", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::Fill { sid, seq, msg } => {
+                serialize_envelope(serializer, "fill", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::MarketPositions { sid, seq, msg } => {
+                serialize_envelope(serializer, "market_positions", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::MarketLifecycleV2 { sid, seq, msg } => serialize_envelope(
+                serializer,
+                "market_lifecycle_v2",
+                None,
+                *sid,
+                *seq,
+                Some(msg),
+            ),
+            WsDataMessage::EventLifecycle { sid, seq, msg } => {
+                serialize_envelope(serializer, "event_lifecycle", None, *sid, *seq, Some(msg))
+            }
+            WsDataMessage::Multivariate { sid, seq, msg } => serialize_envelope(
+                serializer,
+                "multivariate_lookup",
+                None,
+                *sid,
+                *seq,
+                Some(msg),
+            ),
+            WsDataMessage::Communications { sid, seq, msg } => match msg {
+                WsCommunications::RfqCreated(msg) => {
+                    serialize_envelope(serializer, "rfq_created", None, *sid, *seq, Some(msg))
+                }
+                WsCommunications::RfqDeleted(msg) => {
+                    serialize_envelope(serializer, "rfq_deleted", None, *sid, *seq, Some(msg))
+                }
+                WsCommunications::QuoteCreated(msg) => {
+                    serialize_envelope(serializer, "quote_created", None, *sid, *seq, Some(msg))
+                }
+                WsCommunications::QuoteAccepted(msg) => {
+                    serialize_envelope(serializer, "quote_accepted", None, *sid, *seq, Some(msg))
+                }
+                WsCommunications::QuoteExecuted(msg) => {
+                    serialize_envelope(serializer, "quote_executed", None, *sid, *seq, Some(msg))
+                }
+            },
+            WsDataMessage::OrderGroupUpdates { sid, seq, msg } => serialize_envelope(
+                serializer,
+                "order_group_updates",
+                None,
+                *sid,
+                *seq,
+                Some(msg),
+            ),
+            WsDataMessage::UserOrder { sid, seq, msg } => {
+                serialize_envelope(serializer, "user_order", None, *sid, *seq, Some(msg))
+            }
+        }
+    }
+}
+
 impl<'a> WsMessageRef<'a> {
     pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, KalshiError> {
         match serde_json::from_slice::<WsWireMessageRef<'a>>(bytes) {
             Ok(wire) => Ok(wire.into_message()),
             Err(first_err) => match serde_json::from_slice::<WsEnvelopeRef<'a>>(bytes) {
-                Ok(env) => env.into_message(),
+                Ok(env) => env.into_message().map(|msg| match msg {
+                    WsMessageRef::Unknown { msg_type, raw, .. } => WsMessageRef::Unknown {
+                        msg_type,
+                        raw,
+                        envelope: serde_json::from_slice::<&'a RawValue>(bytes).ok(),
+                    },
+                    other => other,
+                }),
                 Err(second_err) => Err(KalshiError::parse_reason(
                     "websocket borrowed message",
                     bytes,
@@ -3101,6 +3651,71 @@ mod tests {
     use super::*;
     use bytes::Bytes;
 
+    #[test]
+    fn normalized_sorts_and_dedups_channels_and_tickers() {
+        let params = WsSubscriptionParams {
+            channels: vec![WsChannel::Trade, WsChannel::Ticker, WsChannel::Trade],
+            market_tickers: Some(vec!["B".to_string(), "A".to_string(), "B".to_string()]),
+            ..Default::default()
+        };
+        let normalized = params.normalized();
+        assert_eq!(
+            normalized.channels,
+            vec![WsChannel::Ticker, WsChannel::Trade]
+        );
+        assert_eq!(
+            normalized.market_tickers,
+            Some(vec!["A".to_string(), "B".to_string()])
+        );
+    }
+
+    #[test]
+    fn validate_subscription_rejects_empty_ticker_lists() {
+        for params in [
+            WsSubscriptionParams {
+                channels: vec![WsChannel::Ticker],
+                market_tickers: Some(Vec::new()),
+                ..Default::default()
+            },
+            WsSubscriptionParams {
+                channels: vec![WsChannel::Ticker],
+                market_ids: Some(Vec::new()),
+                ..Default::default()
+            },
+        ] {
+            let err = validate_subscription(&params).unwrap_err();
+            assert!(err.to_string().contains("must not be empty"));
+        }
+    }
+
+    #[test]
+    fn validate_subscription_checks_event_ticker_scope() {
+        let params = WsSubscriptionParams {
+            channels: vec![WsChannel::Ticker, WsChannel::Trade],
+            event_tickers: Some(vec!["FED-23DEC".to_string()]),
+            ..Default::default()
+        };
+        assert!(validate_subscription(&params).is_ok());
+
+        let params = WsSubscriptionParams {
+            channels: vec![WsChannel::Ticker, WsChannel::Fill],
+            event_tickers: Some(vec!["FED-23DEC".to_string()]),
+            ..Default::default()
+        };
+        let err = validate_subscription(&params).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("fill does not support event_tickers")
+        );
+
+        let params = WsSubscriptionParams {
+            channels: vec![WsChannel::Ticker],
+            event_tickers: Some(Vec::new()),
+            ..Default::default()
+        };
+        assert!(validate_subscription(&params).is_err());
+    }
+
     #[test]
     fn validate_subscription_requires_market_tickers_for_orderbook_delta() {
         let params = WsSubscriptionParams {
@@ -3226,9 +3841,11 @@ mod tests {
             WsMessage::Unknown {
                 msg_type: WsMsgType::Unknown(value),
                 raw,
+                envelope,
             } => {
                 assert_eq!(value, "mystery");
                 assert!(raw.is_some());
+                assert!(envelope.is_none());
             }
             _ => panic!("expected unknown message"),
         }
@@ -3270,6 +3887,7 @@ mod tests {
             WsMessage::Unknown {
                 msg_type: WsMsgType::Unknown(value),
                 raw,
+                ..
             } => {
                 assert_eq!(value, "mystery");
                 assert!(raw.is_some());
@@ -3278,6 +3896,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ws_message_unknown_exposes_envelope_fields() {
+        let json = r#"{"type":"mystery","id":3,"sid":17,"seq":9,"extra":true,"msg":{"foo":1}}"#;
+        let msg = WsMessage::from_bytes(json.as_bytes()).unwrap();
+        assert_eq!(msg.envelope_field("sid").map(RawValue::get), Some("17"));
+        assert_eq!(msg.envelope_field("extra").map(RawValue::get), Some("true"));
+        assert!(msg.envelope_field("missing").is_none());
+        assert_eq!(msg.to_wire_json().unwrap(), json);
+
+        let env: WsEnvelope = serde_json::from_str(json).unwrap();
+        let msg = env.into_message().unwrap();
+        assert!(msg.envelope_field("seq").is_none());
+    }
+
     #[test]
     fn ws_message_from_bytes_invalid_json_exposes_raw_bytes_and_reason() {
         let raw = br#"{"type":"ticker","msg":{"market_ticker":"TEST"}"#;
@@ -3367,6 +3999,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn ws_raw_event_peek_type_skips_payload() {
+        let raw = WsRawEvent::new(Bytes::from_static(
+            br#"{"sid":1,"msg":{"market_ticker":"T","yes":[[1,2]]},"type":"orderbook_snapshot"}"#,
+        ));
+        assert_eq!(raw.peek_type(), Some(WsMsgType::OrderbookSnapshot));
+        assert_eq!(raw.len(), raw.as_slice().len());
+
+        let raw = WsRawEvent::new(Bytes::from_static(br#"{"type":"brand_new","msg":{}}"#));
+        assert_eq!(
+            raw.peek_type(),
+            Some(WsMsgType::Unknown("brand_new".to_string()))
+        );
+
+        assert_eq!(WsRawEvent::new(Bytes::from_static(b"{}")).peek_type(), None);
+        assert_eq!(
+            WsRawEvent::new(Bytes::from_static(b"not json")).peek_type(),
+            None
+        );
+    }
+
     #[test]
     fn ws_orderbook_delta_side_parse() {
         let json = r#"{
@@ -3463,4 +4116,43 @@ mod tests {
         };
         assert!(validate_subscription(&params).is_err());
     }
+
+    #[test]
+    fn ws_error_codes_map_known_and_unknown_numbers() {
+        for code in 1..=22 {
+            let typed = WsErrorCode::from(code);
+            assert!(!matches!(typed, WsErrorCode::Unknown(_)), "{code}");
+            assert_eq!(typed.as_i64(), code);
+        }
+        assert_eq!(WsErrorCode::from(42), WsErrorCode::Unknown(42));
+        assert_eq!(WsErrorCode::Unknown(42).as_i64(), 42);
+
+        let err: WsError =
+            serde_json::from_str(r#"{"code":6,"msg":"Already subscribed"}"#).unwrap();
+        assert_eq!(err.error_code(), Some(WsErrorCode::AlreadySubscribed));
+        assert_eq!(err.message.as_deref(), Some("Already subscribed"));
+        let err: WsErrorRef<'_> =
+            serde_json::from_str(r#"{"code":9,"msg":"Authentication required"}"#).unwrap();
+        assert_eq!(err.error_code(), Some(WsErrorCode::NotAuthorized));
+        assert_eq!(err.message.as_deref(), Some("Authentication required"));
+        assert!(err.into_owned().is_auth_rejection());
+        let err: WsError = serde_json::from_str(r#"{"msg":"no code"}"#).unwrap();
+        assert_eq!(err.error_code(), None);
+    }
+
+    #[test]
+    fn ws_channel_from_str_round_trips_every_channel() {
+        for &channel in WsChannel::all() {
+            assert_eq!(channel.as_str().parse::<WsChannel>(), Ok(channel));
+            assert_eq!(
+                serde_json::to_value(channel).expect("serialize"),
+                channel.as_str()
+            );
+        }
+        assert_eq!(WsChannel::all().len(), 10);
+        assert_eq!(
+            "orderbook".parse::<WsChannel>(),
+            Err(ParseWsChannelError("orderbook".to_string()))
+        );
+    }
 }
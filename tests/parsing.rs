@@ -2,16 +2,19 @@
 
 pub(crate) use cargo_husky as _;
 use kalshi_fast::{
-    ApplySubaccountTransferResponse, BuySell, CreateOrderRequest, CreateSubaccountResponse,
-    ErrorResponse, EventData, EventMetadata, EventStatus, GetAccountApiLimitsResponse,
-    GetEventsParams, GetExchangeAnnouncementsResponse, GetExchangeScheduleResponse,
-    GetExchangeStatusResponse, GetFillsParams, GetFillsResponse, GetMarketOrderbookResponse,
-    GetMarketsParams, GetOrdersParams, GetPositionsParams, GetSeriesFeeChangesParams,
-    GetSeriesFeeChangesResponse, GetSettlementsParams, GetSettlementsResponse,
-    GetSubaccountBalancesResponse, GetSubaccountTransfersParams, GetSubaccountTransfersResponse,
-    GetTradesParams, GetTradesResponse, GetUserDataTimestampResponse, MarketMetadata, MarketStatus,
-    MarketStatusConversionError, MarketStatusQuery, MveFilter, OrderStatus, OrderType,
-    PositionCountFilter, PriceRange, SelfTradePreventionType, TimeInForce, YesNo,
+    AmendOrderRequest, ApplySubaccountTransferResponse, BatchCancelOrdersRequest,
+    BatchCancelOrdersRequestOrder, BatchCreateOrdersRequest, BuySell, CreateOrderGroupRequest,
+    CreateOrderRequest, CreateSubaccountResponse, DecreaseOrderRequest, ErrorResponse, EventData,
+    EventMetadata, EventStatus, GetAccountApiLimitsResponse, GetEventsParams,
+    GetExchangeAnnouncementsResponse, GetExchangeScheduleResponse, GetExchangeStatusResponse,
+    GetFillsParams, GetFillsResponse, GetMarketOrderbookResponse, GetMarketsParams,
+    GetOrdersParams, GetPositionsParams, GetSeriesFeeChangesParams, GetSeriesFeeChangesResponse,
+    GetSettlementsParams, GetSettlementsResponse, GetSubaccountBalancesResponse,
+    GetSubaccountTransfersParams, GetSubaccountTransfersResponse, GetTradesParams,
+    GetTradesResponse, GetUserDataTimestampResponse, KalshiError, KalshiErrorCode, MarketMetadata,
+    MarketStatus, MarketStatusConversionError, MarketStatusQuery, MveFilter, OrderStatus,
+    OrderType, PositionCountFilter, PriceRange, SelfTradePreventionType, TimeInForce,
+    UpdateOrderGroupLimitRequest, YesNo,
 };
 
 // ============================================================================
@@ -391,6 +394,41 @@ fn error_response_deserializes_details_string() {
     assert_eq!(err.details.as_deref(), Some("extra info"));
 }
 
+#[test]
+fn error_response_codes_map_known_and_unknown_strings() {
+    let err: ErrorResponse =
+        serde_json::from_str(r#"{"code":"insufficient_balance","message":"no funds"}"#).unwrap();
+    assert_eq!(err.error_code(), Some(KalshiErrorCode::InsufficientBalance));
+    assert_eq!(
+        KalshiErrorCode::from("market_closed"),
+        KalshiErrorCode::MarketClosed
+    );
+    assert_eq!(KalshiErrorCode::MarketClosed.as_str(), "market_closed");
+
+    let unknown = KalshiErrorCode::from("brand_new_code");
+    assert_eq!(
+        unknown,
+        KalshiErrorCode::Unknown("brand_new_code".to_string())
+    );
+    assert_eq!(unknown.to_string(), "brand_new_code");
+
+    let http = KalshiError::Http {
+        status: reqwest::StatusCode::BAD_REQUEST,
+        api_error: Some(err),
+        raw_body: String::new(),
+        request_id: None,
+        retry_after: None,
+    };
+    assert_eq!(
+        http.error_code(),
+        Some(KalshiErrorCode::InsufficientBalance)
+    );
+    assert_eq!(
+        KalshiError::InvalidParams("x".to_string()).error_code(),
+        None
+    );
+}
+
 #[test]
 fn price_range_deserializes_with_aliases() {
     let json = r#"{"min_price":"0.10","max_price":"0.90","increment":"0.05"}"#;
@@ -872,6 +910,50 @@ fn create_order_response_deserializes() {
     assert_eq!(resp.order.ticker, "MKT-1");
 }
 
+#[test]
+fn get_order_response_deserializes() {
+    let json = r#"{
+        "order": {
+            "order_id": "ord-123",
+            "ticker": "MKT-1",
+            "status": "executed",
+            "fill_count_fp": "3.00",
+            "remaining_count_fp": "0.00"
+        }
+    }"#;
+
+    let resp: kalshi_fast::GetOrderResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(resp.order.order_id, "ord-123");
+    assert_eq!(resp.order.status, Some(OrderStatus::Executed));
+}
+
+#[test]
+fn amend_order_response_deserializes() {
+    let json = r#"{
+        "old_order": {"order_id": "ord-123", "ticker": "MKT-1", "status": "canceled"},
+        "order": {"order_id": "ord-456", "ticker": "MKT-1", "status": "resting"}
+    }"#;
+
+    let resp: kalshi_fast::AmendOrderResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(resp.old_order.order_id, "ord-123");
+    assert_eq!(resp.order_id(), "ord-456");
+    assert_eq!(resp.order.status, Some(OrderStatus::Resting));
+}
+
+#[test]
+fn decrease_order_response_deserializes() {
+    let json = r#"{
+        "order": {"order_id": "ord-123", "ticker": "MKT-1", "status": "resting"},
+        "reduced_by": 3,
+        "reduced_by_fp": "3.00"
+    }"#;
+
+    let resp: kalshi_fast::DecreaseOrderResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(resp.order.order_id, "ord-123");
+    assert_eq!(resp.reduced_by, 3);
+    assert_eq!(resp.reduced_by_fp, "3.00");
+}
+
 #[test]
 fn cancel_order_response_deserializes() {
     let json = r#"{
@@ -906,6 +988,42 @@ fn get_market_orderbook_response_deserializes() {
     assert!(resp.orderbook_fp.is_some());
 }
 
+#[test]
+fn orderbook_best_bid_ask_prefers_fixed_point_levels() {
+    let json = r#"{
+        "orderbook": {
+            "yes": [[40, 10], [45, 0]],
+            "no": [[52, 5]],
+            "yes_dollars": [],
+            "no_dollars": []
+        },
+        "orderbook_fp": {
+            "yes_dollars": [["0.4100", "0.50"], ["0.4300", "0.00"]],
+            "no_dollars": [["0.5300", "2.25"]]
+        }
+    }"#;
+    let mut resp: GetMarketOrderbookResponse = serde_json::from_str(json).unwrap();
+
+    assert_eq!(resp.orderbook.best_bid_ask(), Some((40, 48)));
+    assert_eq!(resp.best_bid_ask(), Some((41, 47)));
+    assert_eq!(
+        resp.fixed_levels(YesNo::No),
+        vec![("0.5300".to_string(), "2.25".to_string())]
+    );
+
+    resp.orderbook_fp = None;
+    assert_eq!(resp.best_bid_ask(), Some((40, 48)));
+    assert_eq!(
+        resp.fixed_levels(YesNo::Yes),
+        vec![
+            ("0.4000".to_string(), "10.00".to_string()),
+            ("0.4500".to_string(), "0.00".to_string()),
+        ]
+    );
+    resp.orderbook.no.clear();
+    assert_eq!(resp.best_bid_ask(), None);
+}
+
 #[test]
 fn get_trades_response_deserializes() {
     let json = r#"{
@@ -1244,6 +1362,33 @@ fn get_orders_params_validates_event_ticker_count() {
     assert!(params.validate().is_err());
 }
 
+#[test]
+fn trades_and_orders_params_reject_bad_ts_windows() {
+    let inverted = GetTradesParams {
+        min_ts: Some(200),
+        max_ts: Some(100),
+        ..Default::default()
+    };
+    let err = inverted.validate().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("min_ts (200) must be <= max_ts (100)")
+    );
+
+    let negative = GetOrdersParams {
+        max_ts: Some(-1),
+        ..Default::default()
+    };
+    assert!(negative.validate().is_err());
+
+    let ok = GetOrdersParams {
+        min_ts: Some(100),
+        max_ts: Some(100),
+        ..Default::default()
+    };
+    assert!(ok.validate().is_ok());
+}
+
 #[test]
 fn get_orders_params_validates_subaccount_bounds() {
     let params = GetOrdersParams {
@@ -1368,6 +1513,205 @@ fn create_order_request_validate_sell_position_floor() {
     assert!(req.validate().is_err());
 }
 
+#[test]
+fn amend_order_request_validate_rules() {
+    let base = AmendOrderRequest {
+        ticker: "TICK-1".into(),
+        side: YesNo::Yes,
+        action: BuySell::Buy,
+        count: Some(1),
+        yes_price: Some(10),
+        ..Default::default()
+    };
+    assert!(base.validate().is_ok());
+
+    let no_count = AmendOrderRequest {
+        count: None,
+        ..base.clone()
+    };
+    assert!(no_count.validate().is_err());
+
+    let both_yes = AmendOrderRequest {
+        yes_price_dollars: Some("0.10".into()),
+        ..base.clone()
+    };
+    assert!(both_yes.validate().is_err());
+
+    let yes_and_no = AmendOrderRequest {
+        no_price: Some(90),
+        ..base.clone()
+    };
+    assert!(yes_and_no.validate().is_err());
+
+    let subaccount = AmendOrderRequest {
+        subaccount: Some(33),
+        ..base.clone()
+    };
+    assert!(subaccount.validate().is_err());
+}
+
+#[test]
+fn order_group_limit_validate_rules() {
+    assert!(UpdateOrderGroupLimitRequest::fp("25.00").validate().is_ok());
+    assert!(
+        UpdateOrderGroupLimitRequest {
+            contracts_limit: Some(25),
+            contracts_limit_fp: Some("25".to_string()),
+        }
+        .validate()
+        .is_ok()
+    );
+    for bad in [
+        UpdateOrderGroupLimitRequest::default(),
+        UpdateOrderGroupLimitRequest::fp("2.5"),
+        UpdateOrderGroupLimitRequest::fp("0"),
+        UpdateOrderGroupLimitRequest::fp("1e3"),
+        UpdateOrderGroupLimitRequest::fp("inf"),
+        UpdateOrderGroupLimitRequest::fp("25.001"),
+        UpdateOrderGroupLimitRequest {
+            contracts_limit: Some(25),
+            contracts_limit_fp: Some("30.00".to_string()),
+        },
+    ] {
+        assert!(
+            matches!(bad.validate(), Err(KalshiError::InvalidParams(_))),
+            "{bad:?}"
+        );
+    }
+
+    let create = CreateOrderGroupRequest {
+        contracts_limit: Some(100),
+        subaccount: Some(33),
+        ..Default::default()
+    };
+    assert!(create.validate().is_err());
+}
+
+#[test]
+fn decrease_order_request_validate_rules() {
+    let by = DecreaseOrderRequest {
+        reduce_by: Some(2),
+        reduce_by_fp: Some("2.00".into()),
+        ..Default::default()
+    };
+    assert!(by.validate().is_ok());
+    let to = DecreaseOrderRequest {
+        reduce_to_fp: Some("1.00".into()),
+        ..Default::default()
+    };
+    assert!(to.validate().is_ok());
+
+    assert!(DecreaseOrderRequest::default().validate().is_err());
+    let both = DecreaseOrderRequest {
+        reduce_by: Some(2),
+        reduce_to: Some(1),
+        ..Default::default()
+    };
+    assert!(both.validate().is_err());
+    let mismatch = DecreaseOrderRequest {
+        reduce_to: Some(1),
+        reduce_to_fp: Some("2.00".into()),
+        ..Default::default()
+    };
+    assert!(mismatch.validate().is_err());
+    let subaccount = DecreaseOrderRequest {
+        reduce_by: Some(1),
+        subaccount: Some(33),
+        ..Default::default()
+    };
+    assert!(subaccount.validate().is_err());
+}
+
+#[test]
+fn batch_create_orders_request_validate_rules() {
+    let order = CreateOrderRequest {
+        ticker: "TICK-1".into(),
+        side: YesNo::Yes,
+        action: BuySell::Buy,
+        count: Some(1),
+        yes_price: Some(10),
+        ..Default::default()
+    };
+    let batch = |orders| BatchCreateOrdersRequest { orders };
+
+    assert!(batch(vec![order.clone(); 20]).validate().is_ok());
+    assert!(batch(vec![order.clone(); 21]).validate().is_err());
+    assert!(batch(Vec::new()).validate().is_err());
+
+    let bad = CreateOrderRequest {
+        count: None,
+        ..order.clone()
+    };
+    match batch(vec![order, bad]).validate() {
+        Err(KalshiError::InvalidParams(msg)) => assert!(msg.starts_with("orders[1]: "), "{msg}"),
+        other => panic!("expected InvalidParams, got {other:?}"),
+    }
+}
+
+#[test]
+fn batch_cancel_orders_request_validate_rules() {
+    let ids = |n: usize| (0..n).map(|i| format!("o-{i}"));
+    let req = BatchCancelOrdersRequest::from_ids(ids(20));
+    assert!(req.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(BatchCancelOrdersRequest::from_ids(["o-1"])).unwrap(),
+        serde_json::json!({"ids": ["o-1"]})
+    );
+
+    assert!(
+        BatchCancelOrdersRequest::from_ids(ids(21))
+            .validate()
+            .is_err()
+    );
+    assert!(
+        BatchCancelOrdersRequest::from_ids(Vec::<String>::new())
+            .validate()
+            .is_err()
+    );
+    assert!(BatchCancelOrdersRequest::default().validate().is_err());
+
+    let mixed = BatchCancelOrdersRequest {
+        ids: Some(ids(19).collect()),
+        orders: Some(vec![
+            BatchCancelOrdersRequestOrder {
+                order_id: "x".into(),
+                subaccount: Some(1),
+            },
+            BatchCancelOrdersRequestOrder {
+                order_id: "y".into(),
+                subaccount: None,
+            },
+        ]),
+    };
+    assert!(mixed.validate().is_err());
+
+    let bad_sub = BatchCancelOrdersRequest {
+        ids: None,
+        orders: Some(vec![BatchCancelOrdersRequestOrder {
+            order_id: "x".into(),
+            subaccount: Some(33),
+        }]),
+    };
+    assert!(bad_sub.validate().is_err());
+}
+
+#[test]
+fn market_candlesticks_params_validate_range() {
+    let params = |start_ts, end_ts, period_interval| kalshi_fast::GetMarketCandlesticksParams {
+        start_ts,
+        end_ts,
+        period_interval,
+        include_latest_before_start: None,
+    };
+    let start = 1_700_000_000;
+    assert!(params(start, start + 5000 * 60, 1).validate().is_ok());
+    assert!(params(start, start + 5001 * 60, 1).validate().is_err());
+    assert!(params(start, start + 5001 * 60, 60).validate().is_ok());
+    assert!(params(start, start + 3600, 5).validate().is_err());
+    assert!(params(start + 1, start, 60).validate().is_err());
+    assert!(params(-1, start, 1440).validate().is_err());
+}
+
 #[test]
 fn create_order_request_validate_ok_with_yes_price() {
     let req = CreateOrderRequest {
@@ -1382,6 +1726,161 @@ fn create_order_request_validate_ok_with_yes_price() {
     assert!(req.validate().is_ok());
 }
 
+#[test]
+fn create_order_request_validate_time_in_force_contradictions() {
+    let base = CreateOrderRequest {
+        ticker: "TICK-1".into(),
+        side: YesNo::Yes,
+        action: BuySell::Buy,
+        count: Some(1),
+        yes_price: Some(10),
+        ..Default::default()
+    };
+
+    let req = CreateOrderRequest {
+        time_in_force: Some(TimeInForce::GoodTillCanceled),
+        expiration_ts: Some(1_700_000_000),
+        ..base.clone()
+    };
+    assert!(req.validate().is_ok());
+
+    let req = CreateOrderRequest {
+        time_in_force: Some(TimeInForce::ImmediateOrCancel),
+        expiration_ts: Some(1_700_000_000),
+        ..base.clone()
+    };
+    let err = req.validate().unwrap_err().to_string();
+    assert!(err.contains("expiration_ts not allowed with time_in_force immediate_or_cancel"));
+
+    let req = CreateOrderRequest {
+        time_in_force: Some(TimeInForce::FillOrKill),
+        post_only: Some(true),
+        ..base.clone()
+    };
+    assert!(
+        req.validate()
+            .unwrap_err()
+            .to_string()
+            .contains("post_only")
+    );
+}
+
+#[test]
+fn create_order_request_market_buy_budget() {
+    let req = CreateOrderRequest::market_buy_budget("TICK-1", YesNo::Yes, 500);
+    assert!(req.validate().is_ok());
+
+    let json = serde_json::to_value(&req).unwrap();
+    assert_eq!(json["type"], "market");
+    assert_eq!(json["action"], "buy");
+    assert_eq!(json["buy_max_cost"], 500);
+    assert!(json.get("yes_price").is_none());
+    assert!(json.get("count").is_none());
+
+    let err = CreateOrderRequest {
+        buy_max_cost: None,
+        ..req.clone()
+    }
+    .validate()
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("must provide count or count_fp"));
+
+    let count_sized = CreateOrderRequest {
+        count: Some(3),
+        buy_max_cost: None,
+        ..req
+    };
+    assert!(count_sized.validate().is_ok());
+}
+
+#[test]
+fn create_order_request_validate_buy_max_cost_rules() {
+    let base = CreateOrderRequest {
+        ticker: "TICK-1".into(),
+        side: YesNo::Yes,
+        action: BuySell::Buy,
+        count: Some(1),
+        yes_price: Some(10),
+        buy_max_cost: Some(500),
+        ..Default::default()
+    };
+    assert!(base.validate().is_ok());
+
+    let cases = [
+        (
+            CreateOrderRequest {
+                action: BuySell::Sell,
+                ..base.clone()
+            },
+            "only allowed on buy orders",
+        ),
+        (
+            CreateOrderRequest {
+                time_in_force: Some(TimeInForce::GoodTillCanceled),
+                ..base.clone()
+            },
+            "implies fill_or_kill",
+        ),
+        (
+            CreateOrderRequest {
+                expiration_ts: Some(1_700_000_000),
+                ..base.clone()
+            },
+            "cannot set expiration_ts",
+        ),
+        (
+            CreateOrderRequest {
+                yes_price: None,
+                no_price: Some(90),
+                ..base.clone()
+            },
+            "opposite side",
+        ),
+        (
+            CreateOrderRequest {
+                post_only: Some(true),
+                ..base.clone()
+            },
+            "cannot be post_only",
+        ),
+        (
+            CreateOrderRequest {
+                buy_max_cost: Some(0),
+                ..base.clone()
+            },
+            "must be > 0",
+        ),
+        (
+            CreateOrderRequest {
+                buy_max_cost: Some(9),
+                ..base.clone()
+            },
+            "below the limit price of one contract ($0.1000)",
+        ),
+        (
+            CreateOrderRequest {
+                yes_price: None,
+                yes_price_dollars: Some("0.1050".into()),
+                buy_max_cost: Some(10),
+                ..base.clone()
+            },
+            "below the limit price",
+        ),
+    ];
+    for (req, expected) in cases {
+        let err = req.validate().unwrap_err().to_string();
+        assert!(err.contains(expected), "{err}");
+    }
+
+    let capped = CreateOrderRequest {
+        count: Some(100),
+        buy_max_cost: Some(10),
+        ..base.clone()
+    };
+    assert!(capped.validate().is_ok());
+}
+
 // ============================================================================
 // Optional Field Deserialization Tests (image_url / color_code)
 // ============================================================================
@@ -1641,6 +2140,17 @@ fn multivariate_collections_and_lookup_responses_deserialize_typed() {
         serde_json::from_str(lookup_json).unwrap();
     assert_eq!(lookup.lookup_points.len(), 1);
     assert_eq!(lookup.lookup_points[0].selected_markets.len(), 1);
+
+    let leg: kalshi_fast::TickerPair = kalshi_fast::WsMultivariateSelectedMarket {
+        event_ticker: "EVT-1".to_string(),
+        market_ticker: "MKT-1".to_string(),
+        side: YesNo::No,
+    }
+    .into();
+    assert_eq!(
+        serde_json::to_value(&leg).unwrap(),
+        serde_json::json!({"market_ticker": "MKT-1", "event_ticker": "EVT-1", "side": "no"})
+    );
 }
 
 #[test]
@@ -1733,3 +2243,233 @@ fn queue_positions_forecast_and_structured_targets_deserialize_typed() {
         Some("politics")
     );
 }
+
+#[test]
+fn market_price_ladder_validates_and_rounds() {
+    let json = r#"{
+        "ticker": "MKT-1",
+        "price_ranges": [
+            {"start": "0.0000", "end": "0.1000", "step": "0.0010"},
+            {"start": "0.1000", "end": "0.9000", "step": "0.0100"}
+        ]
+    }"#;
+    let market: kalshi_fast::Market = serde_json::from_str(json).unwrap();
+
+    assert!(market.is_valid_price("0.0420"));
+    assert!(market.is_valid_price("0.55"));
+    assert!(!market.is_valid_price("0.555"));
+    assert!(!market.is_valid_price("0.95"));
+    assert!(!market.is_valid_price("abc"));
+
+    assert_eq!(market.round_to_tick("0.0424"), "0.042");
+    assert_eq!(market.round_to_tick("0.556"), "0.56");
+    assert_eq!(market.round_to_tick("0.97"), "0.90");
+    assert_eq!(market.round_to_tick("abc"), "abc");
+}
+
+#[test]
+fn market_price_in_converts_between_units() {
+    use kalshi_fast::{MarketPriceField, PriceUnit};
+
+    let market: kalshi_fast::Market = serde_json::from_value(serde_json::json!({
+        "ticker": "MKT-1",
+        "response_price_units": "usd_cent",
+        "yes_bid": 42,
+        "yes_ask_dollars": "0.4350",
+        "no_bid": 55,
+        "no_bid_dollars": "0.5500"
+    }))
+    .unwrap();
+
+    assert_eq!(market.response_price_unit(), Some(PriceUnit::Cents));
+    assert_eq!(
+        market.price_in(MarketPriceField::YesBid, PriceUnit::Cents),
+        "42".parse().ok()
+    );
+    assert_eq!(
+        market.price_in(MarketPriceField::YesBid, PriceUnit::Dollars),
+        "0.42".parse().ok()
+    );
+    assert_eq!(
+        market.price_in(MarketPriceField::YesAsk, PriceUnit::Cents),
+        "43.5".parse().ok()
+    );
+    assert_eq!(
+        market.price_in(MarketPriceField::NoBid, PriceUnit::Dollars),
+        "0.55".parse().ok()
+    );
+    assert_eq!(
+        market.price_in(MarketPriceField::LastPrice, PriceUnit::Cents),
+        None
+    );
+    assert_eq!(
+        market
+            .price_in(MarketPriceField::YesAsk, PriceUnit::Cents)
+            .map(|price| price.to_string()),
+        Some("43.50".to_string())
+    );
+    assert_eq!(PriceUnit::parse("dollars"), Some(PriceUnit::Dollars));
+    assert_eq!(PriceUnit::parse("USD_CENT"), Some(PriceUnit::Cents));
+    assert_eq!(PriceUnit::parse("contracts"), None);
+    assert_eq!(PriceUnit::parse("centidollar"), None);
+}
+
+#[test]
+fn tradable_open_params_and_market_tradability() {
+    let params = GetMarketsParams::tradable_open();
+    params.validate().unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({"status": "open"})
+    );
+
+    let market =
+        |json: serde_json::Value| -> kalshi_fast::Market { serde_json::from_value(json).unwrap() };
+    assert!(market(serde_json::json!({"ticker": "A", "status": "active"})).is_tradable_now());
+    assert!(
+        !market(serde_json::json!({"ticker": "A", "status": "active", "can_trade": false}))
+            .is_tradable_now()
+    );
+    assert!(
+        !market(serde_json::json!({"ticker": "A", "status": "closed", "can_trade": true}))
+            .is_tradable_now()
+    );
+    assert!(!market(serde_json::json!({"ticker": "A"})).is_tradable_now());
+}
+
+#[test]
+fn market_sort_key_orders_sizes_desc_times_asc_missing_last() {
+    use kalshi_fast::MarketSortKey;
+
+    let mut markets: Vec<kalshi_fast::Market> = serde_json::from_value(serde_json::json!([
+        {"ticker": "NONE"},
+        {"ticker": "B", "volume": 10, "close_time": "2024-01-02T00:00:00Z"},
+        {"ticker": "A", "volume_fp": "10.00", "close_ts": 1704067200},
+        {"ticker": "C", "volume": 250, "close_ts": 1704240000},
+    ]))
+    .unwrap();
+    let tickers = |markets: &[kalshi_fast::Market]| -> Vec<String> {
+        markets.iter().map(|m| m.ticker.clone()).collect()
+    };
+
+    markets.sort_by(|a, b| MarketSortKey::Volume.compare(a, b));
+    assert_eq!(tickers(&markets), ["C", "A", "B", "NONE"]);
+
+    markets.sort_by(|a, b| MarketSortKey::CloseTime.compare(a, b));
+    assert_eq!(tickers(&markets), ["A", "B", "C", "NONE"]);
+
+    MarketSortKey::Volume.sort(&mut markets);
+    assert_eq!(tickers(&markets), ["C", "A", "B", "NONE"]);
+}
+
+#[test]
+fn positions_notional_exposure_prices_yes_and_no_sides() {
+    let page: kalshi_fast::GetPositionsResponse = serde_json::from_value(serde_json::json!({
+        "market_positions": [
+            {"ticker": "YES", "position": 10},
+            {"ticker": "NO", "position": -4, "position_fp": "-4.50"},
+            {"ticker": "FLAT", "position": 0},
+            {"ticker": "UNPRICED", "position": 3},
+        ],
+    }))
+    .unwrap();
+    let page = kalshi_fast::PositionsPage::from(page);
+    let prices = std::collections::HashMap::from([
+        ("YES".to_string(), 40),
+        ("NO".to_string(), 30),
+        ("FLAT".to_string(), 50),
+    ]);
+
+    assert_eq!(
+        page.market_positions[1].contracts(),
+        Some(kalshi_fast::Quantity::from_hundredths(-450))
+    );
+    assert_eq!(
+        page.market_positions[1].notional_exposure(30),
+        "3.15".parse::<kalshi_fast::FixedPoint>().unwrap()
+    );
+    assert!(page.market_positions[1].notional_exposure(120).is_zero());
+    assert_eq!(
+        page.market_positions[0].notional_exposure(120),
+        "10".parse::<kalshi_fast::FixedPoint>().unwrap()
+    );
+    // 10 * 40 + 4.5 * (100 - 30)
+    assert_eq!(page.notional_exposure(&prices), 715);
+}
+
+#[test]
+fn settlement_summary_aggregates_realized_pnl() {
+    let settlements: Vec<kalshi_fast::Settlement> = serde_json::from_value(serde_json::json!([
+        {
+            "settlement_id": "s1", "ticker": "MKT-A", "event_ticker": "EVT",
+            "revenue": "10.0000", "yes_total_cost": "6.5000", "fee_cost": "0.2500"
+        },
+        {
+            "settlement_id": "s2", "ticker": "MKT-B", "event_ticker": "EVT",
+            "revenue": "0", "yes_total_cost": "1.0000", "no_total_cost": "2.00"
+        },
+        {
+            "settlement_id": "s3", "ticker": "MKT-A",
+            "revenue": "1.00"
+        },
+        {
+            "settlement_id": "s4", "ticker": "MKT-C",
+            "revenue": "oops"
+        }
+    ]))
+    .unwrap();
+
+    let summary = kalshi_fast::SettlementSummary::from(settlements.as_slice());
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.total.revenue, 11_000_000);
+    assert_eq!(summary.total.cost, 9_500_000);
+    assert_eq!(summary.total.fees, 250_000);
+    assert_eq!(summary.total.net_dollars(), "1.2500");
+
+    assert_eq!(summary.by_market["MKT-A"].net_dollars(), "4.2500");
+    assert_eq!(summary.by_market["MKT-B"].net_dollars(), "-3.0000");
+    assert!(!summary.by_market.contains_key("MKT-C"));
+    assert_eq!(summary.by_event["EVT"].net(), 250_000);
+}
+
+#[cfg(feature = "serialize-responses")]
+#[test]
+fn response_types_round_trip_through_serialize() {
+    fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(json: serde_json::Value) {
+        let parsed: T = serde_json::from_value(json).unwrap();
+        let first = serde_json::to_value(&parsed).unwrap();
+        let reparsed: T = serde_json::from_value(first.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&reparsed).unwrap(), first);
+    }
+
+    round_trip::<kalshi_fast::Market>(serde_json::json!({
+        "ticker": "MKT-1",
+        "status": "active",
+        "yes_bid": 40,
+        "yes_bid_dollars": "0.40",
+        "price_ranges": [{"start": "0.01", "end": "0.99", "step": "0.01"}],
+        "custom_strike": {"foo": "bar"},
+    }));
+    round_trip::<kalshi_fast::EventData>(serde_json::json!({
+        "event_ticker": "EVT-1",
+        "markets": [{"ticker": "MKT-1"}],
+    }));
+    round_trip::<kalshi_fast::Order>(serde_json::json!({
+        "order_id": "o1",
+        "ticker": "MKT-1",
+        "side": "yes",
+        "action": "buy",
+        "status": "resting",
+    }));
+    round_trip::<kalshi_fast::Fill>(serde_json::json!({
+        "fill_id": "f1",
+        "order_id": "o1",
+        "trade_id": "t1",
+        "ticker": "MKT-1",
+    }));
+    round_trip::<kalshi_fast::Settlement>(serde_json::json!({
+        "settlement_id": "s1",
+        "ticker": "MKT-1",
+        "market_result": "yes",
+    }));
+}
@@ -212,6 +212,40 @@ async fn test_get_market_orderbook() {
     assert!(resp.orderbook.no.len() <= 1);
 }
 
+#[tokio::test]
+async fn test_get_order_book() {
+    let client = KalshiRestClient::new(common::demo_env());
+
+    let markets_resp = tokio::time::timeout(common::TEST_TIMEOUT, async {
+        client
+            .get_markets(GetMarketsParams {
+                limit: Some(1),
+                status: Some(MarketStatusQuery::Open),
+                ..Default::default()
+            })
+            .await
+    })
+    .await
+    .expect("timeout")
+    .expect("request failed");
+
+    if markets_resp.markets.is_empty() {
+        return;
+    }
+
+    let market_ticker = markets_resp.markets[0].ticker.clone();
+    let book = tokio::time::timeout(common::TEST_TIMEOUT, async {
+        client.get_order_book(&market_ticker, Some(1)).await
+    })
+    .await
+    .expect("timeout")
+    .expect("request failed");
+
+    assert_eq!(book.market_ticker, market_ticker);
+    assert!(book.yes.len() <= 1);
+    assert!(book.no.len() <= 1);
+}
+
 #[tokio::test]
 async fn test_get_trades() {
     let client = KalshiRestClient::new(common::demo_env());
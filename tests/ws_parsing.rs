@@ -176,6 +176,40 @@ fn ws_ticker_message_parses() {
     }
 }
 
+#[test]
+fn ws_ticker_volume_fields_accept_numbers_or_strings() {
+    let ticker = |volume: &str, dollar_volume: &str| {
+        format!(
+            r#"{{"type":"ticker","msg":{{"market_ticker":"MKT-1","market_id":"m1","price":55,"yes_bid":54,"yes_ask":56,"price_dollars":"0.55","yes_bid_dollars":"0.54","yes_ask_dollars":"0.56","volume":{volume},"volume_fp":"123.00","open_interest":"5000","open_interest_fp":"5000.00","dollar_volume":{dollar_volume},"dollar_open_interest":2750,"ts":1700000000000}}}}"#
+        )
+    };
+
+    for json in [
+        ticker("123", "9876543210"),
+        ticker(r#""123""#, r#""9876543210""#),
+    ] {
+        match WsMessage::from_bytes(json.as_bytes()).unwrap() {
+            WsMessage::Data(WsDataMessage::Ticker { msg, .. }) => {
+                assert_eq!(msg.volume, 123);
+                assert_eq!(msg.open_interest, 5000);
+                assert_eq!(msg.dollar_volume, 9_876_543_210);
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+        match kalshi_fast::WsMessageRef::from_bytes(json.as_bytes()).unwrap() {
+            kalshi_fast::WsMessageRef::Data(kalshi_fast::WsDataMessageRef::Ticker {
+                msg, ..
+            }) => {
+                assert_eq!(msg.volume, 123);
+                assert_eq!(msg.dollar_volume, 9_876_543_210);
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+    }
+
+    assert!(WsMessage::from_bytes(ticker(r#""12.5""#, "1").as_bytes()).is_err());
+}
+
 #[test]
 fn ws_trade_message_parses() {
     let json = r#"{
@@ -722,3 +756,49 @@ fn ws_list_subscriptions_parses_from_subscriptions_field() {
         other => panic!("unexpected: {:?}", other),
     }
 }
+
+#[test]
+fn ws_message_to_wire_json_round_trips() {
+    let frames = [
+        r#"{"type":"orderbook_delta","sid":7,"seq":3,"msg":{"market_ticker":"TEST","market_id":"abc","price":45,"price_dollars":"0.4500","delta":-10,"delta_fp":"-10.00","side":"no"}}"#,
+        r#"{"type":"subscribed","id":4,"msg":{"channel":"ticker","sid":12}}"#,
+        r#"{"type":"error","id":9,"msg":{"code":6,"message":"Already subscribed"}}"#,
+        r#"{"type":"rfq_deleted","sid":1,"seq":2,"msg":{"id":"rfq-1","creator_id":"u","market_ticker":"T","deleted_ts":"2024-01-01T00:00:00Z"}}"#,
+    ];
+
+    for frame in frames {
+        let msg = WsMessage::from_bytes(frame.as_bytes()).unwrap();
+        let wire = msg.to_wire_json().unwrap();
+        let reparsed = WsMessage::from_bytes(wire.as_bytes()).unwrap();
+        assert_eq!(reparsed.to_wire_json().unwrap(), wire, "frame: {frame}");
+    }
+
+    let msg = WsMessage::from_bytes(frames[0].as_bytes()).unwrap();
+    let value: Value = serde_json::from_str(&msg.to_wire_json().unwrap()).unwrap();
+    assert_eq!(value["type"], "orderbook_delta");
+    assert_eq!(value["sid"], 7);
+    assert_eq!(value["seq"], 3);
+    assert_eq!(value["msg"]["side"], "no");
+    assert_eq!(value["msg"]["delta"], -10);
+
+    let msg = WsMessage::from_bytes(frames[1].as_bytes()).unwrap();
+    match WsMessage::from_bytes(msg.to_wire_json().unwrap().as_bytes()).unwrap() {
+        WsMessage::Subscribed { id, sid } => {
+            assert_eq!(id, Some(4));
+            assert_eq!(sid, Some(12));
+        }
+        other => panic!("unexpected: {:?}", other),
+    }
+}
+
+#[test]
+fn ws_data_message_exposes_sid_and_seq() {
+    let raw = br#"{"type":"orderbook_delta","sid":7,"seq":42,"msg":{"market_ticker":"MKT-1","market_id":"m","price":45,"price_dollars":"0.45","delta":3,"delta_fp":"3","side":"yes"}}"#;
+    match WsMessage::from_bytes(raw).unwrap() {
+        WsMessage::Data(data) => {
+            assert_eq!(data.sid(), Some(7));
+            assert_eq!(data.seq(), Some(42));
+        }
+        other => panic!("unexpected: {:?}", other),
+    }
+}